@@ -0,0 +1,9 @@
+DELETE FROM user_tasks t
+USING user_tasks newer
+WHERE newer.user_id = t.user_id
+  AND (newer.task_year, newer.task_week) > (t.task_year, t.task_week);
+
+ALTER TABLE user_tasks DROP CONSTRAINT IF EXISTS user_tasks_pkey;
+
+ALTER TABLE user_tasks
+    ADD PRIMARY KEY (user_id);
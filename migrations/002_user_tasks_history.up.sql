@@ -0,0 +1,4 @@
+ALTER TABLE user_tasks DROP CONSTRAINT IF EXISTS user_tasks_pkey;
+
+ALTER TABLE user_tasks
+    ADD PRIMARY KEY (user_id, task_year, task_week);
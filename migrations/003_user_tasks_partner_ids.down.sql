@@ -0,0 +1,11 @@
+ALTER TABLE user_tasks
+    ADD COLUMN partner_id BIGINT;
+
+UPDATE user_tasks
+SET partner_id = partner_ids[1];
+
+ALTER TABLE user_tasks
+    ALTER COLUMN partner_id SET NOT NULL;
+
+ALTER TABLE user_tasks
+    DROP COLUMN partner_ids;
@@ -0,0 +1,11 @@
+ALTER TABLE user_tasks
+    ADD COLUMN partner_ids BIGINT[] NOT NULL DEFAULT '{}';
+
+UPDATE user_tasks
+SET partner_ids = ARRAY[partner_id];
+
+ALTER TABLE user_tasks
+    DROP COLUMN partner_id;
+
+ALTER TABLE user_tasks
+    ALTER COLUMN partner_ids DROP DEFAULT;
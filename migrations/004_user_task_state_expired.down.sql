@@ -0,0 +1,13 @@
+ALTER TABLE user_tasks
+    ALTER COLUMN state TYPE TEXT;
+
+UPDATE user_tasks
+SET state = 'active'
+WHERE state = 'expired';
+
+DROP TYPE USER_TASK_STATE;
+
+CREATE TYPE USER_TASK_STATE AS ENUM ('active', 'completed');
+
+ALTER TABLE user_tasks
+    ALTER COLUMN state TYPE USER_TASK_STATE USING state::USER_TASK_STATE;
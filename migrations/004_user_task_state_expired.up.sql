@@ -0,0 +1 @@
+ALTER TYPE USER_TASK_STATE ADD VALUE IF NOT EXISTS 'expired';
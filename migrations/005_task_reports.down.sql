@@ -0,0 +1,15 @@
+DROP TABLE IF EXISTS task_reports;
+
+ALTER TABLE user_tasks
+    ALTER COLUMN state TYPE TEXT;
+
+UPDATE user_tasks
+SET state = 'active'
+WHERE state = 'awaiting_review';
+
+DROP TYPE USER_TASK_STATE;
+
+CREATE TYPE USER_TASK_STATE AS ENUM ('active', 'completed', 'expired');
+
+ALTER TABLE user_tasks
+    ALTER COLUMN state TYPE USER_TASK_STATE USING state::USER_TASK_STATE;
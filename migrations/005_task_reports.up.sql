@@ -0,0 +1,15 @@
+ALTER TYPE USER_TASK_STATE ADD VALUE IF NOT EXISTS 'awaiting_review';
+
+CREATE TABLE IF NOT EXISTS task_reports (
+    user_id         BIGINT          NOT NULL,
+    task_year       INTEGER         NOT NULL,
+    task_week       INTEGER         NOT NULL,
+    photo_file_ids  TEXT[]          NOT NULL,
+    caption         TEXT            DEFAULT NULL,
+    submitted_at    TIMESTAMPTZ     NOT NULL DEFAULT now(),
+
+    PRIMARY KEY (user_id, task_year, task_week),
+    FOREIGN KEY (user_id, task_year, task_week)
+        REFERENCES user_tasks (user_id, task_year, task_week)
+        ON DELETE CASCADE
+);
@@ -0,0 +1,5 @@
+CREATE TABLE IF NOT EXISTS dialogues (
+    chat_id     BIGINT          PRIMARY KEY,
+    dialogue    BYTEA           NOT NULL,
+    updated_at  TIMESTAMPTZ     NOT NULL DEFAULT now()
+);
@@ -0,0 +1,6 @@
+UPDATE users
+SET username = 'id' || id
+WHERE username IS NULL;
+
+ALTER TABLE users
+    ALTER COLUMN username SET NOT NULL;
@@ -0,0 +1,11 @@
+DO $$ BEGIN
+    CREATE TYPE ADMIN_ROLE AS ENUM ('owner', 'admin', 'reviewer');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+CREATE TABLE IF NOT EXISTS admins (
+    user_id     BIGINT          PRIMARY KEY,
+    role        ADMIN_ROLE      NOT NULL,
+    granted_at  TIMESTAMPTZ     NOT NULL DEFAULT now()
+);
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS pool_tasks (
+    id          BIGSERIAL       PRIMARY KEY,
+    title       VARCHAR(128)    NOT NULL,
+    description TEXT            NOT NULL,
+    tags        TEXT[]          NOT NULL DEFAULT '{}',
+    used_year   INTEGER         DEFAULT NULL,
+    used_week   INTEGER         DEFAULT NULL,
+    created_at  TIMESTAMPTZ     NOT NULL DEFAULT now(),
+
+    CHECK ((used_year IS NULL) = (used_week IS NULL))
+);
@@ -0,0 +1,8 @@
+ALTER TABLE tasks
+    DROP CONSTRAINT IF EXISTS tasks_media_check,
+    DROP COLUMN IF EXISTS media_kind,
+    DROP COLUMN IF EXISTS media_file_id,
+    DROP COLUMN IF EXISTS media_latitude,
+    DROP COLUMN IF EXISTS media_longitude;
+
+DROP TYPE IF EXISTS TASK_MEDIA_KIND;
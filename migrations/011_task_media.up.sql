@@ -0,0 +1,21 @@
+DO $$ BEGIN
+    CREATE TYPE TASK_MEDIA_KIND AS ENUM ('photo', 'document', 'location');
+EXCEPTION
+    WHEN duplicate_object THEN NULL;
+END $$;
+
+ALTER TABLE tasks
+    ADD COLUMN media_kind      TASK_MEDIA_KIND  NULL,
+    ADD COLUMN media_file_id   TEXT             NULL,
+    ADD COLUMN media_latitude  DOUBLE PRECISION NULL,
+    ADD COLUMN media_longitude DOUBLE PRECISION NULL;
+
+ALTER TABLE tasks
+    ADD CONSTRAINT tasks_media_check CHECK (
+        (media_kind IS NULL
+            AND media_file_id IS NULL AND media_latitude IS NULL AND media_longitude IS NULL)
+        OR (media_kind IN ('photo', 'document')
+            AND media_file_id IS NOT NULL AND media_latitude IS NULL AND media_longitude IS NULL)
+        OR (media_kind = 'location'
+            AND media_file_id IS NULL AND media_latitude IS NOT NULL AND media_longitude IS NOT NULL)
+    );
@@ -0,0 +1,5 @@
+ALTER TABLE user_tasks
+    DROP CONSTRAINT IF EXISTS user_tasks_variant_fkey,
+    DROP COLUMN IF EXISTS variant;
+
+DROP TABLE IF EXISTS task_variants;
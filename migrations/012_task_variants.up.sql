@@ -0,0 +1,21 @@
+CREATE TABLE IF NOT EXISTS task_variants (
+    task_year   INTEGER         NOT NULL,
+    task_week   INTEGER         NOT NULL,
+    number      INTEGER         NOT NULL,
+    title       VARCHAR(128)    NOT NULL,
+    description TEXT            NOT NULL,
+
+    PRIMARY KEY (task_year, task_week, number),
+    FOREIGN KEY (task_year, task_week)
+        REFERENCES tasks (year, week)
+        ON DELETE CASCADE
+);
+
+-- NULL stands for the main task of the week
+ALTER TABLE user_tasks
+    ADD COLUMN variant INTEGER NULL;
+
+ALTER TABLE user_tasks
+    ADD CONSTRAINT user_tasks_variant_fkey
+        FOREIGN KEY (task_year, task_week, variant)
+        REFERENCES task_variants (task_year, task_week, number);
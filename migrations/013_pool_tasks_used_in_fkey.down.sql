@@ -0,0 +1,2 @@
+ALTER TABLE pool_tasks
+    DROP CONSTRAINT IF EXISTS pool_tasks_used_in_fkey;
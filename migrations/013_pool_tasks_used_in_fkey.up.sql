@@ -0,0 +1,8 @@
+UPDATE pool_tasks
+SET used_year = NULL, used_week = NULL
+WHERE used_year IS NOT NULL
+  AND NOT EXISTS (SELECT 1 FROM tasks WHERE tasks.year = used_year AND tasks.week = used_week);
+
+ALTER TABLE pool_tasks
+    ADD CONSTRAINT pool_tasks_used_in_fkey
+        FOREIGN KEY (used_year, used_week) REFERENCES tasks (year, week) ON DELETE SET NULL;
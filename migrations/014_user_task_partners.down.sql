@@ -0,0 +1,17 @@
+ALTER TABLE user_tasks
+    ADD COLUMN partner_ids BIGINT[] NOT NULL DEFAULT '{}';
+
+UPDATE user_tasks
+SET partner_ids = ARRAY(
+    SELECT partner_id
+    FROM user_task_partners p
+    WHERE p.user_id = user_tasks.user_id
+      AND p.task_year = user_tasks.task_year
+      AND p.task_week = user_tasks.task_week
+    ORDER BY p.position
+);
+
+ALTER TABLE user_tasks
+    ALTER COLUMN partner_ids DROP DEFAULT;
+
+DROP TABLE IF EXISTS user_task_partners;
@@ -0,0 +1,35 @@
+CREATE TABLE IF NOT EXISTS user_task_partners (
+    user_id    BIGINT  NOT NULL,
+    task_year  INTEGER NOT NULL,
+    task_week  INTEGER NOT NULL,
+    position   INTEGER NOT NULL,
+    partner_id BIGINT  NOT NULL,
+
+    PRIMARY KEY (user_id, task_year, task_week, position),
+    FOREIGN KEY (user_id, task_year, task_week)
+        REFERENCES user_tasks (user_id, task_year, task_week)
+        ON DELETE CASCADE,
+    FOREIGN KEY (partner_id)
+        REFERENCES users (id)
+        DEFERRABLE INITIALLY DEFERRED
+);
+
+DO $$
+DECLARE
+    missing INTEGER;
+BEGIN
+    SELECT count(*) INTO missing
+    FROM user_tasks, unnest(partner_ids) AS p(partner_id)
+    WHERE NOT EXISTS (SELECT 1 FROM users WHERE users.id = p.partner_id);
+
+    IF missing > 0 THEN
+        RAISE EXCEPTION '% user task partners reference missing users', missing;
+    END IF;
+END $$;
+
+INSERT INTO user_task_partners (user_id, task_year, task_week, position, partner_id)
+SELECT user_id, task_year, task_week, p.position, p.partner_id
+FROM user_tasks, unnest(partner_ids) WITH ORDINALITY AS p(partner_id, position);
+
+ALTER TABLE user_tasks
+    DROP COLUMN partner_ids;
@@ -1,55 +1,126 @@
+use std::sync::Arc;
 use teloxide::{filter_command, Bot};
-use teloxide::dispatching::dialogue::InMemStorage;
+use teloxide::dispatching::dialogue::ErasedStorage;
 use teloxide::dispatching::{dialogue, DefaultKey, Dispatcher, UpdateHandler};
 use teloxide::prelude::*;
 
 use crate::domain::use_cases::*;
 
 use crate::presentation::handlers::commands::Command;
-use crate::presentation::handlers::{admin, user};
+use crate::presentation::handlers::{admin, cancel, user};
 use crate::presentation::handlers::fsm::CwDialogueState;
 use crate::presentation::handlers::utils::CwBotError;
 
 
+pub struct UserUseCases {
+    pub start_registration: StartRegistrationUseCase,
+    pub complete_registration: CompleteRegistrationUseCase,
+    pub check_full_name: CheckFullNameUseCase,
+    pub sync_username: SyncUsernameUseCase,
+    pub accept_next_task: AcceptNextTaskUseCase,
+    pub reject_next_task: RejectTaskUseCase,
+    pub get_menu_state: GetMenuStateUseCase,
+    pub get_user_task: GetUserTaskUseCase,
+    pub submit_report: SubmitReportUseCase,
+}
+
+pub struct AdminUseCases {
+    pub check_admin: CheckAdminUseCase,
+    pub get_all_users: GetAllUsersUseCase,
+    pub get_user: GetUserUseCase,
+    pub get_ready_users: GetReadyUsersUseCase,
+    pub assign_partner: AssignPartnerUseCase,
+    pub get_active_users: GetActiveUsersUseCase,
+    pub complete_task: CompleteTaskUseCase,
+    pub auto_pair: AutoPairUseCase,
+}
+
+pub struct TaskPlanningUseCases {
+    pub check_next_task: CheckNextTaskUseCase,
+    pub create_task: CreateTaskUseCase,
+    pub update_task: UpdateTaskUseCase,
+    pub delete_task: DeleteTaskUseCase,
+    pub list_tasks: ListTasksUseCase,
+    pub get_task_calendar: GetTaskCalendarUseCase,
+    pub get_planned_week: GetPlannedWeekUseCase,
+    pub add_pool_task: AddPoolTaskUseCase,
+    pub get_task_pool: GetTaskPoolUseCase,
+    pub plan_pool_task: PlanPoolTaskUseCase,
+    pub reset_task_pool: ResetTaskPoolUseCase,
+    pub add_task_variant: AddTaskVariantUseCase,
+    pub delete_task_variant: DeleteTaskVariantUseCase,
+}
+
+pub struct ReviewUseCases {
+    pub get_review_queue: GetReviewQueueUseCase,
+    pub approve_report: ApproveReportUseCase,
+    pub reject_report: RejectReportUseCase,
+}
+
+pub struct AdminRightsUseCases {
+    pub list_admins: ListAdminsUseCase,
+    pub grant_admin: GrantAdminUseCase,
+    pub revoke_admin: RevokeAdminUseCase,
+}
+
 pub struct CwDispatcher;
 
 impl CwDispatcher {
+    /// Handlers get the use cases one by one, the bundles only group them here
     pub async fn create(
         bot: Bot,
-        start_registration_use_case: StartRegistrationUseCase,
-        complete_registration_use_case: CompleteRegistrationUseCase,
-        accept_next_task_use_case: AcceptNextTaskUseCase,
-        reject_next_task_use_case: RejectTaskUseCase,
-        get_menu_state_use_case: GetMenuStateUseCase,
-        get_current_meeting_use_case: GetUserTaskUseCase,
-        check_admin_use_case: CheckAdminUseCase,
-        get_all_users_use_case: GetAllUsersUseCase,
-        get_user_use_case: GetUserUseCase,
-        get_free_users_use_case: GetReadyUsersUseCase,
-        assign_partner_use_case: AssignPartnerUseCase,
-        check_next_task_use_case: CheckNextTaskUseCase,
-        create_next_task_use_case: CreateNextTaskUseCase,
-        get_active_users_use_case: GetActiveUsersUseCase,
-        complete_task_use_case: CompleteTaskUseCase,
+        user: UserUseCases,
+        admin_menu: AdminUseCases,
+        task_planning: TaskPlanningUseCases,
+        review: ReviewUseCases,
+        admin_rights: AdminRightsUseCases,
+        dialogue_storage: Arc<ErasedStorage<CwDialogueState>>,
     ) -> Dispatcher<Bot, CwBotError, DefaultKey> {
+        let assign_group_use_cases = admin::AssignGroupUseCases {
+            assign_partner: admin_menu.assign_partner,
+            get_user_task: user.get_user_task.clone(),
+            get_user: admin_menu.get_user.clone(),
+        };
+
         Dispatcher::builder(bot, Self::schema())
             .dependencies(dptree::deps![
-                start_registration_use_case,
-                complete_registration_use_case,
-                accept_next_task_use_case,
-                reject_next_task_use_case,
-                get_menu_state_use_case,
-                get_current_meeting_use_case,
-                check_admin_use_case,
-                get_all_users_use_case,
-                get_user_use_case,
-                get_free_users_use_case,
-                assign_partner_use_case,
-                check_next_task_use_case,
-                create_next_task_use_case,
-                get_active_users_use_case,
-                complete_task_use_case,
-                InMemStorage::<CwDialogueState>::new()
+                user.start_registration,
+                user.complete_registration,
+                user.check_full_name,
+                user.sync_username,
+                user.accept_next_task,
+                user.reject_next_task,
+                user.get_menu_state,
+                user.get_user_task,
+                user.submit_report,
+                admin_menu.check_admin,
+                admin_menu.get_all_users,
+                admin_menu.get_user,
+                admin_menu.get_ready_users,
+                assign_group_use_cases,
+                admin_menu.get_active_users,
+                admin_menu.complete_task,
+                admin_menu.auto_pair,
+                task_planning.check_next_task,
+                task_planning.create_task,
+                task_planning.update_task,
+                task_planning.delete_task,
+                task_planning.list_tasks,
+                task_planning.get_task_calendar,
+                task_planning.get_planned_week,
+                task_planning.add_pool_task,
+                task_planning.get_task_pool,
+                task_planning.plan_pool_task,
+                task_planning.reset_task_pool,
+                task_planning.add_task_variant,
+                task_planning.delete_task_variant,
+                review.get_review_queue,
+                review.approve_report,
+                review.reject_report,
+                admin_rights.list_admins,
+                admin_rights.grant_admin,
+                admin_rights.revoke_admin,
+                dialogue_storage
             ])
             .default_handler(|upd| async move {
                 log::warn!("Unhandled update: {:?}", upd);
@@ -63,6 +134,7 @@ impl CwDispatcher {
         
         let command_handler = filter_command::<Command, _>()
             .branch(case![Command::Start].endpoint(user::handle_start_command))
+            .branch(case![Command::Cancel].endpoint(cancel::handle_cancel_command))
             .branch(case![Command::Admin].endpoint(admin::handle_admin_command))
         ;
 
@@ -86,16 +158,65 @@ impl CwDispatcher {
                     .endpoint(user::handle_next_meeting_reject)
             )
             .branch(
-                case![CwDialogueState::AwaitingTaskTitle]
-                    .endpoint(admin::receive_task_title)
+                case![CwDialogueState::AwaitingReport { photo_file_ids, caption }]
+                    .endpoint(user::receive_report)
             )
             .branch(
-                case![CwDialogueState::AwaitingTaskDescription { title }]
-                    .endpoint(admin::receive_task_description)
+                case![CwDialogueState::AwaitingReportRejectReason { user_id, week_id }]
+                    .filter_async(admin::can_review)
+                    .endpoint(admin::receive_report_reject_reason)
+            )
+            .branch(
+                dptree::filter(|state: CwDialogueState| state.is_admin_flow())
+                    .filter_async(admin::can_manage_meetings)
+                    .branch(
+                        case![CwDialogueState::AwaitingTaskTitle { week_id, edit }]
+                            .endpoint(admin::receive_task_title)
+                    )
+                    .branch(
+                        case![CwDialogueState::AwaitingTaskDescription { week_id, edit, title }]
+                            .endpoint(admin::receive_task_description)
+                    )
+                    .branch(
+                        case![CwDialogueState::AwaitingTaskMedia { week_id, edit, title, description }]
+                            .endpoint(admin::receive_task_media)
+                    )
+                    .branch(
+                        case![CwDialogueState::AwaitingVariantTitle { week_id }]
+                            .endpoint(admin::receive_variant_title)
+                    )
+                    .branch(
+                        case![CwDialogueState::AwaitingVariantDescription { week_id, title }]
+                            .endpoint(admin::receive_variant_description)
+                    )
+                    .branch(
+                        case![CwDialogueState::AwaitingPoolTaskTitle]
+                            .endpoint(admin::receive_pool_task_title)
+                    )
+                    .branch(
+                        case![CwDialogueState::AwaitingPoolTaskDescription { title }]
+                            .endpoint(admin::receive_pool_task_description)
+                    )
+                    .branch(
+                        case![CwDialogueState::AwaitingPoolTaskTags { title, description }]
+                            .endpoint(admin::receive_pool_task_tags)
+                    )
             )
         ;
 
         let callback_handler = Update::filter_callback_query()
+            .branch(
+                dptree::entry()
+                    .filter_map(extract_next_task_prompt_callback)
+                    .branch(
+                        case![user::NextTaskPromptCallback::Accept]
+                            .endpoint(user::handle_next_task_prompt_accept_callback)
+                    )
+                    .branch(
+                        case![user::NextTaskPromptCallback::Reject]
+                            .endpoint(user::handle_next_task_prompt_reject_callback)
+                    )
+            )
             .branch(
                 dptree::entry()
                     .filter_map(extract_menu_callback)
@@ -119,6 +240,7 @@ impl CwDispatcher {
             .branch(
                 dptree::entry()
                     .filter_map(extract_admin_menu_callback)
+                    .filter_async(admin::is_admin_menu_callback_allowed)
                     .branch(
                         case![admin::MenuCallback::Users]
                             .endpoint(admin::handle_admin_menu_users_callback)
@@ -128,13 +250,148 @@ impl CwDispatcher {
                             .endpoint(admin::handle_admin_menu_assign_partner_callback)
                     )
                     .branch(
-                        case![admin::MenuCallback::CreateNextTask]
-                            .endpoint(admin::handle_admin_menu_create_next_task)
+                        case![admin::MenuCallback::TaskCalendar]
+                            .endpoint(admin::handle_admin_menu_task_calendar_callback)
+                    )
+                    .branch(
+                        case![admin::MenuCallback::Tasks]
+                            .endpoint(admin::handle_admin_menu_tasks_callback)
+                    )
+                    .branch(
+                        case![admin::MenuCallback::TaskPool]
+                            .endpoint(admin::handle_admin_menu_task_pool_callback)
+                    )
+                    .branch(
+                        case![admin::MenuCallback::AutoPair]
+                            .endpoint(admin::handle_admin_menu_auto_pair_callback)
                     )
                     .branch(
                         case![admin::MenuCallback::Complete]
                             .endpoint(admin::handle_admin_menu_complete_callback)
                     )
+                    .branch(
+                        case![admin::MenuCallback::Review]
+                            .endpoint(admin::handle_admin_menu_review_callback)
+                    )
+                    .branch(
+                        case![admin::MenuCallback::Admins]
+                            .endpoint(admin::handle_admin_menu_admins_callback)
+                    )
+            )
+            .branch(
+                dptree::entry()
+                    .filter_map(extract_task_calendar_callback)
+                    .filter_async(admin::can_manage_meetings)
+                    .branch(
+                        case![admin::TaskCalendarCallback::Page(offset)]
+                            .endpoint(admin::handle_task_calendar_page_callback)
+                    )
+                    .branch(
+                        case![admin::TaskCalendarCallback::Week(year, week)]
+                            .endpoint(admin::handle_task_calendar_week_callback)
+                    )
+                    .branch(
+                        case![admin::TaskCalendarCallback::Create(year, week)]
+                            .endpoint(admin::handle_task_calendar_create_callback)
+                    )
+                    .branch(
+                        case![admin::TaskCalendarCallback::Edit(year, week)]
+                            .endpoint(admin::handle_task_calendar_edit_callback)
+                    )
+                    .branch(
+                        case![admin::TaskCalendarCallback::Delete(year, week)]
+                            .endpoint(admin::handle_task_calendar_delete_callback)
+                    )
+                    .branch(
+                        case![admin::TaskCalendarCallback::AddVariant(year, week)]
+                            .endpoint(admin::handle_task_calendar_add_variant_callback)
+                    )
+                    .branch(
+                        case![admin::TaskCalendarCallback::DeleteVariant(year, week, number)]
+                            .endpoint(admin::handle_task_calendar_delete_variant_callback)
+                    )
+            )
+            .branch(
+                dptree::entry()
+                    .filter_map(extract_tasks_callback)
+                    .filter_async(admin::can_manage_meetings)
+                    .branch(
+                        case![admin::TasksCallback::Page(offset)]
+                            .endpoint(admin::handle_tasks_page_callback)
+                    )
+            )
+            .branch(
+                dptree::entry()
+                    .filter_map(extract_task_pool_callback)
+                    .filter_async(admin::can_manage_meetings)
+                    .branch(
+                        case![admin::TaskPoolCallback::Add]
+                            .endpoint(admin::handle_task_pool_add_callback)
+                    )
+                    .branch(
+                        case![admin::TaskPoolCallback::ResetSeason]
+                            .endpoint(admin::handle_task_pool_reset_callback)
+                    )
+                    .branch(
+                        case![admin::TaskPoolCallback::Plan(year, week)]
+                            .endpoint(admin::handle_task_pool_plan_callback)
+                    )
+                    .branch(
+                        case![admin::TaskPoolCallback::Pick(year, week, tag)]
+                            .endpoint(admin::handle_task_pool_pick_callback)
+                    )
+            )
+            .branch(
+                dptree::entry()
+                    .filter_map(extract_admin_rights_callback)
+                    .filter_async(admin::can_manage_admins)
+                    .branch(
+                        case![admin::AdminRightsCallback::Grant]
+                            .endpoint(admin::handle_admin_rights_grant_callback)
+                    )
+                    .branch(
+                        case![admin::AdminRightsCallback::Role(user_id, role)]
+                            .endpoint(admin::handle_admin_rights_role_callback)
+                    )
+                    .branch(
+                        case![admin::AdminRightsCallback::Revoke(user_id)]
+                            .endpoint(admin::handle_admin_rights_revoke_callback)
+                    )
+            )
+            .branch(
+                dptree::entry()
+                    .filter_map(extract_complete_callback)
+                    .filter_async(admin::can_manage_meetings)
+                    .endpoint(admin::handle_complete_scope_callback)
+            )
+            .branch(
+                dptree::entry()
+                    .filter_map(extract_review_callback)
+                    .filter_async(admin::can_review)
+                    .branch(
+                        case![admin::ReviewCallback::Approve(user_id, year, week)]
+                            .endpoint(admin::handle_review_approve_callback)
+                    )
+                    .branch(
+                        case![admin::ReviewCallback::Reject(user_id, year, week)]
+                            .endpoint(admin::handle_review_reject_callback)
+                    )
+            )
+            .branch(
+                dptree::entry()
+                    .filter_map(extract_auto_pair_callback)
+                    .filter_async(admin::can_manage_meetings)
+                    .branch(
+                        case![admin::AutoPairCallback::Confirm]
+                            .branch(
+                                case![CwDialogueState::AwaitingAutoPairConfirm { groups }]
+                                    .endpoint(admin::handle_auto_pair_confirm_callback)
+                            )
+                    )
+                    .branch(
+                        case![admin::AutoPairCallback::Cancel]
+                            .endpoint(admin::handle_auto_pair_cancel_callback)
+                    )
             )
             .branch(
                 dptree::entry()
@@ -143,22 +400,59 @@ impl CwDispatcher {
                         case![true]
                             .branch(
                                 case![CwDialogueState::AwaitingUser]
+                                    .filter_async(admin::can_manage_meetings)
                                     .endpoint(admin::handle_admin_menu_user_callback)
                             )
                             .branch(
                                 case![CwDialogueState::AwaitingPartner1]
+                                    .filter_async(admin::can_manage_meetings)
                                     .endpoint(admin::handle_admin_menu_assign_partner_1_callback)
                             )
                             .branch(
                                 case![CwDialogueState::AwaitingPartner2 { partner_1_id } ]
+                                    .filter_async(admin::can_manage_meetings)
                                     .endpoint(admin::handle_admin_menu_assign_partner_2_callback)
                             )
+                            .branch(
+                                case![CwDialogueState::AwaitingPartner3 { partner_1_id, partner_2_id }]
+                                    .filter_async(admin::can_manage_meetings)
+                                    .endpoint(admin::handle_admin_menu_assign_partner_3_callback)
+                            )
                             .branch(
                                 case![CwDialogueState::AwaitingUserForComplete]
+                                    .filter_async(admin::can_manage_meetings)
                                     .endpoint(admin::handle_admin_menu_complete_user_callback)
                             )
+                            .branch(
+                                case![CwDialogueState::AwaitingUserForGrant]
+                                    .filter_async(admin::can_manage_admins)
+                                    .endpoint(admin::handle_admin_rights_user_callback)
+                            )
                     )
             )
+            .branch(
+                dptree::entry()
+                    .filter(is_admin_assign_pair_callback)
+                    .filter_async(admin::can_manage_meetings)
+                    .branch(
+                        case![CwDialogueState::AwaitingPartner3 { partner_1_id, partner_2_id }]
+                            .endpoint(admin::handle_admin_assign_pair_callback)
+                    )
+            )
+            .branch(
+                dptree::entry()
+                    .filter_map(extract_assign_variant_callback)
+                    .filter_async(admin::can_manage_meetings)
+                    .branch(
+                        case![CwDialogueState::AwaitingTaskVariant { user_ids }]
+                            .endpoint(admin::handle_admin_assign_variant_callback)
+                    )
+            )
+            .branch(
+                dptree::entry()
+                    .filter(is_send_report_callback)
+                    .endpoint(user::handle_send_report_callback)
+            )
             .branch(
                 dptree::entry()
                     .filter_map(is_profile_re_register_callback)
@@ -167,6 +461,7 @@ impl CwDispatcher {
                             .endpoint(user::handle_re_register_callback)
                     )
             )
+            .branch(dptree::endpoint(cancel::handle_stale_callback))
         ;
         
         let compose_handler = Update::filter_message()
@@ -174,9 +469,13 @@ impl CwDispatcher {
             .branch(message_handler)
         ;
         
-        dialogue::enter::<Update, InMemStorage<CwDialogueState>, CwDialogueState, _>()
-            .branch(callback_handler)
-            .branch(compose_handler)
+        dptree::entry()
+            .inspect_async(user::sync_username)
+            .chain(
+                dialogue::enter::<Update, ErasedStorage<CwDialogueState>, CwDialogueState, _>()
+                    .branch(callback_handler)
+                    .branch(compose_handler)
+            )
     }
 }
 
@@ -184,14 +483,58 @@ fn extract_menu_callback(q: CallbackQuery) -> Option<user::MenuCallback> {
     q.data.and_then(|str| user::MenuCallback::try_from(str).ok())
 }
 
+fn extract_next_task_prompt_callback(q: CallbackQuery) -> Option<user::NextTaskPromptCallback> {
+    q.data.and_then(|str| user::NextTaskPromptCallback::try_from(str).ok())
+}
+
 fn extract_admin_menu_callback(q: CallbackQuery) -> Option<admin::MenuCallback> {
     q.data.and_then(|str| admin::MenuCallback::try_from(str).ok())
 }
 
+fn extract_auto_pair_callback(q: CallbackQuery) -> Option<admin::AutoPairCallback> {
+    q.data.and_then(|str| admin::AutoPairCallback::try_from(str).ok())
+}
+
+fn extract_complete_callback(q: CallbackQuery) -> Option<admin::CompleteCallback> {
+    q.data.and_then(|str| admin::CompleteCallback::try_from(str).ok())
+}
+
+fn extract_review_callback(q: CallbackQuery) -> Option<admin::ReviewCallback> {
+    q.data.and_then(|str| admin::ReviewCallback::try_from(str).ok())
+}
+
+fn extract_task_calendar_callback(q: CallbackQuery) -> Option<admin::TaskCalendarCallback> {
+    q.data.and_then(|str| admin::TaskCalendarCallback::try_from(str).ok())
+}
+
+fn extract_tasks_callback(q: CallbackQuery) -> Option<admin::TasksCallback> {
+    q.data.and_then(|str| admin::TasksCallback::try_from(str).ok())
+}
+
+fn extract_task_pool_callback(q: CallbackQuery) -> Option<admin::TaskPoolCallback> {
+    q.data.and_then(|str| admin::TaskPoolCallback::try_from(str).ok())
+}
+
+fn extract_admin_rights_callback(q: CallbackQuery) -> Option<admin::AdminRightsCallback> {
+    q.data.and_then(|str| admin::AdminRightsCallback::try_from(str).ok())
+}
+
+fn extract_assign_variant_callback(q: CallbackQuery) -> Option<admin::AssignVariantCallback> {
+    q.data.and_then(|str| admin::AssignVariantCallback::try_from(str).ok())
+}
+
 fn is_admin_menu_user_callback(q: CallbackQuery) -> Option<bool> {
     Some(q.data?.starts_with("admin_menu_user:"))
 }
 
+fn is_admin_assign_pair_callback(q: CallbackQuery) -> bool {
+    q.data.as_deref() == Some(admin::ASSIGN_PAIR_CALLBACK)
+}
+
 fn is_profile_re_register_callback(q: CallbackQuery) -> Option<bool> {
     Some(q.data?.eq("menu_profile_re_register"))
 }
+
+fn is_send_report_callback(q: CallbackQuery) -> bool {
+    q.data.as_deref() == Some(user::SEND_REPORT_CALLBACK)
+}
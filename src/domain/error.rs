@@ -1,4 +1,4 @@
-use crate::domain::models::{TaskId, UserId};
+use crate::domain::models::{PoolTaskId, TaskId, UserId};
 
 pub type StdError = Box<dyn std::error::Error + Send + Sync>;
 
@@ -8,6 +8,12 @@ pub enum DomainError {
     #[error("invalid value: {0}")]
     InvalidValue(String),
     
+    #[error("invalid full name: {0}")]
+    InvalidFullName(String),
+
+    #[error("invalid group name: {0}")]
+    InvalidGroupName(String),
+    
     #[error("user {0} not found")]
     UserNotFound(UserId),
     
@@ -19,6 +25,24 @@ pub enum DomainError {
 
     #[error("task {0} already exists")]
     TaskAlreadyExists(TaskId),
+
+    #[error("task {0} is already assigned to users")]
+    TaskInUse(TaskId),
+
+    #[error("week {0} has already started")]
+    WeekAlreadyStarted(TaskId),
+
+    #[error("variant {1} of task {0} not found")]
+    TaskVariantNotFound(TaskId, u32),
+
+    #[error("pool task {0} not found")]
+    PoolTaskNotFound(PoolTaskId),
+
+    #[error("no unused tasks left in the pool")]
+    TaskPoolExhausted,
+    
+    #[error("user {0} was modified concurrently")]
+    ConcurrentModification(UserId),
     
     #[error("invalid status change: {0}")]
     InvalidStateChange(String),
@@ -26,9 +50,18 @@ pub enum DomainError {
     #[error("no active task")]
     NoUserTask,
     
+    #[error("report of user {0} for task {1} not found")]
+    ReportNotFound(UserId, TaskId),
+    
     #[error("no next task")]
     NoNextTask,
     
+    #[error("user {0} is not allowed to do this")]
+    PermissionDenied(UserId),
+
+    #[error("admin {0} not found")]
+    AdminNotFound(UserId),
+    
     #[error("user {0} did not completed registration")]
     UserIsNotRegistered(UserId),
     
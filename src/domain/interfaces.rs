@@ -1,12 +1,32 @@
+use std::any::Any;
+
+use chrono::{DateTime, Local};
+
 use crate::domain::error::DomainError;
-use crate::domain::models::{Task, TaskId, User, UserId, WeekId};
+use crate::domain::models::{Admin, AdminRole, NewPoolTask, NewTaskVariant, PoolTask, PoolTaskId, Report, Task, TaskId, TaskVariant, User, UserId, UserTaskHistory, UserTaskState, WeekId};
 
 
 #[async_trait::async_trait]
 pub trait UserRepository: Send + Sync {
     async fn save(&self, user: &User) -> Result<(), DomainError>;
+
+    async fn save_in(&self, tx: &mut dyn Transaction, user: &User) -> Result<(), DomainError>;
+    
+    /// Bumps the version of `user` once it has been stored
+    async fn update(&self, user: &mut User) -> Result<(), DomainError>;
+    
+    /// Reads the user inside `tx`, locking it until the transaction ends
+    async fn user_in(&self, tx: &mut dyn Transaction, id: UserId) -> Result<User, DomainError>;
+    
+    /// Like [`Self::user_in`], but the user holds the task of `task_id` instead
+    /// of the latest one. Fails with [`DomainError::NoUserTask`] if there is none
+    async fn user_with_task_in(&self, tx: &mut dyn Transaction, id: UserId, task_id: TaskId) -> Result<User, DomainError>;
     
-    async fn update(&self, user: &User) -> Result<(), DomainError>;
+    /// The stored version is bumped once `tx` is committed, `user` is left as is
+    async fn update_in(&self, tx: &mut dyn Transaction, user: &User) -> Result<(), DomainError>;
+
+    /// Reads and locks the user currently holding `username`, if any
+    async fn user_by_username_in(&self, tx: &mut dyn Transaction, username: &str) -> Result<Option<User>, DomainError>;
     
     async fn user(&self, id: UserId) -> Result<User, DomainError>;
     
@@ -16,24 +36,122 @@ pub trait UserRepository: Send + Sync {
     
     async fn ready_users(&self) -> Result<Vec<User>, DomainError>;
     
+    async fn pending_users(&self) -> Result<Vec<User>, DomainError>;
+    
     async fn active_users(&self) -> Result<Vec<User>, DomainError>;
+    
+    /// Users holding a task in `state`, of `week_id` only if given. Each user
+    /// holds the matching task and appears once per such task
+    async fn users_with_task(&self, state: UserTaskState, week_id: Option<WeekId>) -> Result<Vec<User>, DomainError>;
+    
+    async fn history(&self, id: UserId) -> Result<UserTaskHistory, DomainError>;
+
+    /// Expires active tasks of all users for the weeks before `before`, whichever
+    /// task the users currently hold. Returns how many tasks have been expired
+    async fn expire_tasks_in(&self, tx: &mut dyn Transaction, before: WeekId) -> Result<u64, DomainError>;
 }
 
 
 #[async_trait::async_trait]
 pub trait AuthService: Send + Sync {
-    async fn is_admin(&self, user_id: UserId) -> Result<bool, DomainError>;
+    async fn role(&self, user_id: UserId) -> Result<Option<AdminRole>, DomainError>;
+
+    async fn admins(&self) -> Result<Vec<Admin>, DomainError>;
+
+    /// Grants the role to the user, replacing the previous one
+    async fn grant(&self, admin: &Admin) -> Result<(), DomainError>;
+
+    async fn revoke(&self, user_id: UserId) -> Result<(), DomainError>;
 }
 
 #[async_trait::async_trait]
 pub trait TaskRepository: Send + Sync {
     async fn save(&self, task: &Task) -> Result<(), DomainError>;
+
+    async fn save_in(&self, tx: &mut dyn Transaction, task: &Task) -> Result<(), DomainError>;
     
     async fn task(&self, id: TaskId) -> Result<Task, DomainError>;
+
+    /// All tasks, the latest weeks first
+    async fn list(&self) -> Result<Vec<Task>, DomainError>;
+
+    /// Tasks planned for weeks from `from` to `to` inclusive, ordered by week
+    async fn tasks_between(&self, from: TaskId, to: TaskId) -> Result<Vec<Task>, DomainError>;
+
+    /// Fails with [`DomainError::TaskInUse`] if the task has been assigned to users
+    async fn update(&self, task: &Task) -> Result<(), DomainError>;
+
+    /// Fails with [`DomainError::TaskInUse`] if the task has been assigned to users
+    async fn delete(&self, id: TaskId) -> Result<(), DomainError>;
+
+    /// Variants of the task ordered by number
+    async fn variants(&self, id: TaskId) -> Result<Vec<TaskVariant>, DomainError>;
+
+    /// Numbers the new variant after the existing ones
+    async fn add_variant(&self, id: TaskId, variant: &NewTaskVariant) -> Result<TaskVariant, DomainError>;
+
+    /// Fails with [`DomainError::TaskInUse`] if the variant has been assigned to users
+    async fn delete_variant(&self, id: TaskId, number: u32) -> Result<(), DomainError>;
+}
+
+/// Reusable tasks which are planned onto weeks later. A task stays used
+/// until the season is reset.
+#[async_trait::async_trait]
+pub trait TaskPoolRepository: Send + Sync {
+    async fn add(&self, task: &NewPoolTask) -> Result<PoolTask, DomainError>;
+
+    async fn all(&self) -> Result<Vec<PoolTask>, DomainError>;
+
+    /// Tasks not used this season, only the ones with `tag` if it is given
+    async fn available(&self, tag: Option<&str>) -> Result<Vec<PoolTask>, DomainError>;
+
+    /// Like [`Self::available`], but locks the tasks until `tx` ends and skips
+    /// the ones locked by other transactions
+    async fn available_in(&self, tx: &mut dyn Transaction, tag: Option<&str>) -> Result<Vec<PoolTask>, DomainError>;
+
+    /// Fails with [`DomainError::InvalidStateChange`] if the task is already used
+    async fn mark_used_in(&self, tx: &mut dyn Transaction, id: PoolTaskId, week_id: WeekId) -> Result<(), DomainError>;
+
+    /// Starts a new season. Returns how many tasks become available again
+    async fn reset_usage(&self) -> Result<u64, DomainError>;
+}
+
+#[async_trait::async_trait]
+pub trait ReportRepository: Send + Sync {
+    async fn save_in(&self, tx: &mut dyn Transaction, report: &Report) -> Result<(), DomainError>;
+    
+    async fn report(&self, user_id: UserId, task_id: TaskId) -> Result<Report, DomainError>;
+
+    /// Reports whose task is awaiting review, of any week, oldest first
+    async fn awaiting_review(&self) -> Result<Vec<Report>, DomainError>;
+}
+
+/// Opens transactions shared by several repository calls. Changes made
+/// through a transaction become visible only after it is committed.
+#[async_trait::async_trait]
+pub trait UnitOfWork: Send + Sync {
+    async fn begin(&self) -> Result<Box<dyn Transaction>, DomainError>;
+}
+
+/// A transaction is rolled back when dropped without being committed.
+#[async_trait::async_trait]
+pub trait Transaction: Send {
+    async fn commit(self: Box<Self>) -> Result<(), DomainError>;
+    
+    async fn rollback(self: Box<Self>) -> Result<(), DomainError>;
+    
+    /// Lets repositories reach their own transaction implementation
+    fn as_any_mut(&mut self) -> &mut dyn Any;
 }
 
 pub trait WeekService: Send + Sync {
     fn current(&self) -> WeekId;
     
     fn next(&self, week_id: WeekId) -> WeekId;
+    
+    fn start(&self, week_id: WeekId) -> DateTime<Local>;
+}
+
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Local>;
 }
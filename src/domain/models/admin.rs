@@ -0,0 +1,62 @@
+use std::str::FromStr;
+
+use crate::domain::error::DomainError;
+use crate::domain::models::UserId;
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdminRole {
+    /// Listed in `ADMIN_IDS`, the only role which can manage other admins
+    Owner,
+    /// Runs weekly meetings: tasks, pairs and completions
+    Admin,
+    /// Can only review meeting reports
+    Reviewer,
+}
+
+impl AdminRole {
+    pub fn can_manage_admins(&self) -> bool {
+        matches!(self, AdminRole::Owner)
+    }
+
+    pub fn can_manage_meetings(&self) -> bool {
+        matches!(self, AdminRole::Owner | AdminRole::Admin)
+    }
+
+    pub fn can_review(&self) -> bool {
+        true
+    }
+}
+
+impl FromStr for AdminRole {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "owner" => Ok(AdminRole::Owner),
+            "admin" => Ok(AdminRole::Admin),
+            "reviewer" => Ok(AdminRole::Reviewer),
+            _ => Err(DomainError::InvalidValue(format!("unknown admin role: {s}"))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Admin {
+    user_id: UserId,
+    role: AdminRole,
+}
+
+impl Admin {
+    pub fn new(user_id: impl Into<UserId>, role: AdminRole) -> Self {
+        Self { user_id: user_id.into(), role }
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn role(&self) -> AdminRole {
+        self.role
+    }
+}
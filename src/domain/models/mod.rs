@@ -3,9 +3,21 @@ mod profile;
 mod user_task;
 mod week;
 mod task;
+mod pairing;
+mod user_task_history;
+mod report;
+mod admin;
+mod task_pool;
+mod task_variant;
 
 pub use user::*;
 pub use profile::*;
 pub use user_task::*;
 pub use week::*;
 pub use task::*;
+pub use pairing::*;
+pub use user_task_history::*;
+pub use report::*;
+pub use admin::*;
+pub use task_pool::*;
+pub use task_variant::*;
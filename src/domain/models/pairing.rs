@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+
+use rand::seq::SliceRandom;
+
+use crate::domain::models::{UserId, UserTask, UserTaskHistory, WeekId};
+
+
+// Any repeated meeting must outweigh the recency of every other one,
+// so that repeats are only chosen when no alternative exists.
+const REPEAT_PENALTY: i64 = 1_000_000;
+
+#[derive(Debug, Clone, Default)]
+pub struct PartnerHistory {
+    last_met: HashMap<(UserId, UserId), WeekId>,
+}
+
+impl PartnerHistory {
+    pub fn record(&mut self, user_id: UserId, user_task: &UserTask) {
+        let week_id = user_task.task_id();
+        for &partner_id in user_task.partner_ids() {
+            self.last_met
+                .entry(Self::key(user_id, partner_id))
+                .and_modify(|last| *last = (*last).max(week_id))
+                .or_insert(week_id);
+        }
+    }
+
+    pub fn record_history(&mut self, history: &UserTaskHistory) {
+        for user_task in history.user_tasks() {
+            self.record(history.user_id(), user_task);
+        }
+    }
+
+    pub fn last_met(&self, a: UserId, b: UserId) -> Option<WeekId> {
+        self.last_met.get(&Self::key(a, b)).copied()
+    }
+
+    fn cost(&self, a: UserId, b: UserId) -> i64 {
+        match self.last_met(a, b) {
+            Some(week_id) => REPEAT_PENALTY + week_id.year as i64 * 53 + week_id.week as i64,
+            None => 0,
+        }
+    }
+
+    fn key(a: UserId, b: UserId) -> (UserId, UserId) {
+        if a.0 <= b.0 { (a, b) } else { (b, a) }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Pairing {
+    groups: Vec<Vec<UserId>>,
+    leftover: Vec<UserId>,
+}
+
+impl Pairing {
+    pub fn with_history(users: impl IntoIterator<Item = UserId>, history: &PartnerHistory) -> Self {
+        let mut users: Vec<UserId> = users.into_iter().collect();
+        users.shuffle(&mut rand::rng());
+
+        if users.len() < 2 {
+            return Pairing { groups: Vec::new(), leftover: users };
+        }
+
+        if users.len().is_multiple_of(2) {
+            let pairs = Self::pair_up(users, history);
+            return Pairing { groups: Self::into_groups(pairs), leftover: Vec::new() };
+        }
+
+        // With an odd count one user joins an existing pair. Try each user as the odd one out
+        // and keep the grouping with the fewest and oldest repeated meetings.
+        let mut best: Option<(i64, Vec<Vec<UserId>>)> = None;
+        for i in 0..users.len() {
+            let mut rest = users.clone();
+            let odd = rest.remove(i);
+
+            let pairs = Self::pair_up(rest, history);
+            let (join, join_cost) = pairs
+                .iter()
+                .enumerate()
+                .map(|(j, &(a, b))| (j, history.cost(odd, a) + history.cost(odd, b)))
+                .min_by_key(|&(_, cost)| cost)
+                .expect("at least one pair is formed from three or more users");
+            let cost = pairs.iter().map(|&(a, b)| history.cost(a, b)).sum::<i64>() + join_cost;
+
+            if best.as_ref().is_none_or(|(best_cost, _)| cost < *best_cost) {
+                let mut groups = Self::into_groups(pairs);
+                groups[join].push(odd);
+                best = Some((cost, groups));
+            }
+        }
+
+        let (_, groups) = best.expect("odd count of users is not empty");
+        Pairing { groups, leftover: Vec::new() }
+    }
+
+    pub fn groups(&self) -> &[Vec<UserId>] {
+        &self.groups
+    }
+
+    pub fn leftover(&self) -> &[UserId] {
+        &self.leftover
+    }
+
+    fn pair_up(mut users: Vec<UserId>, history: &PartnerHistory) -> Vec<(UserId, UserId)> {
+        let mut pairs = Vec::with_capacity(users.len() / 2);
+        while let Some(user) = users.pop() {
+            let Some((i, _)) = users
+                .iter()
+                .enumerate()
+                .min_by_key(|&(_, &other)| history.cost(user, other))
+            else {
+                break;
+            };
+            pairs.push((user, users.swap_remove(i)));
+        }
+
+        Self::improve(&mut pairs, history);
+        pairs
+    }
+
+    fn into_groups(pairs: Vec<(UserId, UserId)>) -> Vec<Vec<UserId>> {
+        pairs
+            .into_iter()
+            .map(|(a, b)| vec![a, b])
+            .collect()
+    }
+
+    fn improve(pairs: &mut [(UserId, UserId)], history: &PartnerHistory) {
+        let mut improved = true;
+        while improved {
+            improved = false;
+            for i in 0..pairs.len() {
+                for j in i + 1..pairs.len() {
+                    let (a, b) = pairs[i];
+                    let (c, d) = pairs[j];
+                    let current = history.cost(a, b) + history.cost(c, d);
+
+                    let candidates = [((a, c), (b, d)), ((a, d), (b, c))];
+                    let best = candidates
+                        .into_iter()
+                        .min_by_key(|&((w, x), (y, z))| history.cost(w, x) + history.cost(y, z))
+                        .unwrap();
+
+                    let ((w, x), (y, z)) = best;
+                    if history.cost(w, x) + history.cost(y, z) < current {
+                        pairs[i] = (w, x);
+                        pairs[j] = (y, z);
+                        improved = true;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use super::*;
+
+    fn user_ids(n: i64) -> Vec<UserId> {
+        (1..=n).map(UserId).collect()
+    }
+
+    fn met(history: &mut PartnerHistory, a: i64, b: i64, week: u32) {
+        history.record(UserId(a), &UserTask::new(WeekId::new(2025, week), vec![UserId(b)]));
+    }
+
+    fn is_group(pairing: &Pairing, ids: &[i64]) -> bool {
+        let ids: HashSet<UserId> = ids.iter().copied().map(UserId).collect();
+        pairing.groups()
+            .iter()
+            .any(|group| group.iter().copied().collect::<HashSet<_>>() == ids)
+    }
+
+    #[test]
+    fn test_random_pairing_even() {
+        let pairing = Pairing::with_history(user_ids(6), &PartnerHistory::default());
+
+        assert_eq!(pairing.groups().len(), 3);
+        assert!(pairing.groups().iter().all(|group| group.len() == 2));
+        assert!(pairing.leftover().is_empty());
+
+        let paired: HashSet<UserId> = pairing.groups()
+            .iter()
+            .flatten()
+            .copied()
+            .collect();
+        assert_eq!(paired, user_ids(6).into_iter().collect());
+    }
+
+    #[test]
+    fn test_random_pairing_odd() {
+        let pairing = Pairing::with_history(user_ids(5), &PartnerHistory::default());
+
+        assert_eq!(pairing.groups().len(), 2);
+        assert_eq!(pairing.groups().iter().filter(|group| group.len() == 3).count(), 1);
+        assert!(pairing.leftover().is_empty());
+    }
+
+    #[test]
+    fn test_random_pairing_single() {
+        let pairing = Pairing::with_history(user_ids(1), &PartnerHistory::default());
+
+        assert!(pairing.groups().is_empty());
+        assert_eq!(pairing.leftover(), &[UserId(1)]);
+    }
+
+    #[test]
+    fn test_random_pairing_empty() {
+        let pairing = Pairing::with_history(Vec::new(), &PartnerHistory::default());
+
+        assert!(pairing.groups().is_empty());
+        assert!(pairing.leftover().is_empty());
+    }
+
+    #[test]
+    fn test_pairing_avoids_repeats() {
+        let mut history = PartnerHistory::default();
+        met(&mut history, 1, 2, 10);
+        met(&mut history, 3, 4, 10);
+        met(&mut history, 1, 3, 11);
+        met(&mut history, 2, 4, 11);
+
+        for _ in 0..20 {
+            let pairing = Pairing::with_history(user_ids(4), &history);
+            assert!(is_group(&pairing, &[1, 4]));
+            assert!(is_group(&pairing, &[2, 3]));
+        }
+    }
+
+    #[test]
+    fn test_pairing_prefers_least_recently_met() {
+        let mut history = PartnerHistory::default();
+        met(&mut history, 1, 2, 10);
+        met(&mut history, 3, 4, 10);
+        met(&mut history, 1, 3, 11);
+        met(&mut history, 2, 4, 11);
+        met(&mut history, 1, 4, 12);
+        met(&mut history, 2, 3, 12);
+
+        for _ in 0..20 {
+            let pairing = Pairing::with_history(user_ids(4), &history);
+            assert!(is_group(&pairing, &[1, 2]));
+            assert!(is_group(&pairing, &[3, 4]));
+        }
+    }
+
+    #[test]
+    fn test_pairing_triple_avoids_repeats() {
+        let mut history = PartnerHistory::default();
+        met(&mut history, 1, 2, 10);
+        met(&mut history, 1, 3, 10);
+        met(&mut history, 4, 5, 10);
+
+        for _ in 0..20 {
+            let pairing = Pairing::with_history(user_ids(5), &history);
+            let triple = pairing.groups()
+                .iter()
+                .find(|group| group.len() == 3)
+                .expect("five users should make a triple");
+            assert!(!(triple.contains(&UserId(1)) && triple.contains(&UserId(2))));
+            assert!(!(triple.contains(&UserId(1)) && triple.contains(&UserId(3))));
+            assert!(!(triple.contains(&UserId(4)) && triple.contains(&UserId(5))));
+        }
+    }
+
+    #[test]
+    fn test_partner_history_is_symmetric() {
+        let mut history = PartnerHistory::default();
+        met(&mut history, 1, 2, 10);
+        met(&mut history, 2, 1, 12);
+
+        assert_eq!(history.last_met(UserId(1), UserId(2)), Some(WeekId::new(2025, 12)));
+        assert_eq!(history.last_met(UserId(2), UserId(1)), Some(WeekId::new(2025, 12)));
+        assert_eq!(history.last_met(UserId(1), UserId(3)), None);
+    }
+}
@@ -1,3 +1,62 @@
+use std::str::FromStr;
+
+use regex::Regex;
+
+use crate::domain::error::DomainError;
+
+
+const FULL_NAME_MAX_LEN: usize = 128;
+const GROUP_NAME_MAX_LEN: usize = 16;
+const DEFAULT_GROUP_NAME_PATTERN: &str = r"[А-ЯЁ]{1,4}\d{0,2}-\d{1,3}[А-ЯЁ]?";
+
+/// Patterns a group name must match, e.g. `СМ11-11Б` for the default one.
+/// Each pattern has to match the whole normalized group name.
+#[derive(Clone, Debug)]
+pub struct GroupNamePatterns {
+    patterns: Vec<Regex>,
+}
+
+impl GroupNamePatterns {
+    pub fn new<I, S>(patterns: I) -> Result<Self, DomainError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let patterns = patterns
+            .into_iter()
+            .map(|pattern| {
+                Regex::new(&format!("^(?:{})$", pattern.as_ref()))
+                    .map_err(|err| DomainError::InvalidValue(format!("invalid group name pattern: {err}")))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if patterns.is_empty() {
+            return Err(DomainError::InvalidValue("at least one group name pattern is required".to_string()));
+        }
+
+        Ok(Self { patterns })
+    }
+
+    pub fn matches(&self, group_name: &str) -> bool {
+        self.patterns.iter().any(|pattern| pattern.is_match(group_name))
+    }
+}
+
+impl Default for GroupNamePatterns {
+    fn default() -> Self {
+        Self::new([DEFAULT_GROUP_NAME_PATTERN]).expect("default group name pattern must be valid")
+    }
+}
+
+/// Parses `;`-separated patterns
+impl FromStr for GroupNamePatterns {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.split(';').map(str::trim).filter(|pattern| !pattern.is_empty()))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Profile {
     full_name: String,
@@ -5,18 +64,146 @@ pub struct Profile {
 }
 
 impl Profile {
-    pub fn new(full_name: impl Into<String>, group_name: impl Into<String>) -> Profile {
-        Profile { 
-            full_name: full_name.into(), 
+    pub fn new(
+        full_name: &str,
+        group_name: &str,
+        group_name_patterns: &GroupNamePatterns,
+    ) -> Result<Profile, DomainError> {
+        Ok(Profile {
+            full_name: Self::normalize_full_name(full_name)?,
+            group_name: Self::normalize_group_name(group_name, group_name_patterns)?,
+        })
+    }
+
+    /// Restores a profile which has already been validated before it was stored
+    pub fn restore(full_name: impl Into<String>, group_name: impl Into<String>) -> Profile {
+        Profile {
+            full_name: full_name.into(),
             group_name: group_name.into(),
         }
     }
-    
+
+    /// Collapses whitespace and capitalizes each part of `Фамилия Имя [Отчество]`
+    pub fn normalize_full_name(full_name: &str) -> Result<String, DomainError> {
+        let parts: Vec<&str> = full_name.split_whitespace().collect();
+        if !(2..=3).contains(&parts.len()) {
+            return Err(DomainError::InvalidFullName(full_name.to_string()));
+        }
+
+        let mut normalized = Vec::with_capacity(parts.len());
+        for part in parts {
+            let words = part
+                .split('-')
+                .map(capitalize)
+                .collect::<Option<Vec<_>>>()
+                .ok_or_else(|| DomainError::InvalidFullName(full_name.to_string()))?;
+            normalized.push(words.join("-"));
+        }
+
+        let normalized = normalized.join(" ");
+        if normalized.chars().count() > FULL_NAME_MAX_LEN {
+            return Err(DomainError::InvalidFullName(full_name.to_string()));
+        }
+
+        Ok(normalized)
+    }
+
+    /// Removes whitespace, uppercases and checks the group name against the patterns
+    pub fn normalize_group_name(
+        group_name: &str,
+        patterns: &GroupNamePatterns,
+    ) -> Result<String, DomainError> {
+        let normalized: String = group_name
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .flat_map(char::to_uppercase)
+            .collect();
+
+        if normalized.chars().count() > GROUP_NAME_MAX_LEN || !patterns.matches(&normalized) {
+            return Err(DomainError::InvalidGroupName(group_name.to_string()));
+        }
+
+        Ok(normalized)
+    }
+
     pub fn full_name(&self) -> &str {
         &self.full_name
     }
-    
+
     pub fn group_name(&self) -> &str {
         &self.group_name
     }
 }
+
+/// Returns `None` if the word is empty or contains anything but letters
+fn capitalize(word: &str) -> Option<String> {
+    if word.is_empty() || !word.chars().all(char::is_alphabetic) {
+        return None;
+    }
+
+    let mut chars = word.chars();
+    let first = chars.next()?;
+    Some(first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_profile_normalizes_input() {
+        let profile = Profile::new("  иванов   иван ИВАНОВИЧ ", " см11-11б ", &GroupNamePatterns::default())
+            .expect("failed to create profile");
+
+        assert_eq!(profile.full_name(), "Иванов Иван Иванович");
+        assert_eq!(profile.group_name(), "СМ11-11Б");
+    }
+
+    #[test]
+    fn test_full_name_with_hyphen() {
+        let full_name = Profile::normalize_full_name("римская-корсакова анна")
+            .expect("failed to normalize full name");
+
+        assert_eq!(full_name, "Римская-Корсакова Анна");
+    }
+
+    #[test]
+    fn test_invalid_full_name() {
+        for full_name in ["Иванов", "", "Иванов Иван Иванович Младший", "Иванов 1ван", "Иванов - Иван", "@ivanov ivan"] {
+            let result = Profile::normalize_full_name(full_name);
+            assert!(
+                matches!(result, Err(DomainError::InvalidFullName(_))),
+                "expected {full_name:?} to be rejected, got {result:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_group_name() {
+        let patterns = GroupNamePatterns::default();
+        for group_name in ["СМ11", "hello", "СМ11-11Б-СМ11-11Б", ""] {
+            let result = Profile::normalize_group_name(group_name, &patterns);
+            assert!(
+                matches!(result, Err(DomainError::InvalidGroupName(_))),
+                "expected {group_name:?} to be rejected, got {result:?}",
+            );
+        }
+    }
+
+    #[test]
+    fn test_configured_group_name_patterns() {
+        let patterns: GroupNamePatterns = r"ИУ\d-\d{2}Б; РК\d-\d{2}"
+            .parse()
+            .expect("failed to parse patterns");
+
+        assert_eq!(Profile::normalize_group_name("иу7-31б", &patterns).unwrap(), "ИУ7-31Б");
+        assert_eq!(Profile::normalize_group_name("РК6-51", &patterns).unwrap(), "РК6-51");
+        assert!(Profile::normalize_group_name("СМ11-11Б", &patterns).is_err());
+    }
+
+    #[test]
+    fn test_invalid_group_name_patterns() {
+        assert!("".parse::<GroupNamePatterns>().is_err());
+        assert!("[".parse::<GroupNamePatterns>().is_err());
+    }
+}
@@ -0,0 +1,43 @@
+use crate::domain::error::DomainError;
+use crate::domain::models::{TaskId, UserId};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    user_id: UserId,
+    task_id: TaskId,
+    photo_file_ids: Vec<String>,
+    caption: Option<String>,
+}
+
+impl Report {
+    pub fn new(
+        user_id: impl Into<UserId>,
+        task_id: impl Into<TaskId>,
+        photo_file_ids: Vec<String>,
+        caption: Option<String>,
+    ) -> Result<Self, DomainError> {
+        if photo_file_ids.is_empty() {
+            return Err(DomainError::InvalidValue("report must contain at least one photo".to_string()));
+        }
+        let caption = caption
+            .map(|caption| caption.trim().to_string())
+            .filter(|caption| !caption.is_empty());
+        Ok(Self { user_id: user_id.into(), task_id: task_id.into(), photo_file_ids, caption })
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn task_id(&self) -> TaskId {
+        self.task_id
+    }
+
+    pub fn photo_file_ids(&self) -> &[String] {
+        &self.photo_file_ids
+    }
+
+    pub fn caption(&self) -> Option<&str> {
+        self.caption.as_deref()
+    }
+}
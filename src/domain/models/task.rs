@@ -2,16 +2,31 @@ use crate::domain::models::WeekId;
 
 pub type TaskId = WeekId;
 
+/// Attachment sent to participants along with the task text.
+/// Files are kept on Telegram servers and referenced by their `file_id`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TaskMedia {
+    Photo { file_id: String },
+    Document { file_id: String },
+    Location { latitude: f64, longitude: f64 },
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct Task {
     id: TaskId,
     title: String,
     description: String,
+    media: Option<TaskMedia>,
 }
 
 impl Task {
     pub fn new(task_id: impl Into<TaskId>, title: impl Into<String>, description: impl Into<String>) -> Self {
-        Self { id: task_id.into(), title: title.into(), description: description.into() }
+        Self { id: task_id.into(), title: title.into(), description: description.into(), media: None }
+    }
+
+    pub fn with_media(mut self, media: Option<TaskMedia>) -> Self {
+        self.media = media;
+        self
     }
     
     pub fn id(&self) -> TaskId {
@@ -25,4 +40,8 @@ impl Task {
     pub fn description(&self) -> &str {
         &self.description
     }
+
+    pub fn media(&self) -> Option<&TaskMedia> {
+        self.media.as_ref()
+    }
 }
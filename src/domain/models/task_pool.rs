@@ -0,0 +1,167 @@
+use std::fmt::Display;
+
+use rand::seq::IndexedRandom;
+
+use crate::domain::error::DomainError;
+use crate::domain::models::{Task, WeekId};
+
+
+const TITLE_MAX_LEN: usize = 128;
+// Tags go into callback data, which Telegram limits to 64 bytes
+const TAG_MAX_LEN: usize = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PoolTaskId(pub i64);
+
+impl From<i64> for PoolTaskId {
+    fn from(id: i64) -> Self {
+        Self(id)
+    }
+}
+
+impl Display for PoolTaskId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// A task for the pool which has not been stored yet
+#[derive(Clone, Debug, PartialEq)]
+pub struct NewPoolTask {
+    title: String,
+    description: String,
+    tags: Vec<String>,
+}
+
+impl NewPoolTask {
+    pub fn new(title: &str, description: &str, tags: &str) -> Result<Self, DomainError> {
+        let title = title.trim();
+        if title.is_empty() || title.chars().count() > TITLE_MAX_LEN {
+            return Err(DomainError::InvalidValue(format!("invalid pool task title: {title}")));
+        }
+
+        Ok(Self {
+            title: title.to_string(),
+            description: description.trim().to_string(),
+            tags: normalize_tags(tags)?,
+        })
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+}
+
+/// A reusable task which is not bound to a week until it is planned
+#[derive(Clone, Debug, PartialEq)]
+pub struct PoolTask {
+    id: PoolTaskId,
+    title: String,
+    description: String,
+    tags: Vec<String>,
+    used_in: Option<WeekId>,
+}
+
+impl PoolTask {
+    pub fn restore(
+        id: impl Into<PoolTaskId>,
+        title: impl Into<String>,
+        description: impl Into<String>,
+        tags: Vec<String>,
+        used_in: Option<WeekId>,
+    ) -> Self {
+        Self { id: id.into(), title: title.into(), description: description.into(), tags, used_in }
+    }
+
+    /// Picks a random task among the ones not used this season
+    pub fn pick(tasks: &[PoolTask]) -> Option<&PoolTask> {
+        let available = tasks
+            .iter()
+            .filter(|task| !task.is_used())
+            .collect::<Vec<_>>();
+        available.choose(&mut rand::rng()).copied()
+    }
+
+    pub fn is_used(&self) -> bool {
+        self.used_in.is_some()
+    }
+
+    /// The week task planned from this pool task
+    pub fn to_task(&self, week_id: WeekId) -> Task {
+        Task::new(week_id, self.title.as_str(), self.description.as_str())
+    }
+
+    pub fn id(&self) -> PoolTaskId {
+        self.id
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Week the task was planned for during the current season
+    pub fn used_in(&self) -> Option<WeekId> {
+        self.used_in
+    }
+}
+
+/// Splits tags by whitespace or commas, drops leading `#` and lowercases them.
+/// `-` stands for no tags at all.
+pub fn normalize_tags(tags: &str) -> Result<Vec<String>, DomainError> {
+    let mut normalized: Vec<String> = Vec::new();
+    for tag in tags.split(|c: char| c.is_whitespace() || c == ',') {
+        let tag = tag.trim_start_matches('#').to_lowercase();
+        if tag.is_empty() || tag == "-" {
+            continue;
+        }
+
+        let valid = tag.chars().count() <= TAG_MAX_LEN
+            && tag.chars().all(|c| c.is_alphanumeric() || c == '_' || c == '-');
+        if !valid {
+            return Err(DomainError::InvalidValue(format!("invalid tag: {tag}")));
+        }
+
+        if !normalized.contains(&tag) {
+            normalized.push(tag);
+        }
+    }
+    Ok(normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_tags() {
+        assert_eq!(normalize_tags("#Спорт, прогулка  #спорт").unwrap(), vec!["спорт", "прогулка"]);
+        assert_eq!(normalize_tags("-").unwrap(), Vec::<String>::new());
+        assert!(normalize_tags("#<b>").is_err());
+        assert!(normalize_tags("оченьдлинныйтегдлякнопки").is_err());
+    }
+
+    #[test]
+    fn test_pick_skips_used_tasks() {
+        let tasks = vec![
+            PoolTask::restore(1, "Used", "", Vec::new(), Some(WeekId::new(2025, 1))),
+            PoolTask::restore(2, "Free", "", Vec::new(), None),
+        ];
+
+        for _ in 0..10 {
+            assert_eq!(PoolTask::pick(&tasks).map(|task| task.id()), Some(PoolTaskId(2)));
+        }
+        assert_eq!(PoolTask::pick(&tasks[..1]), None);
+    }
+}
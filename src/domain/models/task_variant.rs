@@ -0,0 +1,141 @@
+use rand::seq::{IndexedRandom, SliceRandom};
+
+use crate::domain::error::DomainError;
+
+
+const TITLE_MAX_LEN: usize = 128;
+
+/// A variant which has not been numbered and stored yet
+#[derive(Clone, Debug, PartialEq)]
+pub struct NewTaskVariant {
+    title: String,
+    description: String,
+}
+
+impl NewTaskVariant {
+    pub fn new(title: &str, description: &str) -> Result<Self, DomainError> {
+        let title = title.trim();
+        if title.is_empty() || title.chars().count() > TITLE_MAX_LEN {
+            return Err(DomainError::InvalidValue(format!("invalid task variant title: {title}")));
+        }
+
+        Ok(Self {
+            title: title.to_string(),
+            description: description.trim().to_string(),
+        })
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+/// An alternative to the main task of a week. Pairs of the same week may get
+/// different variants, both partners of a pair always get the same one.
+/// Variants are numbered within their task.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TaskVariant {
+    number: u32,
+    title: String,
+    description: String,
+}
+
+impl TaskVariant {
+    pub fn new(number: u32, title: impl Into<String>, description: impl Into<String>) -> Self {
+        Self { number, title: title.into(), description: description.into() }
+    }
+
+    /// Picks a random variant which is not among `used`, `None` stands for
+    /// the main task. Any of them may repeat once all have been used
+    pub fn pick(variants: &[TaskVariant], used: &[Option<u32>]) -> Option<u32> {
+        let choices = Self::choices(variants);
+        let unused: Vec<Option<u32>> = choices
+            .iter()
+            .copied()
+            .filter(|choice| !used.contains(choice))
+            .collect();
+        let choices = if unused.is_empty() { choices } else { unused };
+        *choices.choose(&mut rand::rng()).unwrap_or(&None)
+    }
+
+    /// Spreads the main task and its variants over `groups` pairs in random
+    /// order, so that a variant repeats only when there are more pairs than variants
+    pub fn distribute(variants: &[TaskVariant], groups: usize) -> Vec<Option<u32>> {
+        let mut choices = Self::choices(variants);
+        choices.shuffle(&mut rand::rng());
+        choices.into_iter().cycle().take(groups).collect()
+    }
+
+    fn choices(variants: &[TaskVariant]) -> Vec<Option<u32>> {
+        std::iter::once(None)
+            .chain(variants.iter().map(|variant| Some(variant.number)))
+            .collect()
+    }
+
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use super::*;
+
+    #[test]
+    fn test_distribute_avoids_repeats() {
+        let variants = vec![
+            TaskVariant::new(1, "First", ""),
+            TaskVariant::new(2, "Second", ""),
+        ];
+
+        let assigned = TaskVariant::distribute(&variants, 3);
+        assert_eq!(assigned.iter().collect::<HashSet<_>>().len(), 3);
+
+        let assigned = TaskVariant::distribute(&variants, 5);
+        assert_eq!(assigned.len(), 5);
+        for choice in [None, Some(1), Some(2)] {
+            let count = assigned.iter().filter(|&&variant| variant == choice).count();
+            assert!((1..=2).contains(&count), "{choice:?} is assigned {count} times");
+        }
+    }
+
+    #[test]
+    fn test_pick_avoids_used_variants() {
+        let variants = vec![
+            TaskVariant::new(1, "First", ""),
+            TaskVariant::new(2, "Second", ""),
+        ];
+
+        for _ in 0..10 {
+            assert_eq!(TaskVariant::pick(&variants, &[None, Some(1)]), Some(2));
+        }
+        let used = [None, Some(1), Some(2)];
+        assert!(used.contains(&TaskVariant::pick(&variants, &used)));
+    }
+
+    #[test]
+    fn test_new_variant_title_length() {
+        assert!(NewTaskVariant::new(&"а".repeat(TITLE_MAX_LEN), "").is_ok());
+        assert!(matches!(NewTaskVariant::new(&"а".repeat(TITLE_MAX_LEN + 1), ""), Err(DomainError::InvalidValue(_))));
+        assert!(matches!(NewTaskVariant::new("  ", ""), Err(DomainError::InvalidValue(_))));
+    }
+
+    #[test]
+    fn test_without_variants_only_main_task() {
+        assert_eq!(TaskVariant::pick(&[], &[]), None);
+        assert_eq!(TaskVariant::distribute(&[], 2), vec![None, None]);
+    }
+}
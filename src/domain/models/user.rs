@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::fmt::Display;
 
 use crate::domain::error::DomainError;
@@ -5,6 +6,10 @@ use crate::domain::models::profile::Profile;
 use crate::domain::models::{UserTask, UserTaskState, WeekId};
 
 
+pub const MIN_GROUP_SIZE: usize = 2;
+pub const MAX_GROUP_SIZE: usize = 3;
+
+
 #[derive(Default, Debug, Clone, Copy, PartialEq)]
 pub enum NextTaskStatus {
     #[default]
@@ -20,19 +25,21 @@ pub struct UserId(pub i64);
 #[derive(PartialEq)]
 pub struct User {
     id: UserId,
-    username: String,
+    username: Option<String>,
 
     profile: Option<Profile>,
     user_task: Option<UserTask>,
     next_task_status: NextTaskStatus,
     completed_tasks: i32,
+    /// Version of the stored record the user was loaded from
+    version: i32,
 }
 
 impl User {
-    pub fn new(telegram_id: i64, username: impl Into<String>) -> Self {
+    pub fn new(telegram_id: i64, username: Option<impl Into<String>>) -> Self {
         Self {
             id: UserId(telegram_id),
-            username: username.into(),
+            username: username.map(Into::into),
             ..Default::default()
         }
     }
@@ -65,16 +72,49 @@ impl User {
         Ok(())
     }
     
-    pub fn promote(&mut self, partner_id: UserId, week_id: WeekId) -> Result<(), DomainError> {
+    pub fn promote(&mut self, partner_ids: &[UserId], week_id: WeekId) -> Result<(), DomainError> {
+        self.promote_with_variant(partner_ids, week_id, None)
+    }
+
+    fn promote_with_variant(
+        &mut self,
+        partner_ids: &[UserId],
+        week_id: WeekId,
+        variant: Option<u32>,
+    ) -> Result<(), DomainError> {
         if self.next_task_status != NextTaskStatus::Accepted {
             return Err(DomainError::InvalidStateChange(
                 format!("{:?} -> {:?}", self.next_task_status, NextTaskStatus::Accepted)
             ))
         }
-        self.user_task = Some(UserTask::new(week_id, partner_id));
+        self.user_task = Some(UserTask::new(week_id, partner_ids.to_vec()).with_variant(variant));
         self.next_task_status = NextTaskStatus::default();
         Ok(())
     }
+
+    /// All members of the group get the same task `variant`
+    pub fn promote_group(users: &mut [User], week_id: WeekId, variant: Option<u32>) -> Result<(), DomainError> {
+        if !(MIN_GROUP_SIZE..=MAX_GROUP_SIZE).contains(&users.len()) {
+            return Err(DomainError::InvalidValue(
+                format!("group of {} users", users.len())
+            ))
+        }
+        let ids: Vec<UserId> = users.iter().map(|user| user.id).collect();
+        if ids.iter().collect::<HashSet<_>>().len() != ids.len() {
+            return Err(DomainError::InvalidValue(
+                String::from("group contains the same user twice")
+            ))
+        }
+        for user in users.iter_mut() {
+            let partner_ids: Vec<UserId> = ids
+                .iter()
+                .copied()
+                .filter(|&id| id != user.id)
+                .collect();
+            user.promote_with_variant(&partner_ids, week_id, variant)?;
+        }
+        Ok(())
+    }
     
     pub fn complete_task(&mut self) -> Result<(), DomainError> {
         let user_task = self.user_task.as_mut().ok_or(DomainError::NoUserTask)?;
@@ -83,6 +123,24 @@ impl User {
         Ok(())
     }
     
+    pub fn submit_report(&mut self) -> Result<(), DomainError> {
+        let user_task = self.user_task.as_mut().ok_or(DomainError::NoUserTask)?;
+        user_task.submit_for_review()
+    }
+    
+    pub fn reject_report(&mut self) -> Result<(), DomainError> {
+        let user_task = self.user_task.as_mut().ok_or(DomainError::NoUserTask)?;
+        user_task.reopen()
+    }
+    
+    pub fn reset_next_task_status(&mut self) -> bool {
+        if self.next_task_status != NextTaskStatus::Rejected {
+            return false;
+        }
+        self.next_task_status = NextTaskStatus::Pending;
+        true
+    }
+    
     pub fn is_ready(&self) -> bool {
         self.next_task_status == NextTaskStatus::Accepted
     }
@@ -95,21 +153,27 @@ impl User {
 
     pub fn restore(
         id: impl Into<UserId>,
-        username: impl Into<String>,
+        username: Option<String>,
         profile: Option<Profile>,
         user_task: Option<UserTask>,
         next_task_status: NextTaskStatus,
         completed_tasks: i32,
+        version: i32,
     ) -> Self {
-        Self { id: id.into(), username: username.into(), profile, user_task, next_task_status, completed_tasks }
+        Self { id: id.into(), username, profile, user_task, next_task_status, completed_tasks, version }
     }
     
     pub fn id(&self) -> UserId {
         self.id
     }
     
-    pub fn username(&self) -> &str {
-        &self.username
+    pub fn username(&self) -> Option<&str> {
+        self.username.as_deref()
+    }
+
+    /// Telegram usernames can be changed, removed or passed to another account at any time
+    pub fn set_username(&mut self, username: Option<String>) {
+        self.username = username;
     }
     
     pub fn profile(&self) -> Option<&Profile> {
@@ -128,6 +192,15 @@ impl User {
         self.next_task_status
     }
     
+    pub fn version(&self) -> i32 {
+        self.version
+    }
+    
+    /// Called by repositories once the user has been stored
+    pub(crate) fn increment_version(&mut self) {
+        self.version += 1;
+    }
+    
     pub fn completed_tasks(&self) -> i32 {
         self.completed_tasks
     }
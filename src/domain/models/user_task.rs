@@ -4,19 +4,28 @@ use crate::domain::models::{TaskId, UserId};
 #[derive(Debug, Clone, PartialEq)]
 pub enum UserTaskState {
     Active,
+    AwaitingReview,
     Completed,
+    Expired,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct UserTask {
     task_id: TaskId,
-    partner_id: UserId,
+    partner_ids: Vec<UserId>,
     state: UserTaskState,
+    /// Number of the task variant, `None` for the main task
+    variant: Option<u32>,
 }
 
 impl UserTask {
-    pub fn new(task_id: impl Into<TaskId>, partner_id: impl Into<UserId>) -> Self {
-        Self { task_id: task_id.into(), partner_id: partner_id.into(), state: UserTaskState::Active }
+    pub fn new(task_id: impl Into<TaskId>, partner_ids: Vec<UserId>) -> Self {
+        Self { task_id: task_id.into(), partner_ids, state: UserTaskState::Active, variant: None }
+    }
+
+    pub fn with_variant(mut self, variant: Option<u32>) -> Self {
+        self.variant = variant;
+        self
     }
 
     pub fn is_completed(&self) -> bool {
@@ -25,7 +34,7 @@ impl UserTask {
 
     pub fn complete(&mut self) -> Result<(), DomainError> {
         match self.state {
-            UserTaskState::Active => {
+            UserTaskState::Active | UserTaskState::AwaitingReview => {
                 self.state = UserTaskState::Completed;
                 Ok(())
             },
@@ -35,23 +44,71 @@ impl UserTask {
         }
     }
     
+    pub fn submit_for_review(&mut self) -> Result<(), DomainError> {
+        match self.state {
+            UserTaskState::Active => {
+                self.state = UserTaskState::AwaitingReview;
+                Ok(())
+            },
+            _  => Err(DomainError::InvalidStateChange(
+                format!("{:?} -> {:?}", self.state, UserTaskState::AwaitingReview)
+            )),
+        }
+    }
+    
+    pub fn reopen(&mut self) -> Result<(), DomainError> {
+        match self.state {
+            UserTaskState::AwaitingReview => {
+                self.state = UserTaskState::Active;
+                Ok(())
+            },
+            _  => Err(DomainError::InvalidStateChange(
+                format!("{:?} -> {:?}", self.state, UserTaskState::Active)
+            )),
+        }
+    }
+    
+    pub fn is_awaiting_review(&self) -> bool {
+        self.state == UserTaskState::AwaitingReview
+    }
+    
+    pub fn expire(&mut self) -> Result<(), DomainError> {
+        match self.state {
+            UserTaskState::Active => {
+                self.state = UserTaskState::Expired;
+                Ok(())
+            },
+            _  => Err(DomainError::InvalidStateChange(
+                format!("{:?} -> {:?}", self.state, UserTaskState::Expired)
+            )),
+        }
+    }
+    
+    pub fn is_expired(&self) -> bool {
+        self.state == UserTaskState::Expired
+    }
+    
     pub fn restore(
         task_id: impl Into<TaskId>, 
-        partner_id: impl Into<UserId>,
+        partner_ids: Vec<UserId>,
         state: UserTaskState,
     ) -> Self {
-        Self { task_id: task_id.into(), partner_id: partner_id.into(), state }
+        Self { task_id: task_id.into(), partner_ids, state, variant: None }
     }
     
     pub fn task_id(&self) -> TaskId {
         self.task_id
     }
     
-    pub fn partner_id(&self) -> UserId {
-        self.partner_id
+    pub fn partner_ids(&self) -> &[UserId] {
+        &self.partner_ids
     }
     
     pub fn state(&self) -> &UserTaskState {
         &self.state
     }
+
+    pub fn variant(&self) -> Option<u32> {
+        self.variant
+    }
 }
@@ -0,0 +1,24 @@
+use crate::domain::models::{UserId, UserTask};
+
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UserTaskHistory {
+    user_id: UserId,
+    user_tasks: Vec<UserTask>,
+}
+
+impl UserTaskHistory {
+    pub fn new(user_id: impl Into<UserId>, user_tasks: impl IntoIterator<Item = UserTask>) -> Self {
+        let mut user_tasks: Vec<UserTask> = user_tasks.into_iter().collect();
+        user_tasks.sort_by_key(|ut| ut.task_id());
+        Self { user_id: user_id.into(), user_tasks }
+    }
+
+    pub fn user_id(&self) -> UserId {
+        self.user_id
+    }
+
+    pub fn user_tasks(&self) -> &[UserTask] {
+        &self.user_tasks
+    }
+}
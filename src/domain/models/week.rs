@@ -1,6 +1,6 @@
 use std::fmt::Display;
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct WeekId {
     pub year: i32,
     pub week: u32,
@@ -12,9 +12,9 @@ impl WeekId {
     }
 }
 
-impl Into<(i32, u32)> for WeekId {
-    fn into(self) -> (i32, u32) {
-        (self.year, self.week)
+impl From<WeekId> for (i32, u32) {
+    fn from(week_id: WeekId) -> Self {
+        (week_id.year, week_id.week)
     }
 }
 
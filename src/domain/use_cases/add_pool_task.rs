@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::TaskPoolRepository;
+use crate::domain::models::NewPoolTask;
+
+
+#[derive(Clone)]
+pub struct AddPoolTaskUseCase {
+    pool_repo: Arc<dyn TaskPoolRepository>,
+}
+
+impl AddPoolTaskUseCase {
+    pub fn new(pool_repo: Arc<dyn TaskPoolRepository>) -> Self {
+        Self { pool_repo }
+    }
+
+    /// `tags` are separated by whitespace or commas, `-` for none
+    pub async fn execute(&self, title: &str, description: &str, tags: &str) -> Result<(), DomainError> {
+        let task = NewPoolTask::new(title, description, tags)?;
+        self.pool_repo.add(&task).await?;
+        Ok(())
+    }
+}
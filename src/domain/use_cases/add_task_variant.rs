@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::TaskRepository;
+use crate::domain::models::{NewTaskVariant, WeekId};
+
+
+#[derive(Clone)]
+pub struct AddTaskVariantUseCase {
+    task_repo: Arc<dyn TaskRepository>,
+}
+
+impl AddTaskVariantUseCase {
+    pub fn new(task_repo: Arc<dyn TaskRepository>) -> Self {
+        Self { task_repo }
+    }
+
+    pub async fn execute(&self, week_id: (i32, u32), title: &str, description: &str) -> Result<u32, DomainError> {
+        let variant = NewTaskVariant::new(title, description)?;
+        let variant = self.task_repo
+            .add_variant(WeekId::new(week_id.0, week_id.1), &variant)
+            .await?;
+        Ok(variant.number())
+    }
+}
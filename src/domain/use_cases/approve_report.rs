@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{Transaction, UnitOfWork, UserRepository};
+use crate::domain::models::{TaskId, WeekId};
+use crate::domain::use_cases::{CompleteTaskUseCase, CompletionScope};
+
+
+#[derive(Clone)]
+pub struct ApproveReportUseCase {
+    uow: Arc<dyn UnitOfWork>,
+    user_repo: Arc<dyn UserRepository>,
+    complete_task_use_case: CompleteTaskUseCase,
+}
+
+impl ApproveReportUseCase {
+    pub fn new(
+        uow: Arc<dyn UnitOfWork>,
+        user_repo: Arc<dyn UserRepository>,
+        complete_task_use_case: CompleteTaskUseCase,
+    ) -> Self {
+        Self { uow, user_repo, complete_task_use_case }
+    }
+
+    /// Returns ids of the users whose task has been completed
+    pub async fn execute(&self, user_id: i64, week_id: (i32, u32)) -> Result<Vec<i64>, DomainError> {
+        let mut tx = self.uow.begin().await?;
+        match self.approve(tx.as_mut(), user_id, WeekId::new(week_id.0, week_id.1)).await {
+            Ok(completed) => {
+                tx.commit().await?;
+                Ok(completed)
+            }
+            Err(err) => {
+                if let Err(rollback_err) = tx.rollback().await {
+                    log::error!("failed to roll back transaction: {}", rollback_err);
+                }
+                Err(err)
+            }
+        }
+    }
+
+    async fn approve(&self, tx: &mut dyn Transaction, user_id: i64, task_id: TaskId) -> Result<Vec<i64>, DomainError> {
+        let user = self.user_repo.user_with_task_in(tx, user_id.into(), task_id).await?;
+        let user_task = user.user_task()
+            .ok_or(DomainError::NoUserTask)?;
+        if !user_task.is_awaiting_review() {
+            return Err(DomainError::InvalidStateChange(
+                format!("{:?} -> report approval", user_task.state())
+            ));
+        }
+
+        self.complete_task_use_case
+            .complete_in(tx, user, CompletionScope::Pair)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::models::{User, UserTaskState, WeekId};
+    use crate::services::{InMemoryUnitOfWork, InMemoryUserRepository};
+    use super::*;
+
+    async fn setup() -> (ApproveReportUseCase, Arc<InMemoryUserRepository>) {
+        let user_repo = Arc::new(InMemoryUserRepository::default());
+
+        let mut users = [User::new(1, Some("alice")), User::new(2, Some("bob"))];
+        for user in users.iter_mut() {
+            user.accept().unwrap();
+        }
+        User::promote_group(&mut users, WeekId::new(2025, 12), None).unwrap();
+        users[0].submit_report().unwrap();
+        for user in users.iter() {
+            user_repo.save(user).await.unwrap();
+        }
+
+        let use_case = ApproveReportUseCase::new(
+            Arc::new(InMemoryUnitOfWork),
+            user_repo.clone(),
+            CompleteTaskUseCase::new(Arc::new(InMemoryUnitOfWork), user_repo.clone()),
+        );
+        (use_case, user_repo)
+    }
+
+    async fn state(user_repo: &InMemoryUserRepository, id: i64) -> UserTaskState {
+        user_repo.user(id.into()).await.unwrap().user_task().unwrap().state().clone()
+    }
+
+    #[tokio::test]
+    async fn test_approve_completes_both_partners() {
+        let (use_case, user_repo) = setup().await;
+
+        let completed = use_case.execute(1, (2025, 12)).await.unwrap();
+
+        assert_eq!(completed, vec![1, 2]);
+        assert_eq!(state(&user_repo, 1).await, UserTaskState::Completed);
+        assert_eq!(state(&user_repo, 2).await, UserTaskState::Completed);
+        assert_eq!(user_repo.user(2.into()).await.unwrap().completed_tasks(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_approve_requires_submitted_report() {
+        let (use_case, user_repo) = setup().await;
+
+        let result = use_case.execute(2, (2025, 12)).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidStateChange(_))));
+        assert_eq!(state(&user_repo, 2).await, UserTaskState::Active);
+    }
+
+    #[tokio::test]
+    async fn test_approve_report_of_previous_week() {
+        let (use_case, user_repo) = setup().await;
+        for id in [1, 2] {
+            let mut user = user_repo.user(id.into()).await.unwrap();
+            user.accept().unwrap();
+            user.promote(&[(3 - id).into()], WeekId::new(2025, 13)).unwrap();
+            user_repo.update(&mut user).await.unwrap();
+        }
+
+        let completed = use_case.execute(1, (2025, 12)).await.unwrap();
+
+        assert_eq!(completed, vec![1, 2]);
+        assert_eq!(state(&user_repo, 1).await, UserTaskState::Active);
+        assert_eq!(user_repo.task_state(1.into(), WeekId::new(2025, 12)), Some(UserTaskState::Completed));
+        assert_eq!(user_repo.task_state(2.into(), WeekId::new(2025, 12)), Some(UserTaskState::Completed));
+    }
+}
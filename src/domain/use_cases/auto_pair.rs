@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{TaskRepository, Transaction, UnitOfWork, UserRepository, WeekService};
+use crate::domain::models::{Pairing, PartnerHistory, Task, TaskId, TaskVariant, User, UserId};
+use crate::domain::use_cases::{PairingDto, UserDto};
+
+
+#[derive(Clone)]
+pub struct AutoPairUseCase {
+    uow: Arc<dyn UnitOfWork>,
+    user_repo: Arc<dyn UserRepository>,
+    task_repo: Arc<dyn TaskRepository>,
+    week_service: Arc<dyn WeekService>,
+}
+
+impl AutoPairUseCase {
+    pub fn new(
+        uow: Arc<dyn UnitOfWork>,
+        user_repo: Arc<dyn UserRepository>,
+        task_repo: Arc<dyn TaskRepository>,
+        week_service: Arc<dyn WeekService>,
+    ) -> Self {
+        Self { uow, user_repo, task_repo, week_service }
+    }
+
+    pub async fn preview(&self) -> Result<PairingDto, DomainError> {
+        self.next_task().await?;
+
+        let users: HashMap<UserId, User> = self.user_repo
+            .ready_users().await?
+            .into_iter()
+            .map(|user| (user.id(), user))
+            .collect();
+
+        let mut history = PartnerHistory::default();
+        for &user_id in users.keys() {
+            history.record_history(&self.user_repo.history(user_id).await?);
+        }
+
+        let pairing = Pairing::with_history(users.keys().copied(), &history);
+        Ok(PairingDto::new(&pairing, |id| UserDto::from(users[&id].clone())))
+    }
+
+    /// When the task has variants, they are spread over the groups at random
+    pub async fn execute(&self, groups: &[Vec<i64>]) -> Result<usize, DomainError> {
+        let task = self.next_task().await?;
+        let variants = self.task_repo.variants(task.id()).await?;
+        let assigned = TaskVariant::distribute(&variants, groups.len());
+
+        let mut tx = self.uow.begin().await?;
+        match self.promote(tx.as_mut(), groups, &assigned, task.id()).await {
+            Ok(()) => tx.commit().await?,
+            Err(err) => {
+                if let Err(rollback_err) = tx.rollback().await {
+                    log::error!("failed to roll back transaction: {}", rollback_err);
+                }
+                return Err(err);
+            }
+        }
+
+        Ok(groups.len())
+    }
+
+    async fn promote(
+        &self,
+        tx: &mut dyn Transaction,
+        groups: &[Vec<i64>],
+        variants: &[Option<u32>],
+        task_id: TaskId,
+    ) -> Result<(), DomainError> {
+        let mut users = Vec::new();
+        for (group, &variant) in groups.iter().zip(variants) {
+            let mut members = Vec::with_capacity(group.len());
+            for &user_id in group {
+                members.push(self.user_repo.user_in(tx, user_id.into()).await?);
+            }
+
+            User::promote_group(&mut members, task_id, variant)?;
+            users.extend(members);
+        }
+
+        for user in &users {
+            self.user_repo.update_in(tx, user).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn next_task(&self) -> Result<Task, DomainError> {
+        let current_week = self.week_service.current();
+        let next_week_id = self.week_service.next(current_week);
+
+        match self.task_repo.task(next_week_id).await {
+            Ok(task) => Ok(task),
+            Err(DomainError::TaskNotFound(_)) => Err(DomainError::NoNextTask),
+            Err(e) => Err(e),
+        }
+    }
+}
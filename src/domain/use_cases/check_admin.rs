@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use crate::domain::error::DomainError;
 use crate::domain::interfaces::AuthService;
+use crate::domain::use_cases::AdminRightsDto;
 
 
 #[derive(Clone)]
@@ -14,7 +15,11 @@ impl CheckAdminUseCase {
         Self{ auth_service }
     }
 
-    pub async fn execute(self, user_id: i64) -> Result<bool, DomainError> {
-        Ok(self.auth_service.is_admin(user_id.into()).await.unwrap_or(false))
+    /// Returns `None` if the user is not an admin
+    pub async fn execute(self, user_id: i64) -> Result<Option<AdminRightsDto>, DomainError> {
+        Ok(self.auth_service
+            .role(user_id.into())
+            .await?
+            .map(AdminRightsDto::from))
     }
 }
@@ -0,0 +1,17 @@
+use crate::domain::error::DomainError;
+use crate::domain::models::Profile;
+
+
+#[derive(Clone, Default)]
+pub struct CheckFullNameUseCase;
+
+impl CheckFullNameUseCase {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Returns the normalized full name or `DomainError::InvalidFullName`
+    pub fn execute(&self, full_name: &str) -> Result<String, DomainError> {
+        Profile::normalize_full_name(full_name)
+    }
+}
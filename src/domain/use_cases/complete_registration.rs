@@ -2,17 +2,18 @@ use std::sync::Arc;
 
 use crate::domain::error::DomainError;
 use crate::domain::interfaces::UserRepository;
-use crate::domain::models::{Profile, User};
+use crate::domain::models::{GroupNamePatterns, Profile, User};
 
 
 #[derive(Clone)]
 pub struct CompleteRegistrationUseCase {
     user_repo: Arc<dyn UserRepository>,
+    group_name_patterns: GroupNamePatterns,
 }
 
 impl CompleteRegistrationUseCase {
-    pub fn new(user_repo: Arc<dyn UserRepository>) -> Self {
-        Self { user_repo }
+    pub fn new(user_repo: Arc<dyn UserRepository>, group_name_patterns: GroupNamePatterns) -> Self {
+        Self { user_repo, group_name_patterns }
     }
 
     pub async fn execute(
@@ -21,10 +22,10 @@ impl CompleteRegistrationUseCase {
         full_name: &str,
         group_name: &str,
     ) -> Result<User, DomainError> {
-        let profile = Profile::new(full_name, group_name);
+        let profile = Profile::new(full_name, group_name, &self.group_name_patterns)?;
         let mut user = self.user_repo.user(user_id.into()).await?;
         user.set_profile(profile);
-        self.user_repo.update(&user).await?;
+        self.user_repo.update(&mut user).await?;
         Ok(user)
     }
 }
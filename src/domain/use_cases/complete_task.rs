@@ -1,23 +1,188 @@
 use std::sync::Arc;
 
 use crate::domain::error::DomainError;
-use crate::domain::interfaces::UserRepository;
+use crate::domain::interfaces::{Transaction, UnitOfWork, UserRepository};
+use crate::domain::models::{User, UserTask};
 
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompletionScope {
+    /// The user and every partner from the same meeting
+    Pair,
+    /// Only the user, when the partner did not show up
+    Single,
+}
+
 #[derive(Clone)]
 pub struct CompleteTaskUseCase {
+    uow: Arc<dyn UnitOfWork>,
     user_repo: Arc<dyn UserRepository>,
 }
 
 impl CompleteTaskUseCase {
-    pub fn new(user_repo: Arc<dyn UserRepository>) -> Self {
-        Self { user_repo } 
+    pub fn new(uow: Arc<dyn UnitOfWork>, user_repo: Arc<dyn UserRepository>) -> Self {
+        Self { uow, user_repo } 
+    }
+
+    /// Returns ids of the users whose task has been completed
+    pub async fn execute(self, user_id: i64, scope: CompletionScope) -> Result<Vec<i64>, DomainError> {
+        let mut tx = self.uow.begin().await?;
+        match self.complete(tx.as_mut(), user_id, scope).await {
+            Ok(completed) => {
+                tx.commit().await?;
+                Ok(completed)
+            }
+            Err(err) => {
+                if let Err(rollback_err) = tx.rollback().await {
+                    log::error!("failed to roll back transaction: {}", rollback_err);
+                }
+                Err(err)
+            }
+        }
+    }
+
+    async fn complete(
+        &self,
+        tx: &mut dyn Transaction,
+        user_id: i64,
+        scope: CompletionScope,
+    ) -> Result<Vec<i64>, DomainError> {
+        let user = self.user_repo.user_in(tx, user_id.into()).await?;
+        self.complete_in(tx, user, scope).await
     }
 
-    pub async fn execute(self, user_id: i64) -> Result<(), DomainError> {
-        let mut user = self.user_repo.user(user_id.into()).await?;
+    /// Completes the task `user` has been loaded with, the partners' tasks
+    /// of the same week are completed as well
+    pub(super) async fn complete_in(
+        &self,
+        tx: &mut dyn Transaction,
+        mut user: User,
+        scope: CompletionScope,
+    ) -> Result<Vec<i64>, DomainError> {
+        let user_task = user.user_task()
+            .ok_or(DomainError::NoUserTask)?
+            .clone();
         user.complete_task()?;
-        self.user_repo.update(&user).await?;
-        Ok(())
+
+        let mut users = vec![user];
+        if scope == CompletionScope::Pair {
+            for &partner_id in user_task.partner_ids() {
+                let mut partner = self.user_repo
+                    .user_with_task_in(tx, partner_id, user_task.task_id())
+                    .await?;
+                // partners already credited for this meeting are left as is
+                if partner.user_task().is_some_and(UserTask::is_completed) {
+                    continue;
+                }
+                partner.complete_task()?;
+                users.push(partner);
+            }
+        }
+
+        for user in &users {
+            self.user_repo.update_in(tx, user).await?;
+        }
+        Ok(users.iter().map(|user| user.id().0).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::models::{User, UserTaskState, WeekId};
+    use crate::services::{InMemoryUnitOfWork, InMemoryUserRepository};
+    use super::*;
+
+    async fn setup() -> (CompleteTaskUseCase, Arc<InMemoryUserRepository>) {
+        let user_repo = Arc::new(InMemoryUserRepository::default());
+
+        let mut users = [User::new(1, Some("alice")), User::new(2, Some("bob"))];
+        for user in users.iter_mut() {
+            user.accept().unwrap();
+        }
+        User::promote_group(&mut users, WeekId::new(2025, 12), None).unwrap();
+        for user in users.iter() {
+            user_repo.save(user).await.unwrap();
+        }
+
+        (CompleteTaskUseCase::new(Arc::new(InMemoryUnitOfWork), user_repo.clone()), user_repo)
+    }
+
+    async fn state(user_repo: &InMemoryUserRepository, id: i64) -> UserTaskState {
+        user_repo.user(id.into()).await.unwrap().user_task().unwrap().state().clone()
+    }
+
+    #[tokio::test]
+    async fn test_complete_pair() {
+        let (use_case, user_repo) = setup().await;
+
+        let completed = use_case.execute(1, CompletionScope::Pair).await.unwrap();
+
+        assert_eq!(completed, vec![1, 2]);
+        assert_eq!(state(&user_repo, 1).await, UserTaskState::Completed);
+        assert_eq!(state(&user_repo, 2).await, UserTaskState::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_complete_single() {
+        let (use_case, user_repo) = setup().await;
+
+        let completed = use_case.execute(1, CompletionScope::Single).await.unwrap();
+
+        assert_eq!(completed, vec![1]);
+        assert_eq!(state(&user_repo, 1).await, UserTaskState::Completed);
+        assert_eq!(state(&user_repo, 2).await, UserTaskState::Active);
+    }
+
+    #[tokio::test]
+    async fn test_complete_pair_skips_credited_partner() {
+        let (use_case, user_repo) = setup().await;
+
+        use_case.clone().execute(2, CompletionScope::Single).await.unwrap();
+        let completed = use_case.execute(1, CompletionScope::Pair).await.unwrap();
+
+        assert_eq!(completed, vec![1]);
+        assert_eq!(user_repo.user(2.into()).await.unwrap().completed_tasks(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_complete_twice() {
+        let (use_case, _) = setup().await;
+
+        use_case.clone().execute(1, CompletionScope::Pair).await.unwrap();
+        let result = use_case.execute(1, CompletionScope::Pair).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidStateChange(_))));
+    }
+
+    #[tokio::test]
+    async fn test_complete_pair_already_paired_for_next_week() {
+        let (use_case, user_repo) = setup().await;
+        let mut partner = user_repo.user(2.into()).await.unwrap();
+        partner.accept().unwrap();
+        partner.promote(&[3.into()], WeekId::new(2025, 13)).unwrap();
+        user_repo.update(&mut partner).await.unwrap();
+
+        let completed = use_case.execute(1, CompletionScope::Pair).await.unwrap();
+
+        assert_eq!(completed, vec![1, 2]);
+        let partner = user_repo.user(2.into()).await.unwrap();
+        assert_eq!(partner.completed_tasks(), 1);
+        assert_eq!(*partner.user_task().unwrap().state(), UserTaskState::Active);
+        let history = user_repo.history(2.into()).await.unwrap();
+        assert_eq!(*history.user_tasks()[0].state(), UserTaskState::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_complete_pair_with_missing_partner() {
+        let (use_case, user_repo) = setup().await;
+        let mut user = User::new(3, Some("carol"));
+        user.accept().unwrap();
+        user.promote(&[4.into()], WeekId::new(2025, 12)).unwrap();
+        user_repo.save(&user).await.unwrap();
+
+        let result = use_case.execute(3, CompletionScope::Pair).await;
+
+        assert!(matches!(result, Err(DomainError::UserNotFound(_))));
+        assert_eq!(state(&user_repo, 3).await, UserTaskState::Active);
     }
 }
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{TaskRepository, WeekService};
+use crate::domain::models::{Task, WeekId};
+use crate::domain::use_cases::TaskMediaDto;
+
+
+#[derive(Clone)]
+pub struct CreateTaskUseCase {
+    task_repo: Arc<dyn TaskRepository>,
+    week_service: Arc<dyn WeekService>,
+}
+
+impl CreateTaskUseCase {
+    pub fn new(task_repo: Arc<dyn TaskRepository>, week_service: Arc<dyn WeekService>) -> Self {
+        Self { task_repo, week_service }
+    }
+
+    pub async fn execute(
+        &self,
+        week_id: (i32, u32),
+        title: &str,
+        text: &str,
+        media: Option<TaskMediaDto>,
+    ) -> Result<(), DomainError> {
+        let week_id = WeekId::new(week_id.0, week_id.1);
+        ensure_future_week(self.week_service.as_ref(), week_id)?;
+
+        let task = Task::new(week_id, title, text).with_media(media.map(Into::into));
+        self.task_repo.save(&task).await?;
+        Ok(())
+    }
+}
+
+/// Fails with [`DomainError::WeekAlreadyStarted`] unless the week is still to come
+pub(super) fn ensure_future_week(week_service: &dyn WeekService, week_id: WeekId) -> Result<(), DomainError> {
+    if week_id <= week_service.current() {
+        return Err(DomainError::WeekAlreadyStarted(week_id));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Weekday;
+    use crate::domain::models::TaskMedia;
+    use crate::services::{week_service_at, InMemoryTaskRepository};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_create_task_for_future_week_only() {
+        let task_repo = Arc::new(InMemoryTaskRepository::default());
+        let use_case = CreateTaskUseCase::new(task_repo.clone(), week_service_at(2025, 12, Weekday::Wed));
+
+        use_case.execute((2025, 20), "Title", "Text", None).await.expect("failed to create task");
+        assert_eq!(task_repo.task(WeekId::new(2025, 20)).await.unwrap().title(), "Title");
+
+        let result = use_case.execute((2025, 12), "Title", "Text", None).await;
+        assert!(matches!(result, Err(DomainError::WeekAlreadyStarted(_))));
+
+        let result = use_case.execute((2024, 40), "Title", "Text", None).await;
+        assert!(matches!(result, Err(DomainError::WeekAlreadyStarted(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_task_with_media() {
+        let task_repo = Arc::new(InMemoryTaskRepository::default());
+        let use_case = CreateTaskUseCase::new(task_repo.clone(), week_service_at(2025, 12, Weekday::Wed));
+
+        let media = TaskMediaDto::Location { latitude: 55.765_9, longitude: 37.685_3 };
+        use_case.execute((2025, 13), "Title", "Text", Some(media)).await.expect("failed to create task");
+
+        let task = task_repo.task(WeekId::new(2025, 13)).await.unwrap();
+        assert_eq!(task.media(), Some(&TaskMedia::Location { latitude: 55.765_9, longitude: 37.685_3 }));
+    }
+}
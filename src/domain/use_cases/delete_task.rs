@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{TaskRepository, WeekService};
+use crate::domain::models::WeekId;
+use crate::domain::use_cases::create_task::ensure_future_week;
+
+
+#[derive(Clone)]
+pub struct DeleteTaskUseCase {
+    task_repo: Arc<dyn TaskRepository>,
+    week_service: Arc<dyn WeekService>,
+}
+
+impl DeleteTaskUseCase {
+    pub fn new(task_repo: Arc<dyn TaskRepository>, week_service: Arc<dyn WeekService>) -> Self {
+        Self { task_repo, week_service }
+    }
+
+    /// Fails with [`DomainError::TaskInUse`] once the task has been assigned to anyone
+    pub async fn execute(&self, week_id: (i32, u32)) -> Result<(), DomainError> {
+        let week_id = WeekId::new(week_id.0, week_id.1);
+        ensure_future_week(self.week_service.as_ref(), week_id)?;
+
+        self.task_repo.delete(week_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Weekday;
+    use crate::domain::interfaces::UserRepository;
+    use crate::domain::models::{Task, User, UserId};
+    use crate::services::{week_service_at, InMemoryTaskRepository, InMemoryUserRepository};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_delete_task_in_use() {
+        let user_repo = Arc::new(InMemoryUserRepository::default());
+        let task_repo = Arc::new(InMemoryTaskRepository::with_user_repo(user_repo.clone()));
+        task_repo.save(&Task::new(WeekId::new(2025, 12), "Title", "Text")).await.unwrap();
+
+        let mut user = User::new(1, Some("alice"));
+        user.accept().unwrap();
+        user.promote(&[UserId(2)], WeekId::new(2025, 12)).unwrap();
+        user_repo.save(&user).await.unwrap();
+
+        let use_case = DeleteTaskUseCase::new(task_repo.clone(), week_service_at(2025, 10, Weekday::Wed));
+
+        let result = use_case.execute((2025, 12)).await;
+        assert!(matches!(result, Err(DomainError::TaskInUse(_))));
+        assert!(task_repo.task(WeekId::new(2025, 12)).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_delete_task_of_started_week() {
+        let task_repo = Arc::new(InMemoryTaskRepository::default());
+        task_repo.save(&Task::new(WeekId::new(2025, 10), "Title", "Text")).await.unwrap();
+        task_repo.save(&Task::new(WeekId::new(2025, 11), "Title", "Text")).await.unwrap();
+        let use_case = DeleteTaskUseCase::new(task_repo.clone(), week_service_at(2025, 10, Weekday::Wed));
+
+        let result = use_case.execute((2025, 10)).await;
+        assert!(matches!(result, Err(DomainError::WeekAlreadyStarted(_))));
+        assert!(task_repo.task(WeekId::new(2025, 10)).await.is_ok());
+
+        use_case.execute((2025, 11)).await.expect("failed to delete task");
+        assert!(matches!(task_repo.task(WeekId::new(2025, 11)).await, Err(DomainError::TaskNotFound(_))));
+    }
+}
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{TaskRepository, WeekService};
+use crate::domain::models::WeekId;
+use crate::domain::use_cases::create_task::ensure_future_week;
+
+
+#[derive(Clone)]
+pub struct DeleteTaskVariantUseCase {
+    task_repo: Arc<dyn TaskRepository>,
+    week_service: Arc<dyn WeekService>,
+}
+
+impl DeleteTaskVariantUseCase {
+    pub fn new(task_repo: Arc<dyn TaskRepository>, week_service: Arc<dyn WeekService>) -> Self {
+        Self { task_repo, week_service }
+    }
+
+    /// Fails with [`DomainError::TaskInUse`] once the variant has been assigned to anyone
+    pub async fn execute(&self, week_id: (i32, u32), number: u32) -> Result<(), DomainError> {
+        let week_id = WeekId::new(week_id.0, week_id.1);
+        ensure_future_week(self.week_service.as_ref(), week_id)?;
+
+        self.task_repo.delete_variant(week_id, number).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Weekday;
+    use crate::domain::interfaces::UserRepository;
+    use crate::domain::models::{NewTaskVariant, Task, User};
+    use crate::services::{week_service_at, InMemoryTaskRepository, InMemoryUserRepository};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_delete_task_variant_in_use() {
+        let user_repo = Arc::new(InMemoryUserRepository::default());
+        let task_repo = Arc::new(InMemoryTaskRepository::with_user_repo(user_repo.clone()));
+        let week_id = WeekId::new(2025, 12);
+        task_repo.save(&Task::new(week_id, "Coffee", "Drink coffee together")).await.unwrap();
+        let first = NewTaskVariant::new("Tea", "Drink tea together").unwrap();
+        let first = task_repo.add_variant(week_id, &first).await.unwrap();
+        let second = NewTaskVariant::new("Juice", "Drink juice together").unwrap();
+        let second = task_repo.add_variant(week_id, &second).await.unwrap();
+
+        let mut users = [User::new(1, Some("alice")), User::new(2, Some("bob"))];
+        for user in users.iter_mut() {
+            user.accept().unwrap();
+        }
+        User::promote_group(&mut users, week_id, Some(second.number())).unwrap();
+        for user in &users {
+            user_repo.save(user).await.unwrap();
+        }
+
+        let use_case = DeleteTaskVariantUseCase::new(task_repo.clone(), week_service_at(2025, 10, Weekday::Wed));
+
+        let result = use_case.execute((2025, 12), second.number()).await;
+        assert!(matches!(result, Err(DomainError::TaskInUse(_))));
+
+        use_case.execute((2025, 12), first.number()).await.unwrap();
+        assert_eq!(task_repo.variants(week_id).await.unwrap(), vec![second]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_task_variant_of_started_week() {
+        let task_repo = Arc::new(InMemoryTaskRepository::default());
+        let week_id = WeekId::new(2025, 10);
+        task_repo.save(&Task::new(week_id, "Coffee", "Drink coffee together")).await.unwrap();
+        let variant = NewTaskVariant::new("Tea", "Drink tea together").unwrap();
+        let variant = task_repo.add_variant(week_id, &variant).await.unwrap();
+        let use_case = DeleteTaskVariantUseCase::new(task_repo.clone(), week_service_at(2025, 10, Weekday::Wed));
+
+        let result = use_case.execute((2025, 10), variant.number()).await;
+        assert!(matches!(result, Err(DomainError::WeekAlreadyStarted(_))));
+        assert_eq!(task_repo.variants(week_id).await.unwrap(), vec![variant]);
+    }
+}
@@ -1,42 +1,200 @@
+use chrono::{DateTime, Local};
+
 use crate::domain::error::DomainError;
-use crate::domain::models::{NextTaskStatus, Task, User, UserTask, UserTaskState};
+use crate::domain::models::{Admin, AdminRole, NextTaskStatus, Pairing, PoolTask, Task, TaskMedia, TaskVariant, User, UserId, UserTask, UserTaskState, WeekId};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContactDto {
+    pub id: i64,
+    pub username: Option<String>,
+    pub full_name: Option<String>,
+}
+
+/// Attachment of a task, files are referenced by their Telegram `file_id`
+#[derive(Debug, Clone, PartialEq)]
+pub enum TaskMediaDto {
+    Photo(String),
+    Document(String),
+    Location { latitude: f64, longitude: f64 },
+}
 
 pub struct UserTaskDto {
     pub id: (i32, u32),
-    pub partner_username: String,
+    pub partners: Vec<ContactDto>,
     pub state: String,
     pub title: String,
     pub description: String,
+    pub media: Option<TaskMediaDto>,
+    pub can_send_report: bool,
+}
+
+pub struct TaskDto {
+    pub title: String,
+    pub description: String,
+    pub media: Option<TaskMediaDto>,
+    pub variants: Vec<TaskVariantDto>,
+}
+
+pub struct TaskVariantDto {
+    pub number: u32,
+    pub title: String,
+    pub description: String,
+}
+
+pub struct PlannedWeekDto {
+    pub id: (i32, u32),
+    pub starts_on: String,
+    pub task: Option<TaskDto>,
+}
+
+pub struct PoolTaskDto {
+    pub title: String,
+    pub tags: Vec<String>,
+    /// Start of the week the task was planned for this season
+    pub used_on: Option<String>,
+}
+
+pub struct TaskPoolDto {
+    pub tasks: Vec<PoolTaskDto>,
+    pub tags: Vec<String>,
+}
+
+pub struct ReportDto {
+    pub user: ContactDto,
+    pub week_id: (i32, u32),
+    pub partners: Vec<ContactDto>,
+    pub title: String,
+    pub photo_file_ids: Vec<String>,
+    pub caption: Option<String>,
 }
 
 pub struct UserDto {
     pub id: i64,
-    pub username: String,
+    pub username: Option<String>,
     pub full_name: Option<String>,
 }
 
+pub struct PairingDto {
+    pub groups: Vec<Vec<UserDto>>,
+    pub leftover: Vec<UserDto>,
+}
+
+pub struct AdminRightsDto {
+    pub manage_meetings: bool,
+    pub review: bool,
+    pub manage_admins: bool,
+}
+
+pub struct AdminDto {
+    pub contact: ContactDto,
+    pub role: String,
+    pub revocable: bool,
+}
+
 pub struct FullUserDto {
     pub id: i64,
-    pub username: String,
+    pub username: Option<String>,
     pub full_name: String,
     pub group_name: String,
     pub user_task: Option<UserTaskDto>,
+    pub history: Vec<UserTaskDto>,
     pub next_task_status: String,
     pub completed_quests: i32,
 }
 
 
 impl UserTaskDto {
-    pub fn new(user_task: &UserTask, task: &Task, partner_username: &str) -> Self {
+    /// The title and description of the assigned `variant` replace the ones of the main task
+    pub fn new(
+        user_task: &UserTask,
+        task: &Task,
+        variant: Option<&TaskVariant>,
+        partners: Vec<ContactDto>,
+    ) -> Self {
         Self {
             id: user_task.task_id().into(),
-            partner_username: partner_username.to_string(),
+            partners,
             state: match user_task.state() {
                 UserTaskState::Active => String::from("в процессе"),
+                UserTaskState::AwaitingReview => String::from("на проверке"),
                 UserTaskState::Completed => String::from("завершено"),
+                UserTaskState::Expired => String::from("просрочено"),
             },
+            title: variant.map_or(task.title(), TaskVariant::title).to_string(),
+            description: variant.map_or(task.description(), TaskVariant::description).to_string(),
+            media: task.media().cloned().map(TaskMediaDto::from),
+            can_send_report: *user_task.state() == UserTaskState::Active,
+        }
+    }
+}
+
+impl From<TaskMedia> for TaskMediaDto {
+    fn from(media: TaskMedia) -> Self {
+        match media {
+            TaskMedia::Photo { file_id } => TaskMediaDto::Photo(file_id),
+            TaskMedia::Document { file_id } => TaskMediaDto::Document(file_id),
+            TaskMedia::Location { latitude, longitude } => TaskMediaDto::Location { latitude, longitude },
+        }
+    }
+}
+
+impl From<TaskMediaDto> for TaskMedia {
+    fn from(media: TaskMediaDto) -> Self {
+        match media {
+            TaskMediaDto::Photo(file_id) => TaskMedia::Photo { file_id },
+            TaskMediaDto::Document(file_id) => TaskMedia::Document { file_id },
+            TaskMediaDto::Location { latitude, longitude } => TaskMedia::Location { latitude, longitude },
+        }
+    }
+}
+
+impl From<&Task> for TaskDto {
+    fn from(task: &Task) -> Self {
+        Self {
             title: task.title().to_string(),
             description: task.description().to_string(),
+            media: task.media().cloned().map(TaskMediaDto::from),
+            variants: Vec::new(),
+        }
+    }
+}
+
+impl From<&TaskVariant> for TaskVariantDto {
+    fn from(variant: &TaskVariant) -> Self {
+        Self {
+            number: variant.number(),
+            title: variant.title().to_string(),
+            description: variant.description().to_string(),
+        }
+    }
+}
+
+impl PlannedWeekDto {
+    pub fn new(week_id: WeekId, starts_on: DateTime<Local>, task: Option<&Task>) -> Self {
+        Self {
+            id: week_id.into(),
+            starts_on: starts_on.format("%d.%m.%Y").to_string(),
+            task: task.map(TaskDto::from),
+        }
+    }
+}
+
+impl PoolTaskDto {
+    pub fn new(task: &PoolTask, used_on: Option<DateTime<Local>>) -> Self {
+        Self {
+            title: task.title().to_string(),
+            tags: task.tags().to_vec(),
+            used_on: used_on.map(|date| date.format("%d.%m.%Y").to_string()),
+        }
+    }
+}
+
+impl From<&User> for ContactDto {
+    fn from(user: &User) -> Self {
+        Self {
+            id: user.id().0,
+            username: user.username().map(String::from),
+            full_name: user.profile().map(|p| p.full_name().to_string()),
         }
     }
 }
@@ -45,21 +203,79 @@ impl From<User> for UserDto {
     fn from(user: User) -> Self {
         Self {
             id: user.id().0,
-            username: user.username().to_string(),
+            username: user.username().map(String::from),
             full_name: user.profile().map(|p| p.full_name().to_string()),
         }
     }
 }
 
+impl UserDto {
+    pub fn contact(&self) -> ContactDto {
+        ContactDto {
+            id: self.id,
+            username: self.username.clone(),
+            full_name: self.full_name.clone(),
+        }
+    }
+}
+
+impl From<AdminRole> for AdminRightsDto {
+    fn from(role: AdminRole) -> Self {
+        Self {
+            manage_meetings: role.can_manage_meetings(),
+            review: role.can_review(),
+            manage_admins: role.can_manage_admins(),
+        }
+    }
+}
+
+impl AdminDto {
+    pub fn new(admin: &Admin, contact: ContactDto) -> Self {
+        Self {
+            contact,
+            role: match admin.role() {
+                AdminRole::Owner => String::from("владелец"),
+                AdminRole::Admin => String::from("администратор"),
+                AdminRole::Reviewer => String::from("проверяющий"),
+            },
+            revocable: admin.role() != AdminRole::Owner,
+        }
+    }
+}
+
+impl PairingDto {
+    pub fn new(pairing: &Pairing, user: impl Fn(UserId) -> UserDto) -> Self {
+        Self {
+            groups: pairing.groups()
+                .iter()
+                .map(|group| group.iter().map(|&id| user(id)).collect())
+                .collect(),
+            leftover: pairing.leftover()
+                .iter()
+                .map(|&id| user(id))
+                .collect(),
+        }
+    }
+}
+
 impl FullUserDto {
+    pub fn contact(&self) -> ContactDto {
+        ContactDto {
+            id: self.id,
+            username: self.username.clone(),
+            full_name: Some(self.full_name.clone()),
+        }
+    }
+
     pub fn without_user_task(user: &User) -> Result<Self, DomainError> {
         let profile = user.profile().ok_or(DomainError::UserIsNotRegistered(user.id()))?;
         Ok(Self {
             id: user.id().0,
-            username: user.username().to_string(),
+            username: user.username().map(String::from),
             full_name: profile.full_name().to_string(),
             group_name: profile.group_name().to_string(),
             user_task: None,
+            history: Vec::new(),
             next_task_status: match user.next_task_status() {
                 NextTaskStatus::Pending => String::from("нет ответа"),
                 NextTaskStatus::Accepted => String::from("подтверждено"),
@@ -69,15 +285,21 @@ impl FullUserDto {
         })
     }
     
-    pub fn with_user_task(user: &User, task: &Task, partner_username: &str) -> Result<Self, DomainError> {
+    pub fn with_user_task(
+        user: &User,
+        task: &Task,
+        variant: Option<&TaskVariant>,
+        partners: Vec<ContactDto>,
+    ) -> Result<Self, DomainError> {
         let profile = user.profile().ok_or(DomainError::UserIsNotRegistered(user.id()))?;
         Ok(Self {
             id: user.id().0,
-            username: user.username().to_string(),
+            username: user.username().map(String::from),
             full_name: profile.full_name().to_string(),
             group_name: profile.group_name().to_string(),
             user_task: user.user_task()
-                .map(|ut| UserTaskDto::new(ut, task, partner_username)),
+                .map(|ut| UserTaskDto::new(ut, task, variant, partners)),
+            history: Vec::new(),
             next_task_status: match user.next_task_status() {
                 NextTaskStatus::Pending => String::from("нет ответа"),
                 NextTaskStatus::Accepted => String::from("подтверждено"),
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::AuthService;
+
+
+#[derive(Clone)]
+pub struct GetAdminsUseCase {
+    auth_service: Arc<dyn AuthService>,
+}
+
+impl GetAdminsUseCase {
+    pub fn new(auth_service: Arc<dyn AuthService>) -> Self {
+        Self { auth_service }
+    }
+
+    /// Admins who run the meetings, reviewers don't get broadcast reports
+    pub async fn execute(&self) -> Result<Vec<i64>, DomainError> {
+        Ok(self.auth_service
+            .admins().await?
+            .into_iter()
+            .filter(|admin| admin.role().can_manage_meetings())
+            .map(|admin| admin.user_id().0)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::models::{Admin, AdminRole};
+    use crate::services::MockAuthService;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reviewers_are_left_out() {
+        let auth_service = Arc::new(MockAuthService::with_admin_ids([1]));
+        auth_service.grant(&Admin::new(2, AdminRole::Admin)).await.unwrap();
+        auth_service.grant(&Admin::new(3, AdminRole::Reviewer)).await.unwrap();
+
+        let mut admin_ids = GetAdminsUseCase::new(auth_service).execute().await.unwrap();
+        admin_ids.sort();
+
+        assert_eq!(admin_ids, vec![1, 2]);
+    }
+}
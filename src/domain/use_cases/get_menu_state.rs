@@ -33,7 +33,7 @@ impl GetMenuStateUseCase {
         categories.push(MenuCategory::Profile);
         categories.push(MenuCategory::Rules);
         
-        if user.user_task().is_some() {
+        if user.user_task().is_some_and(|ut| !ut.is_expired()) {
             categories.push(MenuCategory::UserTask);
         }
         
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{TaskRepository, WeekService};
+use crate::domain::models::WeekId;
+use crate::domain::use_cases::{PlannedWeekDto, TaskVariantDto};
+
+
+#[derive(Clone)]
+pub struct GetPlannedWeekUseCase {
+    task_repo: Arc<dyn TaskRepository>,
+    week_service: Arc<dyn WeekService>,
+}
+
+impl GetPlannedWeekUseCase {
+    pub fn new(task_repo: Arc<dyn TaskRepository>, week_service: Arc<dyn WeekService>) -> Self {
+        Self { task_repo, week_service }
+    }
+
+    pub async fn execute(&self, week_id: (i32, u32)) -> Result<PlannedWeekDto, DomainError> {
+        let week_id = WeekId::new(week_id.0, week_id.1);
+        let task = match self.task_repo.task(week_id).await {
+            Ok(task) => Some(task),
+            Err(DomainError::TaskNotFound(_)) => None,
+            Err(err) => return Err(err),
+        };
+
+        let mut dto = PlannedWeekDto::new(week_id, self.week_service.start(week_id), task.as_ref());
+        if let Some(task) = dto.task.as_mut() {
+            task.variants = self.task_repo
+                .variants(week_id).await?
+                .iter()
+                .map(TaskVariantDto::from)
+                .collect();
+        }
+        Ok(dto)
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{ReportRepository, TaskRepository, UserRepository};
+use crate::domain::use_cases::{ContactDto, ReportDto};
+use crate::domain::use_cases::get_user_task::assigned_variant;
+
+
+#[derive(Clone)]
+pub struct GetReviewQueueUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    task_repo: Arc<dyn TaskRepository>,
+    report_repo: Arc<dyn ReportRepository>,
+}
+
+impl GetReviewQueueUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        task_repo: Arc<dyn TaskRepository>,
+        report_repo: Arc<dyn ReportRepository>,
+    ) -> Self {
+        Self { user_repo, task_repo, report_repo }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<ReportDto>, DomainError> {
+        let reports = self.report_repo.awaiting_review().await?;
+
+        let mut queue = Vec::with_capacity(reports.len());
+        for report in reports {
+            let user = self.user_repo.user(report.user_id()).await?;
+            // the user may already hold a task of a later week
+            let history = self.user_repo.history(report.user_id()).await?;
+            let user_task = history.user_tasks()
+                .iter()
+                .find(|ut| ut.task_id() == report.task_id())
+                .ok_or(DomainError::NoUserTask)?;
+            let task = self.task_repo.task(user_task.task_id()).await?;
+            let variant = assigned_variant(self.task_repo.as_ref(), user_task).await?;
+
+            let mut partners = Vec::with_capacity(user_task.partner_ids().len());
+            for &partner_id in user_task.partner_ids() {
+                let partner = self.user_repo.user(partner_id).await?;
+                partners.push(ContactDto::from(&partner));
+            }
+
+            queue.push(ReportDto {
+                user: ContactDto::from(&user),
+                week_id: report.task_id().into(),
+                partners,
+                title: variant.as_ref().map_or(task.title(), |variant| variant.title()).to_string(),
+                photo_file_ids: report.photo_file_ids().to_vec(),
+                caption: report.caption().map(String::from),
+            });
+        }
+        Ok(queue)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::interfaces::UnitOfWork;
+    use crate::domain::models::{Report, Task, User, WeekId};
+    use crate::services::{InMemoryReportRepository, InMemoryTaskRepository, InMemoryUnitOfWork, InMemoryUserRepository};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_queue_keeps_reports_of_users_paired_again() {
+        let user_repo = Arc::new(InMemoryUserRepository::default());
+        let task_repo = Arc::new(InMemoryTaskRepository::default());
+        let report_repo = Arc::new(InMemoryReportRepository::with_user_repo(user_repo.clone()));
+        let (current_week, next_week) = (WeekId::new(2025, 12), WeekId::new(2025, 13));
+        task_repo.save(&Task::new(current_week, "Coffee", "Drink coffee together")).await.unwrap();
+
+        let mut users = [User::new(1, Some("alice")), User::new(2, Some("bob"))];
+        for user in users.iter_mut() {
+            user.accept().unwrap();
+        }
+        User::promote_group(&mut users, current_week, None).unwrap();
+        users[0].submit_report().unwrap();
+        for user in &users {
+            user_repo.save(user).await.unwrap();
+        }
+        let mut tx = InMemoryUnitOfWork.begin().await.unwrap();
+        let report = Report::new(1, current_week, vec!["photo".to_string()], None).unwrap();
+        report_repo.save_in(tx.as_mut(), &report).await.unwrap();
+        tx.commit().await.unwrap();
+
+        for user in users.iter_mut() {
+            user.accept().unwrap();
+        }
+        User::promote_group(&mut users, next_week, None).unwrap();
+        for user in users.iter_mut() {
+            user_repo.update(user).await.unwrap();
+        }
+
+        let use_case = GetReviewQueueUseCase::new(user_repo, task_repo, report_repo);
+        let queue = use_case.execute().await.unwrap();
+
+        assert_eq!(queue.len(), 1);
+        assert_eq!(queue[0].user.id, 1);
+        assert_eq!(queue[0].week_id, (2025, 12));
+        assert_eq!(queue[0].title, "Coffee");
+        assert_eq!(queue[0].partners, vec![ContactDto::from(&users[1])]);
+    }
+}
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{TaskRepository, WeekService};
+use crate::domain::use_cases::PlannedWeekDto;
+
+
+#[derive(Clone)]
+pub struct GetTaskCalendarUseCase {
+    task_repo: Arc<dyn TaskRepository>,
+    week_service: Arc<dyn WeekService>,
+}
+
+impl GetTaskCalendarUseCase {
+    pub fn new(task_repo: Arc<dyn TaskRepository>, week_service: Arc<dyn WeekService>) -> Self {
+        Self { task_repo, week_service }
+    }
+
+    /// Returns `count` upcoming weeks, skipping the first `offset` ones after the current week
+    pub async fn execute(&self, offset: usize, count: usize) -> Result<Vec<PlannedWeekDto>, DomainError> {
+        let mut week_id = self.week_service.next(self.week_service.current());
+        for _ in 0..offset {
+            week_id = self.week_service.next(week_id);
+        }
+
+        let mut week_ids = Vec::with_capacity(count);
+        for _ in 0..count {
+            week_ids.push(week_id);
+            week_id = self.week_service.next(week_id);
+        }
+
+        let (Some(&first), Some(&last)) = (week_ids.first(), week_ids.last()) else {
+            return Ok(Vec::new());
+        };
+        let tasks = self.task_repo.tasks_between(first, last).await?;
+
+        Ok(week_ids
+            .into_iter()
+            .map(|week_id| PlannedWeekDto::new(
+                week_id,
+                self.week_service.start(week_id),
+                tasks.iter().find(|task| task.id() == week_id),
+            ))
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Weekday;
+    use crate::domain::models::{Task, WeekId};
+    use crate::services::{week_service_at, InMemoryTaskRepository};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_calendar_crosses_year_boundary() {
+        let task_repo = Arc::new(InMemoryTaskRepository::default());
+        task_repo.save(&Task::new(WeekId::new(2026, 1), "New year", "Lorem ipsum")).await.unwrap();
+        task_repo.save(&Task::new(WeekId::new(2026, 5), "Too far", "Lorem ipsum")).await.unwrap();
+
+        let use_case = GetTaskCalendarUseCase::new(task_repo, week_service_at(2025, 51, Weekday::Mon));
+        let weeks = use_case.execute(1, 3).await.expect("failed to get calendar");
+
+        let ids = weeks.iter().map(|week| week.id).collect::<Vec<_>>();
+        assert_eq!(ids, vec![(2026, 1), (2026, 2), (2026, 3)]);
+        assert_eq!(weeks[0].starts_on, "29.12.2025");
+        assert_eq!(weeks[0].task.as_ref().map(|task| task.title.as_str()), Some("New year"));
+        assert!(weeks[1].task.is_none() && weeks[2].task.is_none());
+    }
+}
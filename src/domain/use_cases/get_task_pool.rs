@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{TaskPoolRepository, WeekService};
+use crate::domain::use_cases::{PoolTaskDto, TaskPoolDto};
+
+
+#[derive(Clone)]
+pub struct GetTaskPoolUseCase {
+    pool_repo: Arc<dyn TaskPoolRepository>,
+    week_service: Arc<dyn WeekService>,
+}
+
+impl GetTaskPoolUseCase {
+    pub fn new(pool_repo: Arc<dyn TaskPoolRepository>, week_service: Arc<dyn WeekService>) -> Self {
+        Self { pool_repo, week_service }
+    }
+
+    pub async fn execute(&self) -> Result<TaskPoolDto, DomainError> {
+        let tasks = self.pool_repo.all().await?;
+
+        let mut tags: Vec<String> = Vec::new();
+        for tag in tasks.iter().filter(|task| !task.is_used()).flat_map(|task| task.tags()) {
+            if !tags.contains(tag) {
+                tags.push(tag.clone());
+            }
+        }
+        tags.sort();
+
+        Ok(TaskPoolDto {
+            tasks: tasks
+                .iter()
+                .map(|task| PoolTaskDto::new(task, task.used_in().map(|week_id| self.week_service.start(week_id))))
+                .collect(),
+            tags,
+        })
+    }
+}
@@ -0,0 +1,159 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{TaskRepository, UserRepository, WeekService};
+use crate::domain::models::UserTaskState;
+use crate::domain::use_cases::ContactDto;
+use crate::domain::use_cases::get_user_task::assigned_variant;
+
+
+pub struct TaskReminderDto {
+    pub user_id: i64,
+    pub partners: Vec<ContactDto>,
+    pub title: String,
+}
+
+#[derive(Clone)]
+pub struct GetTaskRemindersUseCase {
+    user_repo: Arc<dyn UserRepository>,
+    task_repo: Arc<dyn TaskRepository>,
+    week_service: Arc<dyn WeekService>,
+}
+
+impl GetTaskRemindersUseCase {
+    pub fn new(
+        user_repo: Arc<dyn UserRepository>,
+        task_repo: Arc<dyn TaskRepository>,
+        week_service: Arc<dyn WeekService>,
+    ) -> Self {
+        Self { user_repo, task_repo, week_service }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<TaskReminderDto>, DomainError> {
+        let current_week = self.week_service.current();
+        let mut reminders = Vec::new();
+        // users may already be paired for the next week, their task of the current one is still due
+        for user in self.user_repo.users_with_task(UserTaskState::Active, Some(current_week)).await? {
+            let Some(user_task) = user.user_task() else {
+                continue;
+            };
+            let task = self.task_repo.task(user_task.task_id()).await?;
+            let variant = assigned_variant(self.task_repo.as_ref(), user_task).await?;
+
+            let mut partners = Vec::with_capacity(user_task.partner_ids().len());
+            for &partner_id in user_task.partner_ids() {
+                let partner = self.user_repo.user(partner_id).await?;
+                partners.push(ContactDto::from(&partner));
+            }
+
+            reminders.push(TaskReminderDto {
+                user_id: user.id().0,
+                partners,
+                title: variant.as_ref().map_or(task.title(), |variant| variant.title()).to_string(),
+            });
+        }
+        Ok(reminders)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Weekday;
+    use crate::domain::models::{Task, User, WeekId};
+    use crate::services::{week_service_at, InMemoryTaskRepository, InMemoryUserRepository};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_reminders_skip_completed_tasks() {
+        let user_repo = Arc::new(InMemoryUserRepository::default());
+        let task_repo = Arc::new(InMemoryTaskRepository::default());
+        let week_id = WeekId::new(2025, 12);
+        task_repo.save(&Task::new(week_id, "Coffee", "Drink coffee together")).await.unwrap();
+
+        let mut users = [User::new(1, Some("alice")), User::new(2, Some("bob")), User::new(3, Some("carol"))];
+        for user in users.iter_mut() {
+            user.accept().unwrap();
+        }
+        User::promote_group(&mut users[..2], week_id, None).unwrap();
+        users[2].promote(&[1.into()], week_id).unwrap();
+        users[2].complete_task().unwrap();
+        let idle = User::new(4, Some("dave"));
+
+        for user in users.iter().chain([&idle]) {
+            user_repo.save(user).await.unwrap();
+        }
+
+        let use_case = GetTaskRemindersUseCase::new(user_repo, task_repo, week_service_at(2025, 12, Weekday::Wed));
+        let mut reminders = use_case.execute().await.unwrap();
+        reminders.sort_by_key(|reminder| reminder.user_id);
+
+        assert_eq!(reminders.len(), 2);
+        assert_eq!(reminders[0].user_id, 1);
+        assert_eq!(reminders[0].partners, vec![ContactDto::from(&users[1])]);
+        assert_eq!(reminders[0].title, "Coffee");
+        assert_eq!(reminders[1].user_id, 2);
+        assert_eq!(reminders[1].partners, vec![ContactDto::from(&users[0])]);
+    }
+    #[tokio::test]
+    async fn test_reminders_skip_next_week_tasks() {
+        let user_repo = Arc::new(InMemoryUserRepository::default());
+        let task_repo = Arc::new(InMemoryTaskRepository::default());
+        let (current_week, next_week) = (WeekId::new(2025, 12), WeekId::new(2025, 13));
+        task_repo.save(&Task::new(current_week, "Coffee", "Drink coffee together")).await.unwrap();
+        task_repo.save(&Task::new(next_week, "Walk", "Take a walk together")).await.unwrap();
+
+        let mut users = [
+            User::new(1, Some("alice")), User::new(2, Some("bob")),
+            User::new(3, Some("carol")), User::new(4, Some("dave")),
+        ];
+        for user in users.iter_mut() {
+            user.accept().unwrap();
+        }
+        User::promote_group(&mut users[..2], current_week, None).unwrap();
+        User::promote_group(&mut users[2..], next_week, None).unwrap();
+        for user in &users {
+            user_repo.save(user).await.unwrap();
+        }
+
+        let use_case = GetTaskRemindersUseCase::new(user_repo, task_repo, week_service_at(2025, 12, Weekday::Wed));
+        let mut reminders = use_case.execute().await.unwrap();
+        reminders.sort_by_key(|reminder| reminder.user_id);
+
+        let user_ids = reminders.iter().map(|reminder| reminder.user_id).collect::<Vec<_>>();
+        assert_eq!(user_ids, vec![1, 2]);
+        assert!(reminders.iter().all(|reminder| reminder.title == "Coffee"));
+    }
+
+    #[tokio::test]
+    async fn test_reminders_for_users_paired_for_next_week() {
+        let user_repo = Arc::new(InMemoryUserRepository::default());
+        let task_repo = Arc::new(InMemoryTaskRepository::default());
+        let (current_week, next_week) = (WeekId::new(2025, 12), WeekId::new(2025, 13));
+        task_repo.save(&Task::new(current_week, "Coffee", "Drink coffee together")).await.unwrap();
+        task_repo.save(&Task::new(next_week, "Walk", "Take a walk together")).await.unwrap();
+
+        let mut users = [User::new(1, Some("alice")), User::new(2, Some("bob"))];
+        for user in users.iter_mut() {
+            user.accept().unwrap();
+        }
+        User::promote_group(&mut users, current_week, None).unwrap();
+        for user in &users {
+            user_repo.save(user).await.unwrap();
+        }
+        for user in users.iter_mut() {
+            user.accept().unwrap();
+        }
+        User::promote_group(&mut users, next_week, None).unwrap();
+        for user in users.iter_mut() {
+            user_repo.update(user).await.unwrap();
+        }
+
+        let use_case = GetTaskRemindersUseCase::new(user_repo, task_repo, week_service_at(2025, 12, Weekday::Wed));
+        let mut reminders = use_case.execute().await.unwrap();
+        reminders.sort_by_key(|reminder| reminder.user_id);
+
+        let user_ids = reminders.iter().map(|reminder| reminder.user_id).collect::<Vec<_>>();
+        assert_eq!(user_ids, vec![1, 2]);
+        assert!(reminders.iter().all(|reminder| reminder.title == "Coffee"));
+    }
+}
@@ -2,7 +2,9 @@ use std::sync::Arc;
 
 use crate::domain::error::DomainError;
 use crate::domain::interfaces::{TaskRepository, UserRepository};
-use crate::domain::use_cases::FullUserDto;
+use crate::domain::models::UserTask;
+use crate::domain::use_cases::{ContactDto, FullUserDto, UserTaskDto};
+use crate::domain::use_cases::get_user_task::assigned_variant;
 
 
 #[derive(Clone)]
@@ -20,15 +22,35 @@ impl GetUserUseCase {
         let user = self.user_repo.user(user_id.into()).await?;
         let user_task = user.user_task();
         
-        match user_task {
+        let mut dto = match user_task {
             Some(user_task) => {
                 let task = self.task_repo.task(user_task.task_id()).await?;
-                let partner = self.user_repo.user(user_task.partner_id()).await?;
-                FullUserDto::with_user_task(&user, &task, partner.username())
+                let variant = assigned_variant(self.task_repo.as_ref(), user_task).await?;
+                let partners = self.partners(user_task).await?;
+                FullUserDto::with_user_task(&user, &task, variant.as_ref(), partners)?
             }
             None => {
-                FullUserDto::without_user_task(&user)
+                FullUserDto::without_user_task(&user)?
             }
+        };
+        
+        let history = self.user_repo.history(user.id()).await?;
+        for user_task in history.user_tasks() {
+            let task = self.task_repo.task(user_task.task_id()).await?;
+            let variant = assigned_variant(self.task_repo.as_ref(), user_task).await?;
+            let partners = self.partners(user_task).await?;
+            dto.history.push(UserTaskDto::new(user_task, &task, variant.as_ref(), partners));
+        }
+        
+        Ok(dto)
+    }
+    
+    async fn partners(&self, user_task: &UserTask) -> Result<Vec<ContactDto>, DomainError> {
+        let mut partners = Vec::with_capacity(user_task.partner_ids().len());
+        for &partner_id in user_task.partner_ids() {
+            let partner = self.user_repo.user(partner_id).await?;
+            partners.push(ContactDto::from(&partner));
         }
+        Ok(partners)
     }
 }
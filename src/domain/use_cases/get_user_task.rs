@@ -2,7 +2,8 @@ use std::sync::Arc;
 
 use crate::domain::error::DomainError;
 use crate::domain::interfaces::{TaskRepository, UserRepository};
-use crate::domain::use_cases::UserTaskDto;
+use crate::domain::models::{TaskVariant, UserTask};
+use crate::domain::use_cases::{ContactDto, UserTaskDto};
 
 
 #[derive(Clone)]
@@ -24,8 +25,29 @@ impl GetUserTaskUseCase {
         let user_task = user.user_task()
             .ok_or(DomainError::NoUserTask)?;
         let task = self.task_repo.task(user_task.task_id()).await?;
-        let partner = self.user_repo.user(user_task.partner_id()).await?;
-        let dto = UserTaskDto::new(&user_task, &task, partner.username());
+        let variant = assigned_variant(self.task_repo.as_ref(), user_task).await?;
+        let mut partners = Vec::with_capacity(user_task.partner_ids().len());
+        for &partner_id in user_task.partner_ids() {
+            let partner = self.user_repo.user(partner_id).await?;
+            partners.push(ContactDto::from(&partner));
+        }
+        let dto = UserTaskDto::new(user_task, &task, variant.as_ref(), partners);
         Ok(dto)
     }
 }
+
+/// The variant of the task the user has got, `None` for the main task
+pub(super) async fn assigned_variant(
+    task_repo: &dyn TaskRepository,
+    user_task: &UserTask,
+) -> Result<Option<TaskVariant>, DomainError> {
+    let Some(number) = user_task.variant() else {
+        return Ok(None);
+    };
+    task_repo
+        .variants(user_task.task_id()).await?
+        .into_iter()
+        .find(|variant| variant.number() == number)
+        .map(Some)
+        .ok_or(DomainError::TaskVariantNotFound(user_task.task_id(), number))
+}
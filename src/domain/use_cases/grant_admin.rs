@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{AuthService, UserRepository};
+use crate::domain::models::{Admin, AdminRole, UserId};
+
+
+#[derive(Clone)]
+pub struct GrantAdminUseCase {
+    auth_service: Arc<dyn AuthService>,
+    user_repo: Arc<dyn UserRepository>,
+}
+
+impl GrantAdminUseCase {
+    pub fn new(auth_service: Arc<dyn AuthService>, user_repo: Arc<dyn UserRepository>) -> Self {
+        Self { auth_service, user_repo }
+    }
+
+    /// Owners are configured only through `ADMIN_IDS`
+    pub async fn execute(&self, granter_id: i64, user_id: i64, role: &str) -> Result<(), DomainError> {
+        ensure_can_manage_admins(self.auth_service.as_ref(), granter_id.into()).await?;
+
+        let role: AdminRole = role.parse()?;
+        if role == AdminRole::Owner {
+            return Err(DomainError::InvalidValue("owners are configured through ADMIN_IDS".to_string()));
+        }
+
+        let user = self.user_repo.user(user_id.into()).await?;
+        if !user.profile_completed() {
+            return Err(DomainError::UserIsNotRegistered(user.id()));
+        }
+
+        if self.auth_service.role(user.id()).await? == Some(AdminRole::Owner) {
+            return Err(DomainError::InvalidStateChange("owner role can't be changed".to_string()));
+        }
+
+        self.auth_service.grant(&Admin::new(user.id(), role)).await
+    }
+}
+
+pub(super) async fn ensure_can_manage_admins(
+    auth_service: &dyn AuthService,
+    user_id: UserId,
+) -> Result<(), DomainError> {
+    match auth_service.role(user_id).await? {
+        Some(role) if role.can_manage_admins() => Ok(()),
+        _ => Err(DomainError::PermissionDenied(user_id)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::models::{Profile, User};
+    use crate::services::{InMemoryUserRepository, MockAuthService};
+    use super::*;
+
+    async fn setup() -> (Arc<MockAuthService>, GrantAdminUseCase) {
+        let auth_service = Arc::new(MockAuthService::with_admin_ids([1]));
+        let user_repo = Arc::new(InMemoryUserRepository::default());
+
+        let mut user = User::new(2, Some("bob"));
+        user.set_profile(Profile::restore("Иванов Иван", "СМ11-11Б"));
+        user_repo.save(&user).await.unwrap();
+        user_repo.save(&User::new(3, Some("carol"))).await.unwrap();
+
+        let use_case = GrantAdminUseCase::new(auth_service.clone(), user_repo);
+        (auth_service, use_case)
+    }
+
+    #[tokio::test]
+    async fn test_owner_grants_role() {
+        let (auth_service, use_case) = setup().await;
+
+        use_case.execute(1, 2, "reviewer").await.expect("failed to grant role");
+
+        assert_eq!(auth_service.role(UserId(2)).await.unwrap(), Some(AdminRole::Reviewer));
+    }
+
+    #[tokio::test]
+    async fn test_grant_is_restricted() {
+        let (auth_service, use_case) = setup().await;
+        auth_service.grant(&Admin::new(2, AdminRole::Admin)).await.unwrap();
+
+        let result = use_case.execute(2, 2, "admin").await;
+        assert!(matches!(result, Err(DomainError::PermissionDenied(UserId(2)))));
+
+        let result = use_case.execute(1, 3, "admin").await;
+        assert!(matches!(result, Err(DomainError::UserIsNotRegistered(UserId(3)))));
+
+        let result = use_case.execute(1, 2, "owner").await;
+        assert!(matches!(result, Err(DomainError::InvalidValue(_))));
+    }
+}
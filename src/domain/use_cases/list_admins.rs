@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{AuthService, UserRepository};
+use crate::domain::use_cases::{AdminDto, ContactDto};
+
+
+#[derive(Clone)]
+pub struct ListAdminsUseCase {
+    auth_service: Arc<dyn AuthService>,
+    user_repo: Arc<dyn UserRepository>,
+}
+
+impl ListAdminsUseCase {
+    pub fn new(auth_service: Arc<dyn AuthService>, user_repo: Arc<dyn UserRepository>) -> Self {
+        Self { auth_service, user_repo }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<AdminDto>, DomainError> {
+        let admins = self.auth_service.admins().await?;
+
+        let mut dtos = Vec::with_capacity(admins.len());
+        for admin in &admins {
+            // Owners from `ADMIN_IDS` may have never started the bot
+            let contact = match self.user_repo.find_user(admin.user_id()).await? {
+                Some(user) => ContactDto::from(&user),
+                None => ContactDto { id: admin.user_id().0, username: None, full_name: None },
+            };
+            dtos.push(AdminDto::new(admin, contact));
+        }
+
+        Ok(dtos)
+    }
+}
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{TaskRepository, WeekService};
+use crate::domain::use_cases::PlannedWeekDto;
+
+
+#[derive(Clone)]
+pub struct ListTasksUseCase {
+    task_repo: Arc<dyn TaskRepository>,
+    week_service: Arc<dyn WeekService>,
+}
+
+impl ListTasksUseCase {
+    pub fn new(task_repo: Arc<dyn TaskRepository>, week_service: Arc<dyn WeekService>) -> Self {
+        Self { task_repo, week_service }
+    }
+
+    pub async fn execute(&self) -> Result<Vec<PlannedWeekDto>, DomainError> {
+        Ok(self.task_repo
+            .list().await?
+            .iter()
+            .map(|task| PlannedWeekDto::new(task.id(), self.week_service.start(task.id()), Some(task)))
+            .collect())
+    }
+}
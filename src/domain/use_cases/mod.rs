@@ -1,6 +1,7 @@
 mod accept_task;
 mod start_registration;
 mod complete_registration;
+mod check_full_name;
 mod get_menu_state;
 mod get_user_task;
 mod check_admin;
@@ -10,15 +11,41 @@ mod get_ready_users;
 mod promote;
 mod dto;
 mod reject_task;
-mod create_next_task;
+mod create_task;
 mod check_next_task;
 mod get_active_users;
 mod complete_task;
+mod auto_pair;
+mod rollover_week;
+mod get_pending_users;
+mod get_admins;
+mod get_task_reminders;
+mod submit_report;
+mod get_review_queue;
+mod approve_report;
+mod reject_report;
+mod sync_username;
+mod list_admins;
+mod grant_admin;
+mod revoke_admin;
+mod get_task_calendar;
+mod get_planned_week;
+mod update_task;
+mod delete_task;
+mod list_tasks;
+mod add_pool_task;
+mod get_task_pool;
+mod plan_pool_task;
+mod rotate_task_pool;
+mod reset_task_pool;
+mod add_task_variant;
+mod delete_task_variant;
 
 pub use dto::*;
 pub use accept_task::*;
 pub use start_registration::*;
 pub use complete_registration::*;
+pub use check_full_name::*;
 pub use get_menu_state::*;
 pub use get_user_task::*;
 pub use check_admin::*;
@@ -27,7 +54,32 @@ pub use get_user::*;
 pub use get_ready_users::*;
 pub use promote::*;
 pub use reject_task::*;
-pub use create_next_task::*;
+pub use create_task::*;
 pub use check_next_task::*;
 pub use get_active_users::*;
 pub use complete_task::*;
+pub use auto_pair::*;
+pub use rollover_week::*;
+pub use get_pending_users::*;
+pub use get_admins::*;
+pub use get_task_reminders::*;
+pub use submit_report::*;
+pub use get_review_queue::*;
+pub use approve_report::*;
+pub use reject_report::*;
+pub use sync_username::*;
+pub use list_admins::*;
+pub use grant_admin::*;
+pub use revoke_admin::*;
+pub use get_task_calendar::*;
+pub use get_planned_week::*;
+pub use update_task::*;
+pub use delete_task::*;
+pub use list_tasks::*;
+pub use add_pool_task::*;
+pub use get_task_pool::*;
+pub use plan_pool_task::*;
+pub use rotate_task_pool::*;
+pub use reset_task_pool::*;
+pub use add_task_variant::*;
+pub use delete_task_variant::*;
@@ -0,0 +1,112 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{TaskPoolRepository, TaskRepository, Transaction, UnitOfWork, WeekService};
+use crate::domain::models::{PoolTask, WeekId};
+use crate::domain::use_cases::create_task::ensure_future_week;
+use crate::domain::use_cases::TaskDto;
+
+
+#[derive(Clone)]
+pub struct PlanPoolTaskUseCase {
+    uow: Arc<dyn UnitOfWork>,
+    task_repo: Arc<dyn TaskRepository>,
+    pool_repo: Arc<dyn TaskPoolRepository>,
+    week_service: Arc<dyn WeekService>,
+}
+
+impl PlanPoolTaskUseCase {
+    pub fn new(
+        uow: Arc<dyn UnitOfWork>,
+        task_repo: Arc<dyn TaskRepository>,
+        pool_repo: Arc<dyn TaskPoolRepository>,
+        week_service: Arc<dyn WeekService>,
+    ) -> Self {
+        Self { uow, task_repo, pool_repo, week_service }
+    }
+
+    pub async fn execute(&self, week_id: (i32, u32), tag: Option<&str>) -> Result<TaskDto, DomainError> {
+        let week_id = WeekId::new(week_id.0, week_id.1);
+        ensure_future_week(self.week_service.as_ref(), week_id)?;
+
+        let mut tx = self.uow.begin().await?;
+        match self.plan(tx.as_mut(), week_id, tag).await {
+            Ok(dto) => {
+                tx.commit().await?;
+                Ok(dto)
+            }
+            Err(err) => {
+                if let Err(rollback_err) = tx.rollback().await {
+                    log::error!("failed to roll back transaction: {}", rollback_err);
+                }
+                Err(err)
+            }
+        }
+    }
+
+    async fn plan(
+        &self,
+        tx: &mut dyn Transaction,
+        week_id: WeekId,
+        tag: Option<&str>,
+    ) -> Result<TaskDto, DomainError> {
+        let available = self.pool_repo.available_in(tx, tag).await?;
+        let pool_task = PoolTask::pick(&available).ok_or(DomainError::TaskPoolExhausted)?;
+
+        let task = pool_task.to_task(week_id);
+        self.task_repo.save_in(tx, &task).await?;
+        self.pool_repo.mark_used_in(tx, pool_task.id(), week_id).await?;
+        Ok(TaskDto::from(&task))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Weekday;
+    use crate::domain::models::{NewPoolTask, Task};
+    use crate::services::{week_service_at, InMemoryTaskPoolRepository, InMemoryTaskRepository, InMemoryUnitOfWork};
+    use super::*;
+
+    fn use_case() -> (PlanPoolTaskUseCase, Arc<InMemoryTaskRepository>, Arc<InMemoryTaskPoolRepository>) {
+        let task_repo = Arc::new(InMemoryTaskRepository::default());
+        let pool_repo = Arc::new(InMemoryTaskPoolRepository::default());
+        let use_case = PlanPoolTaskUseCase::new(
+            Arc::new(InMemoryUnitOfWork),
+            task_repo.clone(),
+            pool_repo.clone(),
+            week_service_at(2025, 12, Weekday::Wed),
+        );
+        (use_case, task_repo, pool_repo)
+    }
+
+    #[tokio::test]
+    async fn test_plan_pool_task_by_tag() {
+        let (use_case, task_repo, pool_repo) = use_case();
+        pool_repo.add(&NewPoolTask::new("Прогулка", "Lorem ipsum", "улица").unwrap()).await.unwrap();
+        pool_repo.add(&NewPoolTask::new("Кофе", "Lorem ipsum", "кафе").unwrap()).await.unwrap();
+
+        let task = use_case.execute((2025, 13), Some("кафе")).await.expect("failed to plan task");
+
+        assert_eq!(task.title, "Кофе");
+        assert_eq!(task_repo.task(WeekId::new(2025, 13)).await.unwrap().title(), "Кофе");
+        assert_eq!(pool_repo.available(None).await.unwrap().len(), 1);
+
+        let result = use_case.execute((2025, 14), Some("кафе")).await;
+        assert!(matches!(result, Err(DomainError::TaskPoolExhausted)));
+    }
+
+    #[tokio::test]
+    async fn test_plan_pool_task_keeps_pool_on_failure() {
+        let (use_case, task_repo, pool_repo) = use_case();
+        pool_repo.add(&NewPoolTask::new("Прогулка", "Lorem ipsum", "-").unwrap()).await.unwrap();
+        task_repo.save(&Task::new(WeekId::new(2025, 13), "Planned", "Lorem ipsum")).await.unwrap();
+
+        let result = use_case.execute((2025, 13), None).await;
+        assert!(matches!(result, Err(DomainError::TaskAlreadyExists(_))));
+
+        let result = use_case.execute((2025, 12), None).await;
+        assert!(matches!(result, Err(DomainError::WeekAlreadyStarted(_))));
+
+        assert_eq!(pool_repo.available(None).await.unwrap().len(), 1);
+    }
+}
@@ -1,11 +1,14 @@
 use std::sync::Arc;
 
 use crate::domain::error::DomainError;
-use crate::domain::interfaces::{TaskRepository, UserRepository, WeekService};
+use crate::domain::interfaces::{TaskRepository, Transaction, UnitOfWork, UserRepository, WeekService};
+use crate::domain::models::{Task, TaskId, TaskVariant, User, UserTask, UserTaskState};
+use crate::domain::use_cases::TaskVariantDto;
 
 
 #[derive(Clone)]
 pub struct AssignPartnerUseCase {
+    uow: Arc<dyn UnitOfWork>,
     user_repo: Arc<dyn UserRepository>,
     task_repo: Arc<dyn TaskRepository>,
     week_service: Arc<dyn WeekService>,
@@ -13,32 +16,153 @@ pub struct AssignPartnerUseCase {
 
 impl AssignPartnerUseCase {
     pub fn new(
+        uow: Arc<dyn UnitOfWork>,
         user_repo: Arc<dyn UserRepository>,
         task_repo: Arc<dyn TaskRepository>,
         week_service: Arc<dyn WeekService>,
     ) -> Self {
-        Self { user_repo, task_repo, week_service }
+        Self { uow, user_repo, task_repo, week_service }
     }
 
-    pub async fn execute(self, user1_id: i64, user2_id: i64) -> Result<(), DomainError> {
-        let current_week = self.week_service.current();
-        let next_week_id = self.week_service.next(current_week);
-        
-        let task = match self.task_repo.task(next_week_id).await {
-            Ok(task) => task,
-            Err(DomainError::TaskNotFound(_)) => return Err(DomainError::NoNextTask),
-            Err(e) => return Err(e),
+    pub async fn next_task_variants(&self) -> Result<Vec<TaskVariantDto>, DomainError> {
+        let task = self.next_task().await?;
+        let variants = self.task_repo.variants(task.id()).await?;
+        Ok(variants.iter().map(TaskVariantDto::from).collect())
+    }
+
+    /// `variant` is the number of the variant chosen by the admin, `Some(0)`
+    /// for the main task and `None` to pick one no pair has got this week yet
+    pub async fn execute(self, user_ids: &[i64], variant: Option<u32>) -> Result<(), DomainError> {
+        let task = self.next_task().await?;
+        let variants = self.task_repo.variants(task.id()).await?;
+        let variant = match variant {
+            None => TaskVariant::pick(&variants, &self.used_variants(task.id()).await?),
+            Some(0) => None,
+            Some(number) if variants.iter().any(|variant| variant.number() == number) => Some(number),
+            Some(number) => return Err(DomainError::TaskVariantNotFound(task.id(), number)),
         };
         
-        let mut user1 = self.user_repo.user(user1_id.into()).await?;
-        let mut user2 = self.user_repo.user(user2_id.into()).await?;
+        let mut tx = self.uow.begin().await?;
+        match self.promote(tx.as_mut(), user_ids, task.id(), variant).await {
+            Ok(()) => tx.commit().await,
+            Err(err) => {
+                if let Err(rollback_err) = tx.rollback().await {
+                    log::error!("failed to roll back transaction: {}", rollback_err);
+                }
+                Err(err)
+            }
+        }
+    }
+    
+    async fn promote(
+        &self,
+        tx: &mut dyn Transaction,
+        user_ids: &[i64],
+        task_id: TaskId,
+        variant: Option<u32>,
+    ) -> Result<(), DomainError> {
+        let mut users = Vec::with_capacity(user_ids.len());
+        for &user_id in user_ids {
+            users.push(self.user_repo.user_in(tx, user_id.into()).await?);
+        }
         
-        user1.promote(user2.id(), task.id())?;
-        user2.promote(user1.id(), task.id())?;
+        User::promote_group(&mut users, task_id, variant)?;
         
-        self.user_repo.update(&user1).await?;
-        self.user_repo.update(&user2).await?;
+        for user in &users {
+            self.user_repo.update_in(tx, user).await?;
+        }
         
         Ok(())
     }
+    async fn used_variants(&self, task_id: TaskId) -> Result<Vec<Option<u32>>, DomainError> {
+        let mut used = Vec::new();
+        for state in [UserTaskState::Active, UserTaskState::AwaitingReview, UserTaskState::Completed] {
+            let users = self.user_repo.users_with_task(state, Some(task_id)).await?;
+            used.extend(users.iter().filter_map(User::user_task).map(UserTask::variant));
+        }
+        Ok(used)
+    }
+
+    async fn next_task(&self) -> Result<Task, DomainError> {
+        let current_week = self.week_service.current();
+        let next_week_id = self.week_service.next(current_week);
+
+        match self.task_repo.task(next_week_id).await {
+            Ok(task) => Ok(task),
+            Err(DomainError::TaskNotFound(_)) => Err(DomainError::NoNextTask),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Weekday;
+    use crate::domain::models::{NewTaskVariant, UserId, WeekId};
+    use crate::domain::use_cases::GetUserTaskUseCase;
+    use crate::services::{week_service_at, InMemoryTaskRepository, InMemoryUnitOfWork, InMemoryUserRepository};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_assign_chosen_variant_to_both_partners() {
+        let user_repo = Arc::new(InMemoryUserRepository::default());
+        let task_repo = Arc::new(InMemoryTaskRepository::with_user_repo(user_repo.clone()));
+        let week_id = WeekId::new(2025, 13);
+        task_repo.save(&Task::new(week_id, "Coffee", "Drink coffee together")).await.unwrap();
+        let variant = NewTaskVariant::new("Tea", "Drink tea together").unwrap();
+        let variant = task_repo.add_variant(week_id, &variant).await.unwrap();
+
+        for mut user in [User::new(1, Some("alice")), User::new(2, Some("bob"))] {
+            user.accept().unwrap();
+            user_repo.save(&user).await.unwrap();
+        }
+
+        let use_case = AssignPartnerUseCase::new(
+            Arc::new(InMemoryUnitOfWork),
+            user_repo.clone(),
+            task_repo.clone(),
+            week_service_at(2025, 12, Weekday::Wed),
+        );
+        assert_eq!(use_case.next_task_variants().await.unwrap().len(), 1);
+
+        let result = use_case.clone().execute(&[1, 2], Some(variant.number() + 1)).await;
+        assert!(matches!(result, Err(DomainError::TaskVariantNotFound(_, 2))));
+
+        use_case.execute(&[1, 2], Some(variant.number())).await.unwrap();
+
+        let get_user_task = GetUserTaskUseCase::new(user_repo.clone(), task_repo.clone());
+        for user_id in [1, 2] {
+            let user_task = get_user_task.clone().execute(user_id).await.unwrap();
+            assert_eq!(user_task.title, "Tea");
+            assert_eq!(user_task.description, "Drink tea together");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_random_variant_is_not_used_yet() {
+        let user_repo = Arc::new(InMemoryUserRepository::default());
+        let task_repo = Arc::new(InMemoryTaskRepository::with_user_repo(user_repo.clone()));
+        let week_id = WeekId::new(2025, 13);
+        task_repo.save(&Task::new(week_id, "Coffee", "Drink coffee together")).await.unwrap();
+        let variant = NewTaskVariant::new("Tea", "Drink tea together").unwrap();
+        task_repo.add_variant(week_id, &variant).await.unwrap();
+
+        for id in 1..=4 {
+            let mut user = User::new(id, Some(format!("user{id}")));
+            user.accept().unwrap();
+            user_repo.save(&user).await.unwrap();
+        }
+
+        let use_case = AssignPartnerUseCase::new(
+            Arc::new(InMemoryUnitOfWork),
+            user_repo.clone(),
+            task_repo.clone(),
+            week_service_at(2025, 12, Weekday::Wed),
+        );
+        use_case.clone().execute(&[1, 2], Some(0)).await.unwrap();
+        use_case.execute(&[3, 4], None).await.unwrap();
+
+        let user = user_repo.user(UserId(3)).await.unwrap();
+        assert_eq!(user.user_task().unwrap().variant(), Some(1));
+    }
 }
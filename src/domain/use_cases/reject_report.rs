@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{Transaction, UnitOfWork, UserRepository};
+use crate::domain::models::{TaskId, WeekId};
+
+
+#[derive(Clone)]
+pub struct RejectReportUseCase {
+    uow: Arc<dyn UnitOfWork>,
+    user_repo: Arc<dyn UserRepository>,
+}
+
+impl RejectReportUseCase {
+    pub fn new(uow: Arc<dyn UnitOfWork>, user_repo: Arc<dyn UserRepository>) -> Self {
+        Self { uow, user_repo }
+    }
+
+    pub async fn execute(&self, user_id: i64, week_id: (i32, u32)) -> Result<(), DomainError> {
+        let mut tx = self.uow.begin().await?;
+        match self.reject(tx.as_mut(), user_id, WeekId::new(week_id.0, week_id.1)).await {
+            Ok(()) => tx.commit().await,
+            Err(err) => {
+                if let Err(rollback_err) = tx.rollback().await {
+                    log::error!("failed to roll back transaction: {}", rollback_err);
+                }
+                Err(err)
+            }
+        }
+    }
+
+    async fn reject(&self, tx: &mut dyn Transaction, user_id: i64, task_id: TaskId) -> Result<(), DomainError> {
+        let mut user = self.user_repo.user_with_task_in(tx, user_id.into(), task_id).await?;
+        user.reject_report()?;
+        self.user_repo.update_in(tx, &user).await
+    }
+}
@@ -17,7 +17,7 @@ impl RejectTaskUseCase {
     pub async fn execute(self, user_id: i64) -> Result<(), DomainError> {
         let mut user = self.user_repo.user(user_id.into()).await?;
         user.reject()?;
-        self.user_repo.update(&user).await?;
+        self.user_repo.update(&mut user).await?;
         Ok(())
     }
 }
@@ -0,0 +1,20 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::TaskPoolRepository;
+
+
+#[derive(Clone)]
+pub struct ResetTaskPoolUseCase {
+    pool_repo: Arc<dyn TaskPoolRepository>,
+}
+
+impl ResetTaskPoolUseCase {
+    pub fn new(pool_repo: Arc<dyn TaskPoolRepository>) -> Self {
+        Self { pool_repo }
+    }
+
+    pub async fn execute(&self) -> Result<u64, DomainError> {
+        self.pool_repo.reset_usage().await
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::AuthService;
+use crate::domain::models::{AdminRole, UserId};
+use crate::domain::use_cases::grant_admin::ensure_can_manage_admins;
+
+
+#[derive(Clone)]
+pub struct RevokeAdminUseCase {
+    auth_service: Arc<dyn AuthService>,
+}
+
+impl RevokeAdminUseCase {
+    pub fn new(auth_service: Arc<dyn AuthService>) -> Self {
+        Self { auth_service }
+    }
+
+    pub async fn execute(&self, revoker_id: i64, user_id: i64) -> Result<(), DomainError> {
+        ensure_can_manage_admins(self.auth_service.as_ref(), revoker_id.into()).await?;
+
+        let user_id = UserId(user_id);
+        match self.auth_service.role(user_id).await? {
+            None => Err(DomainError::AdminNotFound(user_id)),
+            Some(AdminRole::Owner) => Err(DomainError::InvalidStateChange("owner role can't be revoked".to_string())),
+            Some(_) => self.auth_service.revoke(user_id).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::models::Admin;
+    use crate::services::MockAuthService;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_revoke() {
+        let auth_service = Arc::new(MockAuthService::with_admin_ids([1, 2]));
+        auth_service.grant(&Admin::new(3, AdminRole::Reviewer)).await.unwrap();
+
+        let use_case = RevokeAdminUseCase::new(auth_service.clone());
+
+        let result = use_case.execute(3, 1).await;
+        assert!(matches!(result, Err(DomainError::PermissionDenied(UserId(3)))));
+
+        let result = use_case.execute(1, 2).await;
+        assert!(matches!(result, Err(DomainError::InvalidStateChange(_))));
+
+        use_case.execute(1, 3).await.expect("failed to revoke role");
+        assert_eq!(auth_service.role(UserId(3)).await.unwrap(), None);
+
+        let result = use_case.execute(1, 3).await;
+        assert!(matches!(result, Err(DomainError::AdminNotFound(UserId(3)))));
+    }
+}
@@ -0,0 +1,161 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{Transaction, UnitOfWork, UserRepository, WeekService};
+use crate::domain::models::WeekId;
+
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RolloverSummary {
+    pub week_id: WeekId,
+    pub reset_users: usize,
+    pub expired_tasks: usize,
+}
+
+#[derive(Clone)]
+pub struct RolloverWeekUseCase {
+    uow: Arc<dyn UnitOfWork>,
+    user_repo: Arc<dyn UserRepository>,
+    week_service: Arc<dyn WeekService>,
+}
+
+impl RolloverWeekUseCase {
+    pub fn new(
+        uow: Arc<dyn UnitOfWork>,
+        user_repo: Arc<dyn UserRepository>,
+        week_service: Arc<dyn WeekService>,
+    ) -> Self {
+        Self { uow, user_repo, week_service }
+    }
+
+    pub async fn execute(&self) -> Result<RolloverSummary, DomainError> {
+        let week_id = self.week_service.current();
+
+        let mut tx = self.uow.begin().await?;
+        match self.rollover(tx.as_mut(), week_id).await {
+            Ok(summary) => {
+                tx.commit().await?;
+                Ok(summary)
+            }
+            Err(err) => {
+                if let Err(rollback_err) = tx.rollback().await {
+                    log::error!("failed to roll back transaction: {}", rollback_err);
+                }
+                Err(err)
+            }
+        }
+    }
+
+    async fn rollover(&self, tx: &mut dyn Transaction, week_id: WeekId) -> Result<RolloverSummary, DomainError> {
+        let mut summary = RolloverSummary { week_id, reset_users: 0, expired_tasks: 0 };
+
+        for mut user in self.user_repo.all().await? {
+            if !user.reset_next_task_status() {
+                continue;
+            }
+
+            // a user changed concurrently must not stop the rollover of the others
+            match self.user_repo.update_in(tx, &user).await {
+                Ok(()) => summary.reset_users += 1,
+                Err(DomainError::ConcurrentModification(id)) => {
+                    log::warn!("user {} was modified concurrently, skipping its rollover", id);
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        // users may already be paired for the new week, so their latest task is not the stale one
+        summary.expired_tasks = self.user_repo.expire_tasks_in(tx, week_id).await? as usize;
+
+        Ok(summary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Weekday;
+    use crate::domain::models::{NextTaskStatus, User, UserTaskState};
+    use crate::services::{week_service_at, InMemoryUnitOfWork, InMemoryUserRepository};
+    use super::*;
+
+    fn use_case() -> (RolloverWeekUseCase, Arc<InMemoryUserRepository>) {
+        let user_repo = Arc::new(InMemoryUserRepository::default());
+        let week_service = week_service_at(2025, 12, Weekday::Mon);
+        (RolloverWeekUseCase::new(Arc::new(InMemoryUnitOfWork), user_repo.clone(), week_service), user_repo)
+    }
+
+    fn promoted_user(id: i64, week_id: WeekId) -> User {
+        let mut user = User::new(id, Some(format!("user{}", id)));
+        user.accept().unwrap();
+        user.promote(&[(id + 1).into()], week_id).unwrap();
+        user
+    }
+
+    #[tokio::test]
+    async fn test_rollover_resets_rejected_users() {
+        let (use_case, user_repo) = use_case();
+
+        let mut rejected = User::new(1, Some("rejected"));
+        rejected.reject().unwrap();
+        let mut accepted = User::new(2, Some("accepted"));
+        accepted.accept().unwrap();
+        let pending = User::new(3, Some("pending"));
+
+        for user in [&rejected, &accepted, &pending] {
+            user_repo.save(user).await.unwrap();
+        }
+
+        let summary = use_case.execute().await.unwrap();
+
+        assert_eq!(summary.week_id, WeekId::new(2025, 12));
+        assert_eq!(summary.reset_users, 1);
+        assert_eq!(user_repo.user(rejected.id()).await.unwrap().next_task_status(), NextTaskStatus::Pending);
+        assert_eq!(user_repo.user(accepted.id()).await.unwrap().next_task_status(), NextTaskStatus::Accepted);
+        assert_eq!(user_repo.user(pending.id()).await.unwrap().next_task_status(), NextTaskStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_rollover_expires_stale_tasks() {
+        let (use_case, user_repo) = use_case();
+
+        let stale = promoted_user(10, WeekId::new(2025, 11));
+        let current = promoted_user(20, WeekId::new(2025, 12));
+        let mut completed = promoted_user(30, WeekId::new(2025, 11));
+        completed.complete_task().unwrap();
+
+        for user in [&stale, &current, &completed] {
+            user_repo.save(user).await.unwrap();
+        }
+
+        let summary = use_case.execute().await.unwrap();
+
+        assert_eq!(summary.expired_tasks, 1);
+        let state = |user: User| user.user_task().unwrap().state().clone();
+        assert_eq!(state(user_repo.user(stale.id()).await.unwrap()), UserTaskState::Expired);
+        assert_eq!(state(user_repo.user(current.id()).await.unwrap()), UserTaskState::Active);
+        assert_eq!(state(user_repo.user(completed.id()).await.unwrap()), UserTaskState::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_rollover_expires_task_of_user_paired_for_new_week() {
+        let (use_case, user_repo) = use_case();
+
+        let mut user = promoted_user(10, WeekId::new(2025, 11));
+        user_repo.save(&user).await.unwrap();
+        user.accept().unwrap();
+        user.promote(&[11.into()], WeekId::new(2025, 12)).unwrap();
+        user_repo.update(&mut user).await.unwrap();
+
+        let summary = use_case.execute().await.unwrap();
+
+        assert_eq!(summary.expired_tasks, 1);
+        let stored = user_repo.user(user.id()).await.unwrap();
+        assert_eq!(stored.user_task().unwrap().task_id(), WeekId::new(2025, 12));
+        assert_eq!(*stored.user_task().unwrap().state(), UserTaskState::Active);
+        let history = user_repo.history(user.id()).await.unwrap();
+        assert_eq!(*history.user_tasks()[0].state(), UserTaskState::Expired);
+
+        let result = user_repo.update(&mut user).await;
+        assert!(matches!(result, Err(DomainError::ConcurrentModification(_))));
+    }
+}
@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{TaskRepository, WeekService};
+use crate::domain::use_cases::{PlanPoolTaskUseCase, TaskDto};
+
+
+#[derive(Clone)]
+pub struct RotateTaskPoolUseCase {
+    plan_use_case: PlanPoolTaskUseCase,
+    task_repo: Arc<dyn TaskRepository>,
+    week_service: Arc<dyn WeekService>,
+}
+
+impl RotateTaskPoolUseCase {
+    pub fn new(
+        plan_use_case: PlanPoolTaskUseCase,
+        task_repo: Arc<dyn TaskRepository>,
+        week_service: Arc<dyn WeekService>,
+    ) -> Self {
+        Self { plan_use_case, task_repo, week_service }
+    }
+
+    /// `None` if an admin has already planned the upcoming week
+    pub async fn execute(&self) -> Result<Option<TaskDto>, DomainError> {
+        let next_week_id = self.week_service.next(self.week_service.current());
+        match self.task_repo.task(next_week_id).await {
+            Ok(_) => return Ok(None),
+            Err(DomainError::TaskNotFound(_)) => {}
+            Err(err) => return Err(err),
+        }
+
+        match self.plan_use_case.execute(next_week_id.into(), None).await {
+            Ok(task) => Ok(Some(task)),
+            // An admin has planned the week in the meantime
+            Err(DomainError::TaskAlreadyExists(_)) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Weekday;
+    use crate::domain::interfaces::TaskPoolRepository;
+    use crate::domain::models::{NewPoolTask, Task, WeekId};
+    use crate::services::{week_service_at, InMemoryTaskPoolRepository, InMemoryTaskRepository, InMemoryUnitOfWork};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_rotation_fills_only_empty_week() {
+        let week_service = week_service_at(2025, 12, Weekday::Mon);
+        let task_repo = Arc::new(InMemoryTaskRepository::default());
+        let pool_repo = Arc::new(InMemoryTaskPoolRepository::default());
+        let plan_use_case = PlanPoolTaskUseCase::new(
+            Arc::new(InMemoryUnitOfWork),
+            task_repo.clone(),
+            pool_repo.clone(),
+            week_service.clone(),
+        );
+        let use_case = RotateTaskPoolUseCase::new(plan_use_case, task_repo.clone(), week_service);
+
+        let result = use_case.execute().await;
+        assert!(matches!(result, Err(DomainError::TaskPoolExhausted)));
+
+        pool_repo.add(&NewPoolTask::new("Прогулка", "Lorem ipsum", "-").unwrap()).await.unwrap();
+        let task = use_case.execute().await.expect("failed to rotate").expect("week must be planned");
+        assert_eq!(task.title, "Прогулка");
+        assert_eq!(task_repo.task(WeekId::new(2025, 13)).await.unwrap().title(), "Прогулка");
+
+        pool_repo.add(&NewPoolTask::new("Кофе", "Lorem ipsum", "-").unwrap()).await.unwrap();
+        assert!(use_case.execute().await.expect("failed to rotate").is_none());
+        assert_eq!(pool_repo.available(None).await.unwrap().len(), 1);
+
+        task_repo.save(&Task::new(WeekId::new(2025, 14), "Planned", "Lorem ipsum")).await.unwrap();
+        assert!(use_case.execute().await.is_ok());
+    }
+}
@@ -1,26 +1,74 @@
 use std::sync::Arc;
 
 use crate::domain::error::DomainError;
-use crate::domain::interfaces::UserRepository;
+use crate::domain::interfaces::{Transaction, UnitOfWork, UserRepository};
 use crate::domain::models;
+use crate::domain::use_cases::sync_username::release_username;
 
 
 #[derive(Clone)]
 pub struct StartRegistrationUseCase {
+    uow: Arc<dyn UnitOfWork>,
     user_repo: Arc<dyn UserRepository>,
 }
 
 impl StartRegistrationUseCase {
-    pub fn new(user_repo: Arc<dyn UserRepository>) -> Self {
-        Self { user_repo }
+    pub fn new(uow: Arc<dyn UnitOfWork>, user_repo: Arc<dyn UserRepository>) -> Self {
+        Self { uow, user_repo }
     }
     
+    /// A user who has not completed the profile yet may start it over
     pub async fn execute(
         &self, 
         user_id: i64, 
-        username: &str,
+        username: Option<&str>,
     ) -> Result<(), DomainError> { 
         let user = models::User::new(user_id, username);
-        self.user_repo.save(&user).await
+
+        let mut tx = self.uow.begin().await?;
+        match self.register(tx.as_mut(), &user).await {
+            Ok(()) => tx.commit().await,
+            Err(err) => {
+                if let Err(rollback_err) = tx.rollback().await {
+                    log::error!("failed to roll back transaction: {}", rollback_err);
+                }
+                Err(err)
+            }
+        }
+    }
+
+    async fn register(&self, tx: &mut dyn Transaction, user: &models::User) -> Result<(), DomainError> {
+        match self.user_repo.find_user(user.id()).await? {
+            Some(stored) if stored.profile_completed() => return Err(DomainError::UserAlreadyExists(user.id())),
+            Some(_) => return Ok(()),
+            None => {}
+        }
+        if let Some(username) = user.username() {
+            release_username(self.user_repo.as_ref(), tx, username, user.id()).await?;
+        }
+        self.user_repo.save_in(tx, user).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::models::{Profile, User};
+    use crate::services::{InMemoryUnitOfWork, InMemoryUserRepository};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_restart_unfinished_registration() {
+        let user_repo = Arc::new(InMemoryUserRepository::default());
+        let use_case = StartRegistrationUseCase::new(Arc::new(InMemoryUnitOfWork), user_repo.clone());
+
+        use_case.execute(1, Some("alice")).await.unwrap();
+        use_case.execute(1, Some("alice")).await.unwrap();
+
+        let mut registered = User::new(2, Some("bob"));
+        registered.set_profile(Profile::restore("Ivanov Ivan", "СМ13-13Б"));
+        user_repo.save(&registered).await.unwrap();
+
+        let result = use_case.execute(2, Some("bob")).await;
+        assert!(matches!(result, Err(DomainError::UserAlreadyExists(_))));
     }
 }
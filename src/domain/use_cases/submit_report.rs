@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{ReportRepository, Transaction, UnitOfWork, UserRepository};
+use crate::domain::models::Report;
+
+
+#[derive(Clone)]
+pub struct SubmitReportUseCase {
+    uow: Arc<dyn UnitOfWork>,
+    user_repo: Arc<dyn UserRepository>,
+    report_repo: Arc<dyn ReportRepository>,
+}
+
+impl SubmitReportUseCase {
+    pub fn new(
+        uow: Arc<dyn UnitOfWork>,
+        user_repo: Arc<dyn UserRepository>,
+        report_repo: Arc<dyn ReportRepository>,
+    ) -> Self {
+        Self { uow, user_repo, report_repo }
+    }
+
+    pub async fn execute(
+        &self,
+        user_id: i64,
+        photo_file_ids: Vec<String>,
+        caption: Option<String>,
+    ) -> Result<(), DomainError> {
+        let mut tx = self.uow.begin().await?;
+        match self.submit(tx.as_mut(), user_id, photo_file_ids, caption).await {
+            Ok(()) => tx.commit().await,
+            Err(err) => {
+                if let Err(rollback_err) = tx.rollback().await {
+                    log::error!("failed to roll back transaction: {}", rollback_err);
+                }
+                Err(err)
+            }
+        }
+    }
+
+    async fn submit(
+        &self,
+        tx: &mut dyn Transaction,
+        user_id: i64,
+        photo_file_ids: Vec<String>,
+        caption: Option<String>,
+    ) -> Result<(), DomainError> {
+        let mut user = self.user_repo.user_in(tx, user_id.into()).await?;
+        let task_id = user.user_task()
+            .ok_or(DomainError::NoUserTask)?
+            .task_id();
+        let report = Report::new(user.id(), task_id, photo_file_ids, caption)?;
+
+        user.submit_report()?;
+        self.report_repo.save_in(tx, &report).await?;
+        self.user_repo.update_in(tx, &user).await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::models::{User, UserTaskState, WeekId};
+    use crate::services::{InMemoryReportRepository, InMemoryUnitOfWork, InMemoryUserRepository};
+    use super::*;
+
+    async fn setup() -> (SubmitReportUseCase, Arc<InMemoryUserRepository>, Arc<InMemoryReportRepository>) {
+        let user_repo = Arc::new(InMemoryUserRepository::default());
+        let report_repo = Arc::new(InMemoryReportRepository::default());
+
+        let mut user = User::new(1, Some("alice"));
+        user.accept().unwrap();
+        user.promote(&[2.into()], WeekId::new(2025, 12)).unwrap();
+        user_repo.save(&user).await.unwrap();
+
+        let use_case = SubmitReportUseCase::new(
+            Arc::new(InMemoryUnitOfWork),
+            user_repo.clone(),
+            report_repo.clone(),
+        );
+        (use_case, user_repo, report_repo)
+    }
+
+    #[tokio::test]
+    async fn test_submit_report_awaits_review() {
+        let (use_case, user_repo, report_repo) = setup().await;
+
+        use_case.execute(1, vec!["photo".to_string()], Some(" Done! ".to_string())).await.unwrap();
+
+        let user = user_repo.user(1.into()).await.unwrap();
+        assert_eq!(*user.user_task().unwrap().state(), UserTaskState::AwaitingReview);
+        let report = report_repo.report(1.into(), WeekId::new(2025, 12)).await.unwrap();
+        assert_eq!(report.photo_file_ids(), ["photo".to_string()]);
+        assert_eq!(report.caption(), Some("Done!"));
+    }
+
+    #[tokio::test]
+    async fn test_submit_report_without_photos() {
+        let (use_case, user_repo, _) = setup().await;
+
+        let result = use_case.execute(1, Vec::new(), None).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidValue(_))));
+        let user = user_repo.user(1.into()).await.unwrap();
+        assert_eq!(*user.user_task().unwrap().state(), UserTaskState::Active);
+    }
+
+    #[tokio::test]
+    async fn test_submit_report_twice() {
+        let (use_case, _, _) = setup().await;
+
+        use_case.execute(1, vec!["photo".to_string()], None).await.unwrap();
+        let result = use_case.execute(1, vec!["photo".to_string()], None).await;
+
+        assert!(matches!(result, Err(DomainError::InvalidStateChange(_))));
+    }
+}
@@ -0,0 +1,114 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{Transaction, UnitOfWork, UserRepository};
+use crate::domain::models::UserId;
+
+
+#[derive(Clone)]
+pub struct SyncUsernameUseCase {
+    uow: Arc<dyn UnitOfWork>,
+    user_repo: Arc<dyn UserRepository>,
+}
+
+impl SyncUsernameUseCase {
+    pub fn new(uow: Arc<dyn UnitOfWork>, user_repo: Arc<dyn UserRepository>) -> Self {
+        Self { uow, user_repo }
+    }
+
+    /// Returns `false` if the username has not changed since the last sync
+    pub async fn execute(&self, user_id: i64, username: Option<&str>) -> Result<bool, DomainError> {
+        let user = self.user_repo.user(user_id.into()).await?;
+        if user.username() == username {
+            return Ok(false);
+        }
+
+        let mut tx = self.uow.begin().await?;
+        match self.sync(tx.as_mut(), user_id.into(), username).await {
+            Ok(()) => {
+                tx.commit().await?;
+                Ok(true)
+            }
+            Err(err) => {
+                if let Err(rollback_err) = tx.rollback().await {
+                    log::error!("failed to roll back transaction: {}", rollback_err);
+                }
+                Err(err)
+            }
+        }
+    }
+
+    async fn sync(
+        &self,
+        tx: &mut dyn Transaction,
+        user_id: UserId,
+        username: Option<&str>,
+    ) -> Result<(), DomainError> {
+        let mut user = self.user_repo.user_in(tx, user_id).await?;
+        if user.username() == username {
+            return Ok(());
+        }
+
+        if let Some(username) = username {
+            release_username(self.user_repo.as_ref(), tx, username, user_id).await?;
+        }
+
+        user.set_username(username.map(String::from));
+        self.user_repo.update_in(tx, &user).await
+    }
+}
+
+/// Telegram lets a username pass to another account once its previous owner
+/// drops it, so the stale copy has to be cleared before it can be stored again
+pub(super) async fn release_username(
+    user_repo: &dyn UserRepository,
+    tx: &mut dyn Transaction,
+    username: &str,
+    new_holder: UserId,
+) -> Result<(), DomainError> {
+    let Some(mut holder) = user_repo.user_by_username_in(tx, username).await? else {
+        return Ok(());
+    };
+    if holder.id() == new_holder {
+        return Ok(());
+    }
+
+    log::info!("username @{} has passed from user {} to user {}", username, holder.id(), new_holder);
+    holder.set_username(None);
+    user_repo.update_in(tx, &holder).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::{InMemoryUnitOfWork, InMemoryUserRepository};
+    use crate::domain::models::User;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sync_changed_username() {
+        let user_repo = Arc::new(InMemoryUserRepository::default());
+        user_repo.save(&User::new(1, Some("alice"))).await.unwrap();
+
+        let use_case = SyncUsernameUseCase::new(Arc::new(InMemoryUnitOfWork), user_repo.clone());
+
+        assert!(!use_case.execute(1, Some("alice")).await.unwrap());
+        assert!(use_case.execute(1, Some("alice_2")).await.unwrap());
+        assert_eq!(user_repo.user(UserId(1)).await.unwrap().username(), Some("alice_2"));
+
+        assert!(use_case.execute(1, None).await.unwrap());
+        assert_eq!(user_repo.user(UserId(1)).await.unwrap().username(), None);
+    }
+
+    #[tokio::test]
+    async fn test_sync_username_taken_from_previous_holder() {
+        let user_repo = Arc::new(InMemoryUserRepository::default());
+        user_repo.save(&User::new(1, Some("alice"))).await.unwrap();
+        user_repo.save(&User::new(2, Some("bob"))).await.unwrap();
+
+        let use_case = SyncUsernameUseCase::new(Arc::new(InMemoryUnitOfWork), user_repo.clone());
+
+        assert!(use_case.execute(2, Some("alice")).await.unwrap());
+        assert_eq!(user_repo.user(UserId(1)).await.unwrap().username(), None);
+        assert_eq!(user_repo.user(UserId(2)).await.unwrap().username(), Some("alice"));
+    }
+}
@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{TaskRepository, WeekService};
+use crate::domain::models::{Task, WeekId};
+use crate::domain::use_cases::create_task::ensure_future_week;
+use crate::domain::use_cases::TaskMediaDto;
+
+
+#[derive(Clone)]
+pub struct UpdateTaskUseCase {
+    task_repo: Arc<dyn TaskRepository>,
+    week_service: Arc<dyn WeekService>,
+}
+
+impl UpdateTaskUseCase {
+    pub fn new(task_repo: Arc<dyn TaskRepository>, week_service: Arc<dyn WeekService>) -> Self {
+        Self { task_repo, week_service }
+    }
+
+    pub async fn execute(
+        &self,
+        week_id: (i32, u32),
+        title: &str,
+        text: &str,
+        media: Option<TaskMediaDto>,
+    ) -> Result<(), DomainError> {
+        let week_id = WeekId::new(week_id.0, week_id.1);
+        ensure_future_week(self.week_service.as_ref(), week_id)?;
+
+        let task = Task::new(week_id, title, text).with_media(media.map(Into::into));
+        self.task_repo.update(&task).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Weekday;
+    use crate::services::{week_service_at, InMemoryTaskRepository};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_update_task_for_future_week_only() {
+        let task_repo = Arc::new(InMemoryTaskRepository::default());
+        task_repo.save(&Task::new(WeekId::new(2025, 12), "Title", "Text")).await.unwrap();
+        task_repo.save(&Task::new(WeekId::new(2025, 13), "Title", "Text")).await.unwrap();
+        let use_case = UpdateTaskUseCase::new(task_repo.clone(), week_service_at(2025, 12, Weekday::Wed));
+
+        use_case.execute((2025, 13), "Fixed", "Text", None).await.expect("failed to update task");
+        assert_eq!(task_repo.task(WeekId::new(2025, 13)).await.unwrap().title(), "Fixed");
+
+        let result = use_case.execute((2025, 12), "Fixed", "Text", None).await;
+        assert!(matches!(result, Err(DomainError::WeekAlreadyStarted(_))));
+        assert_eq!(task_repo.task(WeekId::new(2025, 12)).await.unwrap().title(), "Title");
+    }
+}
@@ -0,0 +1,106 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use teloxide::prelude::*;
+use teloxide::RequestError;
+
+use crate::domain::interfaces::Clock;
+use crate::domain::use_cases::{GetAdminsUseCase, GetPendingUsersUseCase, UserDto};
+use crate::jobs::WeeklySchedule;
+use crate::presentation::handlers::admin::{failure_reason, send_broadcast_report, NotificationFailure};
+use crate::presentation::handlers::user::send_next_task_prompt;
+
+
+pub struct NextTaskBroadcastJob {
+    bot: Bot,
+    get_pending_users_use_case: GetPendingUsersUseCase,
+    get_admins_use_case: GetAdminsUseCase,
+    schedule: WeeklySchedule,
+    clock: Arc<dyn Clock>,
+    interval: Duration,
+}
+
+impl NextTaskBroadcastJob {
+    pub fn new(
+        bot: Bot,
+        get_pending_users_use_case: GetPendingUsersUseCase,
+        get_admins_use_case: GetAdminsUseCase,
+        schedule: WeeklySchedule,
+        clock: Arc<dyn Clock>,
+        messages_per_second: u32,
+    ) -> Self {
+        let interval = Duration::from_secs(1) / messages_per_second.max(1);
+        Self { bot, get_pending_users_use_case, get_admins_use_case, schedule, clock, interval }
+    }
+
+    pub async fn run(self) {
+        loop {
+            let now = self.clock.now();
+            let next_run = self.schedule.next_after(now);
+            let delay = (next_run - now)
+                .to_std()
+                .unwrap_or_default();
+            log::info!("next task broadcast is scheduled at {}", next_run);
+
+            tokio::time::sleep(delay).await;
+
+            self.broadcast().await;
+        }
+    }
+
+    async fn broadcast(&self) {
+        let users = match self.get_pending_users_use_case.execute().await {
+            Ok(users) => users,
+            Err(err) => {
+                log::error!("failed to get pending users for broadcast: {}", err);
+                return;
+            }
+        };
+
+        let mut sent = 0;
+        let mut failures = Vec::new();
+        for user in users {
+            match self.send(&user).await {
+                Ok(()) => sent += 1,
+                Err(err) => {
+                    log::warn!("failed to send next task prompt to user {}: {}", user.id, err);
+                    failures.push(NotificationFailure {
+                        user: user.contact(),
+                        reason: failure_reason(&err),
+                    });
+                }
+            }
+            tokio::time::sleep(self.interval).await;
+        }
+        log::info!("next task broadcast finished: {} sent, {} failed", sent, failures.len());
+
+        self.report(sent, &failures).await;
+    }
+
+    async fn send(&self, user: &UserDto) -> Result<(), RequestError> {
+        let chat_id = ChatId(user.id);
+        match send_next_task_prompt(&self.bot, chat_id).await {
+            Err(RequestError::RetryAfter(seconds)) => {
+                tokio::time::sleep(seconds.duration()).await;
+                send_next_task_prompt(&self.bot, chat_id).await
+            }
+            result => result,
+        }
+    }
+
+    async fn report(&self, sent: usize, failures: &[NotificationFailure]) {
+        let admin_ids = match self.get_admins_use_case.execute().await {
+            Ok(admin_ids) => admin_ids,
+            Err(err) => {
+                log::error!("failed to get admins for broadcast report: {}", err);
+                return;
+            }
+        };
+
+        for admin_id in admin_ids {
+            if let Err(err) = send_broadcast_report(&self.bot, ChatId(admin_id), sent, failures).await {
+                log::warn!("failed to send broadcast report to admin {}: {}", admin_id, err);
+            }
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod broadcast;
+mod reminder;
+mod rollover;
+mod schedule;
+
+pub use broadcast::*;
+pub use reminder::*;
+pub use rollover::*;
+pub use schedule::*;
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use teloxide::prelude::*;
+use teloxide::RequestError;
+
+use crate::domain::interfaces::Clock;
+use crate::domain::use_cases::{GetTaskRemindersUseCase, TaskReminderDto};
+use crate::jobs::WeeklySchedule;
+use crate::presentation::handlers::user::send_task_reminder;
+
+
+pub struct TaskReminderJob {
+    bot: Bot,
+    use_case: GetTaskRemindersUseCase,
+    schedule: WeeklySchedule,
+    clock: Arc<dyn Clock>,
+    interval: Duration,
+}
+
+impl TaskReminderJob {
+    pub fn new(
+        bot: Bot,
+        use_case: GetTaskRemindersUseCase,
+        schedule: WeeklySchedule,
+        clock: Arc<dyn Clock>,
+        messages_per_second: u32,
+    ) -> Self {
+        let interval = Duration::from_secs(1) / messages_per_second.max(1);
+        Self { bot, use_case, schedule, clock, interval }
+    }
+
+    pub async fn run(self) {
+        loop {
+            let now = self.clock.now();
+            let next_run = self.schedule.next_after(now);
+            let delay = (next_run - now)
+                .to_std()
+                .unwrap_or_default();
+            log::info!("next task reminder is scheduled at {}", next_run);
+
+            tokio::time::sleep(delay).await;
+
+            self.remind().await;
+        }
+    }
+
+    async fn remind(&self) {
+        let reminders = match self.use_case.execute().await {
+            Ok(reminders) => reminders,
+            Err(err) => {
+                log::error!("failed to get users for task reminder: {}", err);
+                return;
+            }
+        };
+
+        let mut sent = 0;
+        for reminder in &reminders {
+            match self.send(reminder).await {
+                Ok(()) => sent += 1,
+                Err(err) => log::warn!("failed to remind user {} about the task: {}", reminder.user_id, err),
+            }
+            tokio::time::sleep(self.interval).await;
+        }
+        log::info!("task reminder finished: {} of {} sent", sent, reminders.len());
+    }
+
+    async fn send(&self, reminder: &TaskReminderDto) -> Result<(), RequestError> {
+        match send_task_reminder(&self.bot, reminder).await {
+            Err(RequestError::RetryAfter(seconds)) => {
+                tokio::time::sleep(seconds.duration()).await;
+                send_task_reminder(&self.bot, reminder).await
+            }
+            result => result,
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Local};
+
+use crate::domain::interfaces::{Clock, WeekService};
+use crate::domain::use_cases::{RolloverWeekUseCase, RotateTaskPoolUseCase};
+
+
+pub struct RolloverJob {
+    use_case: RolloverWeekUseCase,
+    week_service: Arc<dyn WeekService>,
+    clock: Arc<dyn Clock>,
+    rotation: Option<RotateTaskPoolUseCase>,
+}
+
+impl RolloverJob {
+    pub fn new(
+        use_case: RolloverWeekUseCase,
+        week_service: Arc<dyn WeekService>,
+        clock: Arc<dyn Clock>,
+    ) -> Self {
+        Self { use_case, week_service, clock, rotation: None }
+    }
+
+    /// Plans a pool task for the upcoming week after each rollover if none is planned yet
+    pub fn with_task_pool_rotation(mut self, use_case: RotateTaskPoolUseCase) -> Self {
+        self.rotation = Some(use_case);
+        self
+    }
+
+    pub async fn run(self) {
+        loop {
+            let next_run = self.next_run();
+            let delay = (next_run - self.clock.now())
+                .to_std()
+                .unwrap_or_default();
+            log::info!("next week rollover is scheduled at {}", next_run);
+
+            tokio::time::sleep(delay).await;
+
+            match self.use_case.execute().await {
+                Ok(summary) => log::info!(
+                    "week {} has started: {} users reset to pending, {} tasks expired",
+                    summary.week_id, summary.reset_users, summary.expired_tasks,
+                ),
+                Err(err) => log::error!("failed to roll over week: {}", err),
+            }
+
+            if let Some(rotation) = &self.rotation {
+                match rotation.execute().await {
+                    Ok(Some(task)) => log::info!("task \"{}\" is planned from the pool for the next week", task.title),
+                    Ok(None) => log::info!("next week already has a task, pool rotation skipped"),
+                    Err(err) => log::error!("failed to rotate task pool: {}", err),
+                }
+            }
+        }
+    }
+
+    fn next_run(&self) -> DateTime<Local> {
+        let current_week = self.week_service.current();
+        self.week_service.start(self.week_service.next(current_week))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use crate::services::{ChronoWeekService, FixedClock, InMemoryUnitOfWork, InMemoryUserRepository};
+    use super::*;
+
+    fn job_at(now: DateTime<Local>) -> RolloverJob {
+        let clock = Arc::new(FixedClock::new(now));
+        let week_service: Arc<ChronoWeekService> = Arc::new(ChronoWeekService::new(clock.clone()));
+        let use_case = RolloverWeekUseCase::new(
+            Arc::new(InMemoryUnitOfWork),
+            Arc::new(InMemoryUserRepository::default()),
+            week_service.clone(),
+        );
+        RolloverJob::new(use_case, week_service, clock)
+    }
+
+    #[test]
+    fn test_next_run_is_next_monday() {
+        let job = job_at(Local.with_ymd_and_hms(2025, 3, 12, 15, 30, 0).unwrap());
+
+        assert_eq!(job.next_run(), Local.with_ymd_and_hms(2025, 3, 17, 0, 0, 0).unwrap());
+    }
+
+    #[test]
+    fn test_next_run_at_boundary() {
+        let job = job_at(Local.with_ymd_and_hms(2025, 3, 17, 0, 0, 0).unwrap());
+
+        assert_eq!(job.next_run(), Local.with_ymd_and_hms(2025, 3, 24, 0, 0, 0).unwrap());
+    }
+}
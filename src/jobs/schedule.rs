@@ -0,0 +1,115 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, Duration, Local, NaiveTime, TimeZone, Weekday};
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeeklySchedule {
+    weekday: Weekday,
+    time: NaiveTime,
+}
+
+impl WeeklySchedule {
+    pub fn new(weekday: Weekday, time: NaiveTime) -> Self {
+        Self { weekday, time }
+    }
+
+    pub fn next_after(&self, now: DateTime<Local>) -> DateTime<Local> {
+        let days_ahead = (self.weekday.num_days_from_monday() + 7
+            - now.weekday().num_days_from_monday()) % 7;
+        let date = now.date_naive() + Duration::days(days_ahead as i64);
+
+        let candidate = self.at(date);
+        if candidate > now {
+            candidate
+        } else {
+            self.at(date + Duration::weeks(1))
+        }
+    }
+
+    fn at(&self, date: chrono::NaiveDate) -> DateTime<Local> {
+        let local = date.and_time(self.time);
+        Local.from_local_datetime(&local)
+            .earliest()
+            .unwrap_or_else(|| Local.from_utc_datetime(&local))
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+#[error("invalid schedule {0:?}, expected format like \"Mon 10:00\"")]
+pub struct ScheduleParseError(String);
+
+impl FromStr for WeeklySchedule {
+    type Err = ScheduleParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ScheduleParseError(s.to_string());
+
+        let (weekday, time) = s.trim()
+            .split_once(' ')
+            .ok_or_else(err)?;
+        let weekday = weekday.parse::<Weekday>().map_err(|_| err())?;
+        let time = NaiveTime::parse_from_str(time.trim(), "%H:%M").map_err(|_| err())?;
+
+        Ok(Self::new(weekday, time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(s: &str) -> WeeklySchedule {
+        s.parse().expect("failed to parse schedule")
+    }
+
+    #[test]
+    fn test_parse_schedule() {
+        assert_eq!(
+            schedule("Mon 10:00"),
+            WeeklySchedule::new(Weekday::Mon, NaiveTime::from_hms_opt(10, 0, 0).unwrap()),
+        );
+        assert_eq!(
+            schedule("thursday 18:30"),
+            WeeklySchedule::new(Weekday::Thu, NaiveTime::from_hms_opt(18, 30, 0).unwrap()),
+        );
+        assert!("Mon".parse::<WeeklySchedule>().is_err());
+        assert!("Someday 10:00".parse::<WeeklySchedule>().is_err());
+        assert!("Mon 25:00".parse::<WeeklySchedule>().is_err());
+    }
+
+    #[test]
+    fn test_next_after_later_this_week() {
+        // Wednesday
+        let now = Local.with_ymd_and_hms(2025, 3, 12, 15, 0, 0).unwrap();
+
+        assert_eq!(
+            schedule("Fri 10:00").next_after(now),
+            Local.with_ymd_and_hms(2025, 3, 14, 10, 0, 0).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_next_after_same_day() {
+        let now = Local.with_ymd_and_hms(2025, 3, 12, 9, 0, 0).unwrap();
+
+        assert_eq!(
+            schedule("Wed 10:00").next_after(now),
+            Local.with_ymd_and_hms(2025, 3, 12, 10, 0, 0).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_next_after_passed_moves_to_next_week() {
+        let now = Local.with_ymd_and_hms(2025, 3, 12, 10, 0, 0).unwrap();
+
+        assert_eq!(
+            schedule("Wed 10:00").next_after(now),
+            Local.with_ymd_and_hms(2025, 3, 19, 10, 0, 0).unwrap(),
+        );
+        assert_eq!(
+            schedule("Mon 10:00").next_after(now),
+            Local.with_ymd_and_hms(2025, 3, 17, 10, 0, 0).unwrap(),
+        );
+    }
+}
@@ -1,10 +1,13 @@
 use std::env;
 use std::sync::Arc;
 use dotenv::dotenv;
+use teloxide::dispatching::dialogue::Storage;
 use teloxide::prelude::*;
 
-use crate::dispatcher::CwDispatcher;
+use crate::dispatcher::{AdminRightsUseCases, AdminUseCases, CwDispatcher, ReviewUseCases, TaskPlanningUseCases, UserUseCases};
+use crate::domain::models::{GroupNamePatterns, UserId};
 use crate::domain::use_cases::*;
+use crate::jobs::{NextTaskBroadcastJob, RolloverJob, TaskReminderJob, WeeklySchedule};
 use crate::services::*;
 use crate::utils::postgres::pool;
 
@@ -12,6 +15,7 @@ mod domain;
 mod services;
 mod presentation;
 mod dispatcher;
+mod jobs;
 mod utils;
 
 
@@ -29,18 +33,49 @@ async fn main() {
     let admin_ids_str = env::var("ADMIN_IDS")
         .expect("ADMIN_IDS must be set");
 
-    let admin_ids: Vec<i64> = admin_ids_str
+    let admin_ids: Vec<UserId> = admin_ids_str
         .split(',')
-        .map(|s| s.trim().parse().expect("invalid admin ID format"))
+        .map(|s| UserId(s.trim().parse().expect("invalid admin ID format")))
         .collect();
 
+    let broadcast_schedule: WeeklySchedule = env::var("NEXT_TASK_BROADCAST_AT")
+        .unwrap_or_else(|_| String::from("Mon 10:00"))
+        .parse()
+        .expect("invalid NEXT_TASK_BROADCAST_AT format");
+    let reminder_schedule: WeeklySchedule = env::var("TASK_REMINDER_AT")
+        .unwrap_or_else(|_| String::from("Thu 18:00"))
+        .parse()
+        .expect("invalid TASK_REMINDER_AT format");
+    let group_name_patterns: GroupNamePatterns = match env::var("GROUP_NAME_PATTERNS") {
+        Ok(patterns) => patterns.parse().expect("invalid GROUP_NAME_PATTERNS format"),
+        Err(_) => GroupNamePatterns::default(),
+    };
+    let broadcast_rate: u32 = env::var("BROADCAST_MESSAGES_PER_SECOND")
+        .unwrap_or_else(|_| String::from("20"))
+        .parse()
+        .expect("invalid BROADCAST_MESSAGES_PER_SECOND format");
+    let task_pool_rotation: bool = env::var("TASK_POOL_ROTATION")
+        .unwrap_or_else(|_| String::from("false"))
+        .parse()
+        .expect("invalid TASK_POOL_ROTATION format");
+
     let user_repo = Arc::new(PostgresUserRepository::new(pool.clone()));
     let task_repo = Arc::new(PostgresTaskRepository::new(pool.clone()));
-    let auth_service = Arc::new(MockAuthService::with_admin_ids(admin_ids));
-    let week_service = Arc::new(ChronoWeekService::default());
+    let report_repo = Arc::new(PostgresReportRepository::new(pool.clone()));
+    let pool_repo = Arc::new(PostgresTaskPoolRepository::new(pool.clone()));
+    let uow = Arc::new(PostgresUnitOfWork::new(pool.clone()));
+    let dialogue_storage = PostgresDialogueStorage::new(pool.clone()).erase();
+    let auth_service = Arc::new(PostgresAuthService::new(pool.clone()));
+    auth_service.seed_owners(&admin_ids)
+        .await
+        .expect("failed to seed owners from ADMIN_IDS");
+    let clock = Arc::new(SystemClock);
+    let week_service = Arc::new(ChronoWeekService::new(clock.clone()));
 
-    let start_registration_use_case = StartRegistrationUseCase::new(user_repo.clone());
-    let complete_registration_use_case = CompleteRegistrationUseCase::new(user_repo.clone());
+    let start_registration_use_case = StartRegistrationUseCase::new(uow.clone(), user_repo.clone());
+    let complete_registration_use_case = CompleteRegistrationUseCase::new(user_repo.clone(), group_name_patterns);
+    let check_full_name_use_case = CheckFullNameUseCase::new();
+    let sync_username_use_case = SyncUsernameUseCase::new(uow.clone(), user_repo.clone());
     let accept_next_task_use_case = AcceptNextTaskUseCase::new(user_repo.clone());
     let reject_next_task_use_case = RejectTaskUseCase::new(user_repo.clone());
     let get_menu_state_use_case = GetMenuStateUseCase::new(user_repo.clone());
@@ -49,32 +84,115 @@ async fn main() {
     let get_all_users_use_case = GetAllUsersUseCase::new(user_repo.clone());
     let get_user_use_case = GetUserUseCase::new(user_repo.clone(), task_repo.clone());
     let get_free_users_use_case = GetReadyUsersUseCase::new(user_repo.clone());
-    let assign_partner_use_case = AssignPartnerUseCase::new(user_repo.clone(), task_repo.clone(), week_service.clone());
+    let assign_partner_use_case = AssignPartnerUseCase::new(uow.clone(), user_repo.clone(), task_repo.clone(), week_service.clone());
     let check_next_task_use_case = CheckNextTaskUseCase::new(task_repo.clone(), week_service.clone());
-    let create_next_task_use_case = CreateNextTaskUseCase::new(task_repo.clone(), week_service.clone());
+    let create_task_use_case = CreateTaskUseCase::new(task_repo.clone(), week_service.clone());
+    let update_task_use_case = UpdateTaskUseCase::new(task_repo.clone(), week_service.clone());
+    let delete_task_use_case = DeleteTaskUseCase::new(task_repo.clone(), week_service.clone());
+    let list_tasks_use_case = ListTasksUseCase::new(task_repo.clone(), week_service.clone());
+    let get_task_calendar_use_case = GetTaskCalendarUseCase::new(task_repo.clone(), week_service.clone());
+    let get_planned_week_use_case = GetPlannedWeekUseCase::new(task_repo.clone(), week_service.clone());
+    let add_pool_task_use_case = AddPoolTaskUseCase::new(pool_repo.clone());
+    let get_task_pool_use_case = GetTaskPoolUseCase::new(pool_repo.clone(), week_service.clone());
+    let plan_pool_task_use_case = PlanPoolTaskUseCase::new(uow.clone(), task_repo.clone(), pool_repo.clone(), week_service.clone());
+    let reset_task_pool_use_case = ResetTaskPoolUseCase::new(pool_repo.clone());
+    let add_task_variant_use_case = AddTaskVariantUseCase::new(task_repo.clone());
+    let delete_task_variant_use_case = DeleteTaskVariantUseCase::new(task_repo.clone(), week_service.clone());
     let get_active_users_use_case = GetActiveUsersUseCase::new(user_repo.clone());
-    let complete_task_use_case = CompleteTaskUseCase::new(user_repo.clone());
+    let complete_task_use_case = CompleteTaskUseCase::new(uow.clone(), user_repo.clone());
+    let auto_pair_use_case = AutoPairUseCase::new(uow.clone(), user_repo.clone(), task_repo.clone(), week_service.clone());
+    let submit_report_use_case = SubmitReportUseCase::new(uow.clone(), user_repo.clone(), report_repo.clone());
+    let get_review_queue_use_case = GetReviewQueueUseCase::new(user_repo.clone(), task_repo.clone(), report_repo.clone());
+    let approve_report_use_case = ApproveReportUseCase::new(uow.clone(), user_repo.clone(), complete_task_use_case.clone());
+    let reject_report_use_case = RejectReportUseCase::new(uow.clone(), user_repo.clone());
+    let list_admins_use_case = ListAdminsUseCase::new(auth_service.clone(), user_repo.clone());
+    let grant_admin_use_case = GrantAdminUseCase::new(auth_service.clone(), user_repo.clone());
+    let revoke_admin_use_case = RevokeAdminUseCase::new(auth_service.clone());
     
-    log::info!("Starting bot...");
+    let rollover_week_use_case = RolloverWeekUseCase::new(uow.clone(), user_repo.clone(), week_service.clone());
+    let get_pending_users_use_case = GetPendingUsersUseCase::new(user_repo.clone());
+    let get_admins_use_case = GetAdminsUseCase::new(auth_service.clone());
+    let get_task_reminders_use_case = GetTaskRemindersUseCase::new(user_repo.clone(), task_repo.clone(), week_service.clone());
     
     let bot = Bot::from_env();
+    
+    let mut rollover_job = RolloverJob::new(rollover_week_use_case, week_service.clone(), clock.clone());
+    if task_pool_rotation {
+        let rotate_task_pool_use_case = RotateTaskPoolUseCase::new(
+            plan_pool_task_use_case.clone(),
+            task_repo.clone(),
+            week_service.clone(),
+        );
+        rollover_job = rollover_job.with_task_pool_rotation(rotate_task_pool_use_case);
+    }
+    tokio::spawn(rollover_job.run());
+    tokio::spawn(NextTaskBroadcastJob::new(
+        bot.clone(),
+        get_pending_users_use_case,
+        get_admins_use_case,
+        broadcast_schedule,
+        clock.clone(),
+        broadcast_rate,
+    ).run());
+    tokio::spawn(TaskReminderJob::new(
+        bot.clone(),
+        get_task_reminders_use_case,
+        reminder_schedule,
+        clock.clone(),
+        broadcast_rate,
+    ).run());
+    
+    log::info!("Starting bot...");
+    
     let mut dispatcher = CwDispatcher::create(
         bot,
-        start_registration_use_case,
-        complete_registration_use_case,
-        accept_next_task_use_case,
-        reject_next_task_use_case,
-        get_menu_state_use_case,
-        get_current_meeting_use_case,
-        check_admin_use_case,
-        get_all_users_use_case,
-        get_user_use_case,
-        get_free_users_use_case,
-        assign_partner_use_case,
-        check_next_task_use_case,
-        create_next_task_use_case,
-        get_active_users_use_case,
-        complete_task_use_case,
+        UserUseCases {
+            start_registration: start_registration_use_case,
+            complete_registration: complete_registration_use_case,
+            check_full_name: check_full_name_use_case,
+            sync_username: sync_username_use_case,
+            accept_next_task: accept_next_task_use_case,
+            reject_next_task: reject_next_task_use_case,
+            get_menu_state: get_menu_state_use_case,
+            get_user_task: get_current_meeting_use_case,
+            submit_report: submit_report_use_case,
+        },
+        AdminUseCases {
+            check_admin: check_admin_use_case,
+            get_all_users: get_all_users_use_case,
+            get_user: get_user_use_case,
+            get_ready_users: get_free_users_use_case,
+            assign_partner: assign_partner_use_case,
+            get_active_users: get_active_users_use_case,
+            complete_task: complete_task_use_case,
+            auto_pair: auto_pair_use_case,
+        },
+        TaskPlanningUseCases {
+            check_next_task: check_next_task_use_case,
+            create_task: create_task_use_case,
+            update_task: update_task_use_case,
+            delete_task: delete_task_use_case,
+            list_tasks: list_tasks_use_case,
+            get_task_calendar: get_task_calendar_use_case,
+            get_planned_week: get_planned_week_use_case,
+            add_pool_task: add_pool_task_use_case,
+            get_task_pool: get_task_pool_use_case,
+            plan_pool_task: plan_pool_task_use_case,
+            reset_task_pool: reset_task_pool_use_case,
+            add_task_variant: add_task_variant_use_case,
+            delete_task_variant: delete_task_variant_use_case,
+        },
+        ReviewUseCases {
+            get_review_queue: get_review_queue_use_case,
+            approve_report: approve_report_use_case,
+            reject_report: reject_report_use_case,
+        },
+        AdminRightsUseCases {
+            list_admins: list_admins_use_case,
+            grant_admin: grant_admin_use_case,
+            revoke_admin: revoke_admin_use_case,
+        },
+        dialogue_storage,
     ).await;
     dispatcher.dispatch().await;
 }
@@ -1,14 +1,25 @@
 use teloxide::prelude::*;
-use teloxide::types::MaybeInaccessibleMessage;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, MaybeInaccessibleMessage};
 
-use crate::domain::use_cases::{AssignPartnerUseCase, CheckNextTaskUseCase, GetReadyUsersUseCase};
+use crate::domain::error::DomainError;
+use crate::domain::use_cases::{
+    AssignPartnerUseCase, CheckNextTaskUseCase, GetReadyUsersUseCase, GetUserTaskUseCase, GetUserUseCase,
+};
 use crate::presentation::handlers::fsm::CwDialogueState;
 use crate::presentation::handlers::texts::T;
 use crate::presentation::handlers::utils::{CwBotError, CwDialogue, CwHandlerResult};
 
-use super::build_admin_menu_users_keyboard;
+use super::{build_admin_menu_users_keyboard, notify_assigned_users, send_notify_report};
 
 
+/// Use cases shared by the handlers which finish a group assignment
+#[derive(Clone)]
+pub struct AssignGroupUseCases {
+    pub assign_partner: AssignPartnerUseCase,
+    pub get_user_task: GetUserTaskUseCase,
+    pub get_user: GetUserUseCase,
+}
+
 pub async fn handle_admin_menu_assign_partner_callback(
     bot: Bot,
     q: CallbackQuery,
@@ -77,7 +88,8 @@ pub async fn handle_admin_menu_assign_partner_2_callback(
     bot: Bot,
     q: CallbackQuery,
     dialogue: CwDialogue,
-    use_case: AssignPartnerUseCase,
+    get_free_users_use_case: GetReadyUsersUseCase,
+    use_cases: AssignGroupUseCases,
     partner_1_id: i64,
 ) -> CwHandlerResult {
     bot.answer_callback_query(&q.id).await?;
@@ -88,14 +100,172 @@ pub async fn handle_admin_menu_assign_partner_2_callback(
         .parse()
         .unwrap();
 
-    use_case.execute(partner_1_id, partner_2_id).await
+    let users = get_free_users_use_case.execute().await
         .map_err(|err| CwBotError::External(err.into()))?;
-    
+    let users = users
+        .into_iter()
+        .filter(|user| user.id != partner_1_id && user.id != partner_2_id)
+        .collect::<Vec<_>>();
+
+    if users.is_empty() {
+        return assign_group(
+            bot,
+            dialogue,
+            use_cases,
+            &[partner_1_id, partner_2_id],
+        ).await;
+    }
+
+    let mut keyboard = build_admin_menu_users_keyboard(&users);
+    keyboard.inline_keyboard.push(vec![
+        InlineKeyboardButton::callback(T.admin_assign.assign_pair_button, ASSIGN_PAIR_CALLBACK),
+    ]);
+    bot.send_message(dialogue.chat_id(), T.admin_assign.assign_third)
+        .reply_markup(keyboard)
+        .await?;
+
+    dialogue.update(CwDialogueState::AwaitingPartner3 { partner_1_id, partner_2_id }).await?;
+
+    Ok(())
+}
+
+pub async fn handle_admin_menu_assign_partner_3_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_cases: AssignGroupUseCases,
+    (partner_1_id, partner_2_id): (i64, i64),
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    delete_callback_message(&bot, &q).await?;
+
+    let partner_3_id: i64 = q.data.as_ref().unwrap()
+        .split(":").last().unwrap()
+        .parse()
+        .unwrap();
+
+    assign_group(
+        bot,
+        dialogue,
+        use_cases,
+        &[partner_1_id, partner_2_id, partner_3_id],
+    ).await
+}
+
+pub async fn handle_admin_assign_pair_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_cases: AssignGroupUseCases,
+    (partner_1_id, partner_2_id): (i64, i64),
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    delete_callback_message(&bot, &q).await?;
+
+    assign_group(
+        bot,
+        dialogue,
+        use_cases,
+        &[partner_1_id, partner_2_id],
+    ).await
+}
+
+pub async fn handle_admin_assign_variant_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_cases: AssignGroupUseCases,
+    callback: AssignVariantCallback,
+    user_ids: Vec<i64>,
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    delete_callback_message(&bot, &q).await?;
+
+    let variant = match callback {
+        AssignVariantCallback::Random => None,
+        AssignVariantCallback::Variant(number) => Some(number),
+    };
+    promote_group(
+        bot,
+        dialogue,
+        use_cases,
+        &user_ids,
+        variant,
+    ).await
+}
+
+pub const ASSIGN_PAIR_CALLBACK: &str = "admin_assign_pair";
+
+/// Lets the admin choose the task variant first if the next task has any
+async fn assign_group(
+    bot: Bot,
+    dialogue: CwDialogue,
+    use_cases: AssignGroupUseCases,
+    user_ids: &[i64],
+) -> CwHandlerResult {
+    let variants = use_cases.assign_partner.next_task_variants().await
+        .map_err(|err| CwBotError::External(err.into()))?;
+
+    if variants.is_empty() {
+        return promote_group(
+            bot,
+            dialogue,
+            use_cases,
+            user_ids,
+            None,
+        ).await;
+    }
+
+    let mut rows = vec![
+        vec![
+            InlineKeyboardButton::callback(T.admin_assign.random_variant_button, AssignVariantCallback::Random),
+            InlineKeyboardButton::callback(T.admin_assign.main_variant_button, AssignVariantCallback::Variant(0)),
+        ],
+    ];
+    rows.extend(variants.iter().map(|variant| vec![
+        InlineKeyboardButton::callback(
+            T.admin_assign.variant_button(variant),
+            AssignVariantCallback::Variant(variant.number),
+        ),
+    ]));
+    bot.send_message(dialogue.chat_id(), T.admin_assign.choose_variant)
+        .reply_markup(InlineKeyboardMarkup::new(rows))
+        .await?;
+
+    dialogue.update(CwDialogueState::AwaitingTaskVariant { user_ids: user_ids.to_vec() }).await?;
+
+    Ok(())
+}
+
+async fn promote_group(
+    bot: Bot,
+    dialogue: CwDialogue,
+    use_cases: AssignGroupUseCases,
+    user_ids: &[i64],
+    variant: Option<u32>,
+) -> CwHandlerResult {
+    let result = use_cases.assign_partner.execute(user_ids, variant).await;
     dialogue.update(CwDialogueState::Idle).await?;
 
+    match result {
+        Ok(()) => {},
+        Err(DomainError::ConcurrentModification(_)) => {
+            bot.send_message(dialogue.chat_id(), T.admin_menu.data_changed).await?;
+            return Ok(());
+        }
+        Err(DomainError::TaskVariantNotFound(_, _)) => {
+            bot.send_message(dialogue.chat_id(), T.admin_assign.variant_not_found).await?;
+            return Ok(());
+        }
+        Err(err) => return Err(CwBotError::External(err.into())),
+    }
+
     bot.send_message(dialogue.chat_id(), T.admin_assign.assign_success)
         .await?;
     
+    let failures = notify_assigned_users(&bot, user_ids, &use_cases.get_user_task, &use_cases.get_user).await;
+    send_notify_report(&bot, dialogue.chat_id(), &failures).await?;
+    
     Ok(())
 }
 
@@ -108,3 +278,33 @@ pub async fn delete_callback_message(
     }
     Ok(())
 }
+
+#[derive(Debug, Clone)]
+pub enum AssignVariantCallback {
+    Random,
+    /// `0` stands for the main task
+    Variant(u32),
+}
+
+impl Into<String> for AssignVariantCallback {
+    fn into(self) -> String {
+        match self {
+            AssignVariantCallback::Random => "admin_assign_variant:random".to_string(),
+            AssignVariantCallback::Variant(number) => format!("admin_assign_variant:{number}"),
+        }
+    }
+}
+
+impl TryFrom<String> for AssignVariantCallback {
+    type Error = ();
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.split_once(':') {
+            Some(("admin_assign_variant", "random")) => Ok(AssignVariantCallback::Random),
+            Some(("admin_assign_variant", number)) => {
+                Ok(AssignVariantCallback::Variant(number.parse().map_err(|_| ())?))
+            }
+            _ => Err(()),
+        }
+    }
+}
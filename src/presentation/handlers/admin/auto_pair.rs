@@ -0,0 +1,172 @@
+use teloxide::prelude::*;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, ParseMode};
+
+use crate::domain::error::DomainError;
+use crate::domain::use_cases::{AutoPairUseCase, GetUserTaskUseCase, GetUserUseCase, UserDto};
+use crate::presentation::handlers::admin::{delete_callback_message, notify_assigned_users, send_notify_report};
+use crate::presentation::handlers::fsm::CwDialogueState;
+use crate::presentation::handlers::texts::T;
+use crate::presentation::handlers::utils::{CwBotError, CwDialogue, CwHandlerResult};
+
+
+pub async fn handle_admin_menu_auto_pair_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: AutoPairUseCase,
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+
+    let pairing = match use_case.preview().await {
+        Ok(pairing) => pairing,
+        Err(DomainError::NoNextTask) => {
+            bot.send_message(dialogue.chat_id(), T.admin_assign.no_next_task)
+                .await?;
+            return Ok(());
+        }
+        Err(err) => return Err(CwBotError::External(err.into())),
+    };
+
+    let leftover = pairing.leftover
+        .iter()
+        .map(display_name)
+        .collect::<Vec<_>>();
+    if pairing.groups.is_empty() {
+        bot.send_message(dialogue.chat_id(), T.admin_auto_pair.not_enough_users(&leftover))
+            .parse_mode(ParseMode::Html)
+            .await?;
+        return Ok(());
+    }
+
+    let text = T.admin_auto_pair.proposal(
+        &pairing.groups
+            .iter()
+            .map(|group| group.iter().map(display_name).collect())
+            .collect::<Vec<_>>(),
+        &leftover,
+    );
+    let groups = pairing.groups
+        .iter()
+        .map(|group| group.iter().map(|user| user.id).collect())
+        .collect();
+
+    bot.send_message(dialogue.chat_id(), text)
+        .parse_mode(ParseMode::Html)
+        .reply_markup(build_auto_pair_keyboard())
+        .await?;
+    dialogue.update(CwDialogueState::AwaitingAutoPairConfirm { groups }).await?;
+
+    Ok(())
+}
+
+pub async fn handle_auto_pair_confirm_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: AutoPairUseCase,
+    get_user_task_use_case: GetUserTaskUseCase,
+    get_user_use_case: GetUserUseCase,
+    groups: Vec<Vec<i64>>,
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    delete_callback_message(&bot, &q).await?;
+
+    let result = use_case.execute(&groups).await;
+    dialogue.update(CwDialogueState::Idle).await?;
+
+    let count = match result {
+        Ok(count) => count,
+        // the proposal is outdated once users have been paired, removed or changed since
+        Err(
+            DomainError::InvalidStateChange(_)
+            | DomainError::UserNotFound(_)
+            | DomainError::ConcurrentModification(_)
+        ) => {
+            bot.send_message(dialogue.chat_id(), T.admin_auto_pair.data_changed).await?;
+            return Ok(());
+        }
+        Err(err) => return Err(CwBotError::External(err.into())),
+    };
+
+    bot.send_message(dialogue.chat_id(), T.admin_auto_pair.success(count))
+        .await?;
+
+    let user_ids = groups.concat();
+    let failures = notify_assigned_users(&bot, &user_ids, &get_user_task_use_case, &get_user_use_case).await;
+    send_notify_report(&bot, dialogue.chat_id(), &failures).await?;
+
+    Ok(())
+}
+
+pub async fn handle_auto_pair_cancel_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    delete_callback_message(&bot, &q).await?;
+
+    dialogue.update(CwDialogueState::Idle).await?;
+
+    bot.send_message(dialogue.chat_id(), T.admin_auto_pair.cancelled)
+        .await?;
+
+    Ok(())
+}
+
+fn display_name(user: &UserDto) -> String {
+    match (&user.full_name, &user.username) {
+        (Some(full_name), _) => full_name.clone(),
+        (None, Some(username)) => format!("@{}", username),
+        (None, None) => user.id.to_string(),
+    }
+}
+
+#[derive(Clone)]
+pub enum AutoPairCallback {
+    Confirm,
+    Cancel,
+}
+
+pub fn build_auto_pair_keyboard() -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new(vec![
+        vec![
+            AutoPairCallback::Confirm.into(),
+            AutoPairCallback::Cancel.into(),
+        ],
+    ])
+}
+
+impl From<AutoPairCallback> for InlineKeyboardButton {
+    fn from(callback: AutoPairCallback) -> Self {
+        match callback {
+            AutoPairCallback::Confirm => InlineKeyboardButton::callback(
+                T.admin_auto_pair.confirm_button, AutoPairCallback::Confirm,
+            ),
+            AutoPairCallback::Cancel => InlineKeyboardButton::callback(
+                T.admin_auto_pair.cancel_button, AutoPairCallback::Cancel,
+            ),
+        }
+    }
+}
+
+impl From<AutoPairCallback> for String {
+    fn from(callback: AutoPairCallback) -> Self {
+        match callback {
+            AutoPairCallback::Confirm => "admin_auto_pair_confirm".to_string(),
+            AutoPairCallback::Cancel  => "admin_auto_pair_cancel".to_string(),
+        }
+    }
+}
+
+impl TryFrom<String> for AutoPairCallback {
+    type Error = ();
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "admin_auto_pair_confirm" => Ok(AutoPairCallback::Confirm),
+            "admin_auto_pair_cancel"  => Ok(AutoPairCallback::Cancel),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use teloxide::prelude::*;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
 
-use crate::domain::use_cases::{CompleteTaskUseCase, GetActiveUsersUseCase};
+use crate::domain::error::DomainError;
+use crate::domain::use_cases::{CompleteTaskUseCase, CompletionScope, GetActiveUsersUseCase};
 use crate::presentation::handlers::admin::{build_admin_menu_users_keyboard, delete_callback_message};
 use crate::presentation::handlers::fsm::CwDialogueState;
 use crate::presentation::handlers::texts::T;
@@ -37,7 +39,6 @@ pub async fn handle_admin_menu_complete_user_callback(
     bot: Bot,
     q: CallbackQuery,
     dialogue: CwDialogue,
-    use_case: CompleteTaskUseCase,
 ) -> CwHandlerResult {
     bot.answer_callback_query(&q.id).await?;
     delete_callback_message(&bot, &q).await?;
@@ -51,14 +52,87 @@ pub async fn handle_admin_menu_complete_user_callback(
         .parse()
         .unwrap();
 
-    use_case.execute(user_id)
-        .await
-        .map_err(|err| CwBotError::External(err.into()))?;
+    bot.send_message(dialogue.chat_id(), T.admin_complete_task.choose_scope)
+        .reply_markup(build_complete_scope_keyboard(user_id))
+        .await?;
+    dialogue.update(CwDialogueState::Idle).await?;
+
+    Ok(())
+}
+
+pub async fn handle_complete_scope_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: CompleteTaskUseCase,
+    callback: CompleteCallback,
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    delete_callback_message(&bot, &q).await?;
 
-    bot.send_message(dialogue.chat_id(), T.admin_complete_task.success)
+    let (user_id, scope) = match callback {
+        CompleteCallback::Pair(user_id) => (user_id, CompletionScope::Pair),
+        CompleteCallback::Single(user_id) => (user_id, CompletionScope::Single),
+    };
+
+    let completed = match use_case.execute(user_id, scope).await {
+        Ok(completed) => completed,
+        Err(DomainError::ConcurrentModification(_)) => {
+            bot.send_message(dialogue.chat_id(), T.admin_menu.data_changed).await?;
+            return Ok(());
+        }
+        Err(err) => return Err(CwBotError::External(err.into())),
+    };
+    log::info!("task has been completed for users {:?}", completed);
+
+    bot.send_message(dialogue.chat_id(), T.admin_complete_task.success(completed.len()))
         .await?;
 
     Ok(())
 }
 
+#[derive(Debug, Clone)]
+pub enum CompleteCallback {
+    Pair(i64),
+    Single(i64),
+}
+
+impl Into<String> for CompleteCallback {
+    fn into(self) -> String {
+        match self {
+            CompleteCallback::Pair(user_id) => format!("admin_complete_pair:{user_id}"),
+            CompleteCallback::Single(user_id) => format!("admin_complete_single:{user_id}"),
+        }
+    }
+}
+
+impl TryFrom<String> for CompleteCallback {
+    type Error = ();
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let (action, user_id) = value.split_once(':').ok_or(())?;
+        let user_id: i64 = user_id.parse().map_err(|_| ())?;
+        match action {
+            "admin_complete_pair"   => Ok(CompleteCallback::Pair(user_id)),
+            "admin_complete_single" => Ok(CompleteCallback::Single(user_id)),
+            _ => Err(()),
+        }
+    }
+}
+
+fn build_complete_scope_keyboard(user_id: i64) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new(vec![
+        vec![
+            InlineKeyboardButton::callback(
+                T.admin_complete_task.pair_button, CompleteCallback::Pair(user_id),
+            ),
+        ],
+        vec![
+            InlineKeyboardButton::callback(
+                T.admin_complete_task.single_button, CompleteCallback::Single(user_id),
+            ),
+        ],
+    ])
+}
+
 
@@ -1,7 +1,7 @@
 use teloxide::prelude::*;
-use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, User};
 
-use crate::domain::use_cases::CheckAdminUseCase;
+use crate::domain::use_cases::{AdminRightsDto, CheckAdminUseCase};
 use crate::presentation::handlers::texts::T;
 use crate::presentation::handlers::utils::{CwBotError, CwHandlerResult};
 
@@ -11,39 +11,111 @@ pub async fn handle_admin_command(
     msg: Message,
     check_admin_use_case: CheckAdminUseCase,
 ) -> CwHandlerResult {
-    let is_admin = check_admin_use_case.execute(msg.chat.id.0).await
+    let rights = check_admin_use_case.execute(msg.chat.id.0).await
         .map_err(|err| CwBotError::External(err.into()))?;
 
-    if !is_admin {
+    let Some(rights) = rights else {
         log::info!("user {0} made an attempt to call /admin command", msg.chat.id.0);
         return Ok(());
-    }
+    };
+
+    send_admin_menu(&bot, msg.chat.id, &rights).await
+}
 
-    bot.send_message(msg.chat.id, T.admin_menu.text)
-        .reply_markup(build_admin_menu_keyboard())
+pub async fn send_admin_menu(bot: &Bot, chat_id: ChatId, rights: &AdminRightsDto) -> CwHandlerResult {
+    bot.send_message(chat_id, T.admin_menu.text)
+        .reply_markup(build_admin_menu_keyboard(rights))
         .await?;
     Ok(())
 }
 
+/// Lets through only the sections of the admin menu the user still has
+/// rights for, since roles may change after the menu has been sent
+pub async fn is_admin_menu_callback_allowed(
+    q: CallbackQuery,
+    callback: MenuCallback,
+    check_admin_use_case: CheckAdminUseCase,
+) -> bool {
+    has_rights(Some(&q.from), check_admin_use_case, |rights| callback.is_allowed(rights)).await
+}
+
+/// Guards the admin flows the same way, as their keyboards and dialogue
+/// states may outlive the rights of the admin who started them
+pub async fn can_manage_meetings(upd: Update, check_admin_use_case: CheckAdminUseCase) -> bool {
+    has_rights(upd.from(), check_admin_use_case, |rights| rights.manage_meetings).await
+}
+
+pub async fn can_review(upd: Update, check_admin_use_case: CheckAdminUseCase) -> bool {
+    has_rights(upd.from(), check_admin_use_case, |rights| rights.review).await
+}
+
+pub async fn can_manage_admins(upd: Update, check_admin_use_case: CheckAdminUseCase) -> bool {
+    has_rights(upd.from(), check_admin_use_case, |rights| rights.manage_admins).await
+}
+
+async fn has_rights(
+    user: Option<&User>,
+    check_admin_use_case: CheckAdminUseCase,
+    allowed: impl FnOnce(&AdminRightsDto) -> bool,
+) -> bool {
+    let Some(user) = user else {
+        return false;
+    };
+    match check_admin_use_case.execute(user.id.0 as i64).await {
+        Ok(Some(rights)) => allowed(&rights),
+        Ok(None) => false,
+        Err(err) => {
+            log::error!("failed to check admin rights of user {}: {}", user.id, err);
+            false
+        }
+    }
+}
+
 #[derive(Clone)]
 pub enum MenuCallback {
     Users,
-    CreateNextTask,
+    TaskCalendar,
+    Tasks,
+    TaskPool,
     AssignPartner,
+    AutoPair,
     Complete,
+    Review,
+    Admins,
+}
+
+impl MenuCallback {
+    pub fn is_allowed(&self, rights: &AdminRightsDto) -> bool {
+        match self {
+            MenuCallback::Review => rights.review,
+            MenuCallback::Admins => rights.manage_admins,
+            _ => rights.manage_meetings,
+        }
+    }
 }
 
-pub fn build_admin_menu_keyboard() -> InlineKeyboardMarkup {
-    InlineKeyboardMarkup::new(vec![
-        vec![
-            MenuCallback::CreateNextTask.into(),
-            MenuCallback::AssignPartner.into(),
-        ],
-        vec![
-            MenuCallback::Complete.into(),
-            MenuCallback::Users.into(),
-        ]
-    ])
+pub fn build_admin_menu_keyboard(rights: &AdminRightsDto) -> InlineKeyboardMarkup {
+    let buttons = [
+        MenuCallback::TaskCalendar,
+        MenuCallback::Tasks,
+        MenuCallback::TaskPool,
+        MenuCallback::AssignPartner,
+        MenuCallback::AutoPair,
+        MenuCallback::Complete,
+        MenuCallback::Review,
+        MenuCallback::Users,
+        MenuCallback::Admins,
+    ]
+        .into_iter()
+        .filter(|callback| callback.is_allowed(rights))
+        .map(Into::into)
+        .collect::<Vec<InlineKeyboardButton>>();
+
+    InlineKeyboardMarkup::new(
+        buttons.chunks(2)
+            .map(Vec::from)
+            .collect::<Vec<_>>()
+    )
 }
 
 impl Into<InlineKeyboardButton> for MenuCallback {
@@ -52,15 +124,30 @@ impl Into<InlineKeyboardButton> for MenuCallback {
             MenuCallback::Users => InlineKeyboardButton::callback(
                 T.admin_menu.users_button, MenuCallback::Users,
             ),
-            MenuCallback::CreateNextTask => InlineKeyboardButton::callback(
-                T.admin_menu.create_next_task_button, MenuCallback::CreateNextTask,
+            MenuCallback::TaskCalendar => InlineKeyboardButton::callback(
+                T.admin_menu.task_calendar_button, MenuCallback::TaskCalendar,
+            ),
+            MenuCallback::Tasks => InlineKeyboardButton::callback(
+                T.admin_menu.tasks_button, MenuCallback::Tasks,
+            ),
+            MenuCallback::TaskPool => InlineKeyboardButton::callback(
+                T.admin_menu.task_pool_button, MenuCallback::TaskPool,
             ),
             MenuCallback::AssignPartner => InlineKeyboardButton::callback(
                 T.admin_menu.assign_partner_button, MenuCallback::AssignPartner,
             ),
+            MenuCallback::AutoPair => InlineKeyboardButton::callback(
+                T.admin_menu.auto_pair_button, MenuCallback::AutoPair,
+            ),
             MenuCallback::Complete => InlineKeyboardButton::callback(
                 T.admin_menu.verification_button, MenuCallback::Complete,
             ),
+            MenuCallback::Review => InlineKeyboardButton::callback(
+                T.admin_menu.review_button, MenuCallback::Review,
+            ),
+            MenuCallback::Admins => InlineKeyboardButton::callback(
+                T.admin_menu.admins_button, MenuCallback::Admins,
+            ),
         }
     }
 }
@@ -69,9 +156,14 @@ impl Into<String> for MenuCallback {
     fn into(self) -> String {
         match self {
             MenuCallback::Users          => "admin_menu_users".to_string(),
-            MenuCallback::CreateNextTask => "admin_menu_create_next_task".to_string(),
+            MenuCallback::TaskCalendar   => "admin_menu_task_calendar".to_string(),
+            MenuCallback::Tasks          => "admin_menu_tasks".to_string(),
+            MenuCallback::TaskPool       => "admin_menu_task_pool".to_string(),
             MenuCallback::AssignPartner  => "admin_menu_assign_partner".to_string(),
+            MenuCallback::AutoPair       => "admin_menu_auto_pair".to_string(),
             MenuCallback::Complete       => "admin_menu_complete".to_string(),
+            MenuCallback::Review         => "admin_menu_review".to_string(),
+            MenuCallback::Admins         => "admin_menu_admins".to_string(),
         }
     }
 }
@@ -82,9 +174,14 @@ impl TryFrom<String> for MenuCallback {
     fn try_from(value: String) -> Result<Self, Self::Error> {
         match value.as_str() {
             "admin_menu_users"            => Ok(MenuCallback::Users),
-            "admin_menu_create_next_task" => Ok(MenuCallback::CreateNextTask),
+            "admin_menu_task_calendar"    => Ok(MenuCallback::TaskCalendar),
+            "admin_menu_tasks"            => Ok(MenuCallback::Tasks),
+            "admin_menu_task_pool"        => Ok(MenuCallback::TaskPool),
             "admin_menu_assign_partner"   => Ok(MenuCallback::AssignPartner),
+            "admin_menu_auto_pair"        => Ok(MenuCallback::AutoPair),
             "admin_menu_complete"         => Ok(MenuCallback::Complete),
+            "admin_menu_review"           => Ok(MenuCallback::Review),
+            "admin_menu_admins"           => Ok(MenuCallback::Admins),
             _ => Err(()),
         }
     }
@@ -2,12 +2,24 @@ mod menu;
 mod users;
 mod user;
 mod assign_partner;
-mod create_next_task;
+mod task_calendar;
+mod tasks;
+mod task_pool;
 mod complete;
+mod auto_pair;
+mod notify;
+mod review;
+mod rights;
 
 pub use menu::*;
 pub use users::*;
 pub use user::*;
 pub use assign_partner::*;
-pub use create_next_task::*;
+pub use task_calendar::*;
+pub use tasks::*;
+pub use task_pool::*;
 pub use complete::*;
+pub use auto_pair::*;
+pub use notify::*;
+pub use review::*;
+pub use rights::*;
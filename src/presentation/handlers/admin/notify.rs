@@ -0,0 +1,88 @@
+use teloxide::prelude::*;
+use teloxide::types::ParseMode;
+use teloxide::{ApiError, RequestError};
+
+use crate::domain::use_cases::{ContactDto, GetUserTaskUseCase, GetUserUseCase};
+use crate::presentation::handlers::texts::T;
+use crate::presentation::handlers::user::send_user_task;
+
+
+pub struct NotificationFailure {
+    pub user: ContactDto,
+    pub reason: String,
+}
+
+pub async fn notify_assigned_users(
+    bot: &Bot,
+    user_ids: &[i64],
+    get_user_task_use_case: &GetUserTaskUseCase,
+    get_user_use_case: &GetUserUseCase,
+) -> Vec<NotificationFailure> {
+    let mut failures = Vec::new();
+
+    for &user_id in user_ids {
+        let reason = match get_user_task_use_case.clone().execute(user_id).await {
+            Ok(user_task) => {
+                match send_user_task(bot, ChatId(user_id), &user_task, None).await {
+                    Ok(_) => continue,
+                    Err(err) => failure_reason(&err),
+                }
+            }
+            Err(err) => err.to_string(),
+        };
+
+        log::warn!("failed to notify user {} about assigned task: {}", user_id, reason);
+
+        let user = match get_user_use_case.execute(user_id).await {
+            Ok(user) => user.contact(),
+            Err(_) => ContactDto { id: user_id, username: None, full_name: None },
+        };
+        failures.push(NotificationFailure { user, reason });
+    }
+
+    failures
+}
+
+pub async fn send_notify_report(
+    bot: &Bot,
+    chat_id: ChatId,
+    failures: &[NotificationFailure],
+) -> Result<(), RequestError> {
+    if failures.is_empty() {
+        return Ok(());
+    }
+
+    let failures = failures
+        .iter()
+        .map(|failure| (failure.user.clone(), failure.reason.clone()))
+        .collect::<Vec<_>>();
+    bot.send_message(chat_id, T.admin_assign.notify_failed(&failures))
+        .parse_mode(ParseMode::Html)
+        .await?;
+    Ok(())
+}
+
+pub async fn send_broadcast_report(
+    bot: &Bot,
+    chat_id: ChatId,
+    sent: usize,
+    failures: &[NotificationFailure],
+) -> Result<(), RequestError> {
+    let failures = failures
+        .iter()
+        .map(|failure| (failure.user.clone(), failure.reason.clone()))
+        .collect::<Vec<_>>();
+    bot.send_message(chat_id, T.admin_broadcast.report(sent, &failures))
+        .parse_mode(ParseMode::Html)
+        .await?;
+    Ok(())
+}
+
+pub fn failure_reason(err: &RequestError) -> String {
+    match err {
+        RequestError::Api(ApiError::BotBlocked) => T.admin_assign.bot_blocked.to_string(),
+        RequestError::Api(ApiError::UserDeactivated) => T.admin_assign.user_deactivated.to_string(),
+        RequestError::Api(ApiError::ChatNotFound) => T.admin_assign.chat_not_found.to_string(),
+        err => err.to_string(),
+    }
+}
@@ -0,0 +1,201 @@
+use teloxide::dispatching::dialogue::GetChatId;
+use teloxide::prelude::*;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, InputFile, InputMedia, InputMediaPhoto, ParseMode};
+
+use crate::domain::error::DomainError;
+use crate::domain::use_cases::{ApproveReportUseCase, GetReviewQueueUseCase, RejectReportUseCase, ReportDto};
+use crate::presentation::handlers::fsm::CwDialogueState;
+use crate::presentation::handlers::texts::T;
+use crate::presentation::handlers::utils::{CwBotError, CwDialogue, CwHandlerResult};
+
+
+const MEDIA_GROUP_LIMIT: usize = 10;
+
+pub async fn handle_admin_menu_review_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: GetReviewQueueUseCase,
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+
+    let reports = use_case.execute().await
+        .map_err(|err| CwBotError::External(err.into()))?;
+
+    if reports.is_empty() {
+        bot.send_message(dialogue.chat_id(), T.admin_review.no_reports)
+            .await?;
+        return Ok(());
+    }
+
+    for report in &reports {
+        send_report(&bot, dialogue.chat_id(), report).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn handle_review_approve_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: ApproveReportUseCase,
+    (user_id, year, week): (i64, i32, u32),
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    remove_review_keyboard(&bot, &q).await?;
+
+    let completed = match use_case.execute(user_id, (year, week)).await {
+        Ok(completed) => completed,
+        Err(DomainError::InvalidStateChange(_)) => {
+            bot.send_message(dialogue.chat_id(), T.admin_review.already_reviewed).await?;
+            return Ok(());
+        }
+        Err(DomainError::ConcurrentModification(_)) => {
+            bot.send_message(dialogue.chat_id(), T.admin_menu.data_changed).await?;
+            return Ok(());
+        }
+        Err(err) => return Err(CwBotError::External(err.into())),
+    };
+    log::info!("report of user {} for week {}-{} has been approved", user_id, year, week);
+
+    for completed_id in completed {
+        if let Err(err) = bot.send_message(ChatId(completed_id), T.admin_review.approved_notification).await {
+            log::warn!("failed to notify user {} about approved report: {}", completed_id, err);
+        }
+    }
+
+    bot.send_message(dialogue.chat_id(), T.admin_review.approved).await?;
+    Ok(())
+}
+
+pub async fn handle_review_reject_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    (user_id, year, week): (i64, i32, u32),
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    remove_review_keyboard(&bot, &q).await?;
+
+    bot.send_message(dialogue.chat_id(), T.admin_review.enter_reason).await?;
+    dialogue.update(CwDialogueState::AwaitingReportRejectReason { user_id, week_id: (year, week) }).await?;
+
+    Ok(())
+}
+
+pub async fn receive_report_reject_reason(
+    bot: Bot,
+    msg: Message,
+    dialogue: CwDialogue,
+    use_case: RejectReportUseCase,
+    (user_id, week_id): (i64, (i32, u32)),
+) -> CwHandlerResult {
+    let reason = match msg.text() {
+        Some(text) => text.trim().to_string(),
+        None => return Ok(()),
+    };
+
+    dialogue.update(CwDialogueState::Idle).await?;
+
+    match use_case.execute(user_id, week_id).await {
+        Ok(()) => {},
+        Err(DomainError::InvalidStateChange(_)) => {
+            bot.send_message(msg.chat.id, T.admin_review.already_reviewed).await?;
+            return Ok(());
+        }
+        Err(DomainError::ConcurrentModification(_)) => {
+            bot.send_message(msg.chat.id, T.admin_menu.data_changed).await?;
+            return Ok(());
+        }
+        Err(err) => return Err(CwBotError::External(err.into())),
+    }
+    log::info!("report of user {} has been rejected: {}", user_id, reason);
+
+    if let Err(err) = bot.send_message(ChatId(user_id), T.admin_review.rejected_notification(&reason))
+        .parse_mode(ParseMode::Html)
+        .await
+    {
+        log::warn!("failed to notify user {} about rejected report: {}", user_id, err);
+    }
+
+    bot.send_message(msg.chat.id, T.admin_review.rejected).await?;
+    Ok(())
+}
+
+async fn send_report(bot: &Bot, chat_id: ChatId, report: &ReportDto) -> CwHandlerResult {
+    if let [file_id] = report.photo_file_ids.as_slice() {
+        bot.send_photo(chat_id, InputFile::file_id(file_id)).await?;
+    } else {
+        for chunk in report.photo_file_ids.chunks(MEDIA_GROUP_LIMIT) {
+            let media = chunk
+                .iter()
+                .map(|file_id| InputMedia::Photo(InputMediaPhoto::new(InputFile::file_id(file_id))));
+            bot.send_media_group(chat_id, media).await?;
+        }
+    }
+
+    let text = T.admin_review.report(
+        &report.user,
+        &report.partners,
+        &report.title,
+        report.caption.as_deref(),
+    );
+    bot.send_message(chat_id, text)
+        .parse_mode(ParseMode::Html)
+        .reply_markup(build_review_keyboard(report.user.id, report.week_id))
+        .await?;
+    Ok(())
+}
+
+async fn remove_review_keyboard(bot: &Bot, q: &CallbackQuery) -> CwHandlerResult {
+    if let Some(message) = q.regular_message() {
+        bot.edit_message_reply_markup(q.chat_id().unwrap(), message.id).await?;
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub enum ReviewCallback {
+    Approve(i64, i32, u32),
+    Reject(i64, i32, u32),
+}
+
+impl Into<String> for ReviewCallback {
+    fn into(self) -> String {
+        match self {
+            ReviewCallback::Approve(user_id, year, week) => format!("admin_review_approve:{user_id}:{year}:{week}"),
+            ReviewCallback::Reject(user_id, year, week) => format!("admin_review_reject:{user_id}:{year}:{week}"),
+        }
+    }
+}
+
+impl TryFrom<String> for ReviewCallback {
+    type Error = ();
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let (action, args) = value.split_once(':').ok_or(())?;
+        let mut args = args.splitn(3, ':');
+        let user_id: i64 = args.next().ok_or(())?.parse().map_err(|_| ())?;
+        let year: i32 = args.next().ok_or(())?.parse().map_err(|_| ())?;
+        let week: u32 = args.next().ok_or(())?.parse().map_err(|_| ())?;
+        match action {
+            "admin_review_approve" => Ok(ReviewCallback::Approve(user_id, year, week)),
+            "admin_review_reject"  => Ok(ReviewCallback::Reject(user_id, year, week)),
+            _ => Err(()),
+        }
+    }
+}
+
+fn build_review_keyboard(user_id: i64, (year, week): (i32, u32)) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new(vec![
+        vec![
+            InlineKeyboardButton::callback(
+                T.admin_review.approve_button, ReviewCallback::Approve(user_id, year, week),
+            ),
+            InlineKeyboardButton::callback(
+                T.admin_review.reject_button, ReviewCallback::Reject(user_id, year, week),
+            ),
+        ],
+    ])
+}
@@ -0,0 +1,198 @@
+use teloxide::prelude::*;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, ParseMode};
+
+use crate::domain::error::DomainError;
+use crate::domain::use_cases::{AdminDto, GetAllUsersUseCase, GrantAdminUseCase, ListAdminsUseCase, RevokeAdminUseCase};
+use crate::presentation::handlers::admin::{build_admin_menu_users_keyboard, delete_callback_message};
+use crate::presentation::handlers::fsm::CwDialogueState;
+use crate::presentation::handlers::texts::T;
+use crate::presentation::handlers::utils::{CwBotError, CwDialogue, CwHandlerResult};
+
+
+pub async fn handle_admin_menu_admins_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: ListAdminsUseCase,
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+
+    let admins = use_case.execute().await
+        .map_err(|err| CwBotError::External(err.into()))?;
+
+    bot.send_message(dialogue.chat_id(), T.admin_rights.list(&admins))
+        .parse_mode(ParseMode::Html)
+        .reply_markup(build_admins_keyboard(&admins))
+        .await?;
+
+    Ok(())
+}
+
+pub async fn handle_admin_rights_grant_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: GetAllUsersUseCase,
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+
+    let users = use_case.execute().await
+        .map_err(|err| CwBotError::External(err.into()))?;
+
+    if !users.iter().any(|user| user.full_name.is_some()) {
+        bot.send_message(dialogue.chat_id(), T.admin_rights.no_users)
+            .await?;
+        return Ok(());
+    }
+
+    bot.send_message(dialogue.chat_id(), T.admin_rights.choose_user)
+        .reply_markup(build_admin_menu_users_keyboard(&users))
+        .await?;
+    dialogue.update(CwDialogueState::AwaitingUserForGrant).await?;
+
+    Ok(())
+}
+
+pub async fn handle_admin_rights_user_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    delete_callback_message(&bot, &q).await?;
+
+    let user_id: i64 = q.data
+        .as_ref()
+        .unwrap()
+        .split(":")
+        .last()
+        .unwrap()
+        .parse()
+        .unwrap();
+
+    bot.send_message(dialogue.chat_id(), T.admin_rights.choose_role)
+        .parse_mode(ParseMode::Html)
+        .reply_markup(build_role_keyboard(user_id))
+        .await?;
+    dialogue.update(CwDialogueState::Idle).await?;
+
+    Ok(())
+}
+
+pub async fn handle_admin_rights_role_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: GrantAdminUseCase,
+    (user_id, role): (i64, String),
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    delete_callback_message(&bot, &q).await?;
+
+    let text = match use_case.execute(q.from.id.0 as i64, user_id, &role).await {
+        Ok(()) => {
+            log::info!("user {} has granted role {} to user {}", q.from.id, role, user_id);
+            T.admin_rights.granted
+        }
+        Err(DomainError::PermissionDenied(_)) => T.admin_rights.permission_denied,
+        Err(DomainError::UserIsNotRegistered(_)) => T.admin_rights.not_registered,
+        Err(DomainError::InvalidStateChange(_)) => T.admin_rights.owner_unchangeable,
+        Err(err) => return Err(CwBotError::External(err.into())),
+    };
+    bot.send_message(dialogue.chat_id(), text).await?;
+
+    Ok(())
+}
+
+pub async fn handle_admin_rights_revoke_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: RevokeAdminUseCase,
+    user_id: i64,
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    delete_callback_message(&bot, &q).await?;
+
+    let text = match use_case.execute(q.from.id.0 as i64, user_id).await {
+        Ok(()) => {
+            log::info!("user {} has revoked admin rights of user {}", q.from.id, user_id);
+            T.admin_rights.revoked
+        }
+        Err(DomainError::PermissionDenied(_)) => T.admin_rights.permission_denied,
+        Err(DomainError::AdminNotFound(_)) => T.admin_rights.already_revoked,
+        Err(DomainError::InvalidStateChange(_)) => T.admin_rights.owner_unchangeable,
+        Err(err) => return Err(CwBotError::External(err.into())),
+    };
+    bot.send_message(dialogue.chat_id(), text).await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub enum AdminRightsCallback {
+    Grant,
+    Role(i64, String),
+    Revoke(i64),
+}
+
+impl Into<String> for AdminRightsCallback {
+    fn into(self) -> String {
+        match self {
+            AdminRightsCallback::Grant => "admin_rights_grant".to_string(),
+            AdminRightsCallback::Role(user_id, role) => format!("admin_rights_role:{user_id}:{role}"),
+            AdminRightsCallback::Revoke(user_id) => format!("admin_rights_revoke:{user_id}"),
+        }
+    }
+}
+
+impl TryFrom<String> for AdminRightsCallback {
+    type Error = ();
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value == "admin_rights_grant" {
+            return Ok(AdminRightsCallback::Grant);
+        }
+
+        let (action, args) = value.split_once(':').ok_or(())?;
+        match action {
+            "admin_rights_role" => {
+                let (user_id, role) = args.split_once(':').ok_or(())?;
+                Ok(AdminRightsCallback::Role(user_id.parse().map_err(|_| ())?, role.to_string()))
+            }
+            "admin_rights_revoke" => Ok(AdminRightsCallback::Revoke(args.parse().map_err(|_| ())?)),
+            _ => Err(()),
+        }
+    }
+}
+
+fn build_admins_keyboard(admins: &[AdminDto]) -> InlineKeyboardMarkup {
+    let mut rows = admins
+        .iter()
+        .filter(|admin| admin.revocable)
+        .map(|admin| vec![
+            InlineKeyboardButton::callback(
+                T.admin_rights.revoke_button(admin), AdminRightsCallback::Revoke(admin.contact.id),
+            ),
+        ])
+        .collect::<Vec<_>>();
+
+    rows.push(vec![
+        InlineKeyboardButton::callback(T.admin_rights.grant_button, AdminRightsCallback::Grant),
+    ]);
+
+    InlineKeyboardMarkup::new(rows)
+}
+
+fn build_role_keyboard(user_id: i64) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new(vec![
+        vec![
+            InlineKeyboardButton::callback(
+                T.admin_rights.admin_role_button, AdminRightsCallback::Role(user_id, "admin".to_string()),
+            ),
+            InlineKeyboardButton::callback(
+                T.admin_rights.reviewer_role_button, AdminRightsCallback::Role(user_id, "reviewer".to_string()),
+            ),
+        ],
+    ])
+}
@@ -0,0 +1,422 @@
+use teloxide::prelude::*;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, ParseMode};
+
+use crate::domain::error::DomainError;
+use crate::domain::use_cases::{
+    AddTaskVariantUseCase, CreateTaskUseCase, DeleteTaskUseCase, DeleteTaskVariantUseCase, GetPlannedWeekUseCase,
+    GetTaskCalendarUseCase, PlannedWeekDto, TaskMediaDto, UpdateTaskUseCase,
+};
+use crate::presentation::handlers::admin::{delete_callback_message, TaskPoolCallback};
+use crate::presentation::handlers::fsm::CwDialogueState;
+use crate::presentation::handlers::utils::{CwBotError, CwDialogue, CwHandlerResult};
+use crate::presentation::handlers::texts::T;
+
+
+const CALENDAR_PAGE_SIZE: usize = 8;
+
+pub async fn handle_admin_menu_task_calendar_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: GetTaskCalendarUseCase,
+) -> CwHandlerResult {
+    bot.answer_callback_query(q.id).await?;
+
+    let weeks = use_case.execute(0, CALENDAR_PAGE_SIZE).await
+        .map_err(|err| CwBotError::External(err.into()))?;
+
+    bot.send_message(dialogue.chat_id(), T.admin_task_calendar.text)
+        .reply_markup(build_calendar_keyboard(&weeks, 0))
+        .await?;
+    Ok(())
+}
+
+pub async fn handle_task_calendar_page_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: GetTaskCalendarUseCase,
+    offset: usize,
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+
+    let weeks = use_case.execute(offset, CALENDAR_PAGE_SIZE).await
+        .map_err(|err| CwBotError::External(err.into()))?;
+
+    if let Some(message) = q.regular_message() {
+        bot.edit_message_reply_markup(dialogue.chat_id(), message.id)
+            .reply_markup(build_calendar_keyboard(&weeks, offset))
+            .await?;
+    }
+    Ok(())
+}
+
+pub async fn handle_task_calendar_week_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: GetPlannedWeekUseCase,
+    week_id: (i32, u32),
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+
+    let week = use_case.execute(week_id).await
+        .map_err(|err| CwBotError::External(err.into()))?;
+
+    if week.task.is_some() {
+        bot.send_message(dialogue.chat_id(), T.admin_task_calendar.planned_task(&week))
+            .parse_mode(ParseMode::Html)
+            .reply_markup(build_planned_task_keyboard(&week))
+            .await?;
+        return Ok(());
+    }
+
+    bot.send_message(dialogue.chat_id(), T.admin_task_calendar.empty_week(&week.starts_on))
+        .reply_markup(build_empty_week_keyboard(week_id))
+        .await?;
+    Ok(())
+}
+
+pub async fn handle_task_calendar_create_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: GetPlannedWeekUseCase,
+    week_id: (i32, u32),
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    delete_callback_message(&bot, &q).await?;
+
+    let week = use_case.execute(week_id).await
+        .map_err(|err| CwBotError::External(err.into()))?;
+
+    bot.send_message(dialogue.chat_id(), T.admin_task_calendar.enter_title(&week.starts_on)).await?;
+    dialogue.update(CwDialogueState::AwaitingTaskTitle { week_id, edit: false }).await?;
+    Ok(())
+}
+
+pub async fn handle_task_calendar_edit_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: GetPlannedWeekUseCase,
+    week_id: (i32, u32),
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    delete_callback_message(&bot, &q).await?;
+
+    let week = use_case.execute(week_id).await
+        .map_err(|err| CwBotError::External(err.into()))?;
+
+    bot.send_message(dialogue.chat_id(), T.admin_task_calendar.enter_title(&week.starts_on)).await?;
+    dialogue.update(CwDialogueState::AwaitingTaskTitle { week_id, edit: true }).await?;
+    Ok(())
+}
+
+pub async fn handle_task_calendar_delete_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: DeleteTaskUseCase,
+    week_id: (i32, u32),
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    delete_callback_message(&bot, &q).await?;
+
+    let text = match use_case.execute(week_id).await {
+        Ok(()) => {
+            log::info!("task for week {:?} has been deleted", week_id);
+            T.admin_task_calendar.deleted
+        }
+        Err(DomainError::TaskInUse(_)) => T.admin_task_calendar.in_use,
+        Err(DomainError::WeekAlreadyStarted(_)) => T.admin_task_calendar.week_started,
+        Err(DomainError::TaskNotFound(_)) => T.admin_task_calendar.not_found,
+        Err(err) => return Err(CwBotError::External(err.into())),
+    };
+    bot.send_message(dialogue.chat_id(), text).await?;
+    Ok(())
+}
+
+pub async fn handle_task_calendar_add_variant_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    week_id: (i32, u32),
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    delete_callback_message(&bot, &q).await?;
+
+    bot.send_message(dialogue.chat_id(), T.admin_task_calendar.enter_variant_title).await?;
+    dialogue.update(CwDialogueState::AwaitingVariantTitle { week_id }).await?;
+    Ok(())
+}
+
+pub async fn handle_task_calendar_delete_variant_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: DeleteTaskVariantUseCase,
+    (year, week, number): (i32, u32, u32),
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    delete_callback_message(&bot, &q).await?;
+
+    let text = match use_case.execute((year, week), number).await {
+        Ok(()) => {
+            log::info!("variant {} of task for week {}-{} has been deleted", number, year, week);
+            T.admin_task_calendar.variant_deleted
+        }
+        Err(DomainError::TaskInUse(_)) => T.admin_task_calendar.variant_in_use,
+        Err(DomainError::WeekAlreadyStarted(_)) => T.admin_task_calendar.week_started,
+        Err(DomainError::TaskVariantNotFound(_, _)) => T.admin_task_calendar.variant_not_found,
+        Err(err) => return Err(CwBotError::External(err.into())),
+    };
+    bot.send_message(dialogue.chat_id(), text).await?;
+    Ok(())
+}
+
+pub async fn receive_task_title(
+    bot: Bot,
+    msg: Message,
+    dialogue: CwDialogue,
+    (week_id, edit): ((i32, u32), bool),
+) -> CwHandlerResult {
+    let Some(title) = msg.text() else {
+        bot.send_message(dialogue.chat_id(), T.admin_create_task.text_expected).await?;
+        return Ok(());
+    };
+    bot.send_message(dialogue.chat_id(), T.admin_create_task.enter_description).await?;
+    dialogue.update(CwDialogueState::AwaitingTaskDescription { week_id, edit, title: title.to_string() }).await?;
+    Ok(())
+}
+
+pub async fn receive_task_description(
+    bot: Bot,
+    msg: Message,
+    dialogue: CwDialogue,
+    (week_id, edit, title): ((i32, u32), bool, String),
+) -> CwHandlerResult {
+    let Some(description) = msg.text() else {
+        bot.send_message(dialogue.chat_id(), T.admin_create_task.text_expected).await?;
+        return Ok(());
+    };
+    bot.send_message(dialogue.chat_id(), T.admin_create_task.enter_media).await?;
+    dialogue.update(CwDialogueState::AwaitingTaskMedia {
+        week_id,
+        edit,
+        title,
+        description: description.to_string(),
+    }).await?;
+    Ok(())
+}
+
+/// Accepts a photo, a document or a location, `-` leaves the task without media
+pub async fn receive_task_media(
+    bot: Bot,
+    msg: Message,
+    dialogue: CwDialogue,
+    create_task_use_case: CreateTaskUseCase,
+    update_task_use_case: UpdateTaskUseCase,
+    (week_id, edit, title, description): ((i32, u32), bool, String, String),
+) -> CwHandlerResult {
+    let media = if let Some(photo) = msg.photo().and_then(|sizes| sizes.last()) {
+        Some(TaskMediaDto::Photo(photo.file.id.to_string()))
+    } else if let Some(document) = msg.document() {
+        Some(TaskMediaDto::Document(document.file.id.to_string()))
+    } else if let Some(location) = msg.location() {
+        Some(TaskMediaDto::Location { latitude: location.latitude, longitude: location.longitude })
+    } else if msg.text().map(str::trim) == Some("-") {
+        None
+    } else {
+        bot.send_message(dialogue.chat_id(), T.admin_create_task.unsupported_media).await?;
+        return Ok(());
+    };
+
+    let result = if edit {
+        update_task_use_case.execute(week_id, &title, &description, media).await
+    } else {
+        create_task_use_case.execute(week_id, &title, &description, media).await
+    };
+    dialogue.update(CwDialogueState::Idle).await?;
+
+    let text = match result {
+        Ok(()) if edit => T.admin_create_task.updated,
+        Ok(()) => T.admin_create_task.success,
+        Err(DomainError::TaskAlreadyExists(_)) => T.admin_create_task.already_exists,
+        Err(DomainError::TaskInUse(_)) => T.admin_task_calendar.edit_in_use,
+        Err(DomainError::WeekAlreadyStarted(_)) => T.admin_task_calendar.week_started,
+        Err(DomainError::TaskNotFound(_)) => T.admin_task_calendar.not_found,
+        Err(err) => return Err(CwBotError::External(err.into())),
+    };
+    bot.send_message(dialogue.chat_id(), text).await?;
+    Ok(())
+}
+
+pub async fn receive_variant_title(
+    bot: Bot,
+    msg: Message,
+    dialogue: CwDialogue,
+    week_id: (i32, u32),
+) -> CwHandlerResult {
+    let Some(title) = msg.text() else {
+        bot.send_message(dialogue.chat_id(), T.admin_create_task.text_expected).await?;
+        return Ok(());
+    };
+    bot.send_message(dialogue.chat_id(), T.admin_create_task.enter_description).await?;
+    dialogue.update(CwDialogueState::AwaitingVariantDescription { week_id, title: title.to_string() }).await?;
+    Ok(())
+}
+
+pub async fn receive_variant_description(
+    bot: Bot,
+    msg: Message,
+    dialogue: CwDialogue,
+    use_case: AddTaskVariantUseCase,
+    (week_id, title): ((i32, u32), String),
+) -> CwHandlerResult {
+    let Some(description) = msg.text() else {
+        bot.send_message(dialogue.chat_id(), T.admin_create_task.text_expected).await?;
+        return Ok(());
+    };
+    let result = use_case.execute(week_id, &title, description).await;
+    if let Err(DomainError::InvalidValue(_)) = result {
+        // The title has been checked only now, so the whole variant is entered again
+        bot.send_message(dialogue.chat_id(), T.admin_task_calendar.invalid_variant).await?;
+        dialogue.update(CwDialogueState::AwaitingVariantTitle { week_id }).await?;
+        return Ok(());
+    }
+    dialogue.update(CwDialogueState::Idle).await?;
+
+    let text = match result {
+        Ok(number) => {
+            log::info!("variant {} of task for week {:?} has been added", number, week_id);
+            T.admin_task_calendar.variant_added
+        }
+        Err(DomainError::TaskNotFound(_)) => T.admin_task_calendar.not_found,
+        Err(err) => return Err(CwBotError::External(err.into())),
+    };
+    bot.send_message(dialogue.chat_id(), text).await?;
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub enum TaskCalendarCallback {
+    Page(usize),
+    Week(i32, u32),
+    Create(i32, u32),
+    Edit(i32, u32),
+    Delete(i32, u32),
+    AddVariant(i32, u32),
+    DeleteVariant(i32, u32, u32),
+}
+
+impl Into<String> for TaskCalendarCallback {
+    fn into(self) -> String {
+        match self {
+            TaskCalendarCallback::Page(offset) => format!("admin_calendar_page:{offset}"),
+            TaskCalendarCallback::Week(year, week) => format!("admin_calendar_week:{year}:{week}"),
+            TaskCalendarCallback::Create(year, week) => format!("admin_calendar_create:{year}:{week}"),
+            TaskCalendarCallback::Edit(year, week) => format!("admin_calendar_edit:{year}:{week}"),
+            TaskCalendarCallback::Delete(year, week) => format!("admin_calendar_delete:{year}:{week}"),
+            TaskCalendarCallback::AddVariant(year, week) => format!("admin_calendar_add_variant:{year}:{week}"),
+            TaskCalendarCallback::DeleteVariant(year, week, number) => {
+                format!("admin_calendar_delete_variant:{year}:{week}:{number}")
+            }
+        }
+    }
+}
+
+impl TryFrom<String> for TaskCalendarCallback {
+    type Error = ();
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let (action, args) = value.split_once(':').ok_or(())?;
+        if action == "admin_calendar_page" {
+            return Ok(TaskCalendarCallback::Page(args.parse().map_err(|_| ())?));
+        }
+
+        let mut args = args.splitn(3, ':');
+        let year: i32 = args.next().ok_or(())?.parse().map_err(|_| ())?;
+        let week: u32 = args.next().ok_or(())?.parse().map_err(|_| ())?;
+        match (action, args.next()) {
+            ("admin_calendar_week", None)        => Ok(TaskCalendarCallback::Week(year, week)),
+            ("admin_calendar_create", None)      => Ok(TaskCalendarCallback::Create(year, week)),
+            ("admin_calendar_edit", None)        => Ok(TaskCalendarCallback::Edit(year, week)),
+            ("admin_calendar_delete", None)      => Ok(TaskCalendarCallback::Delete(year, week)),
+            ("admin_calendar_add_variant", None) => Ok(TaskCalendarCallback::AddVariant(year, week)),
+            ("admin_calendar_delete_variant", Some(number)) => {
+                Ok(TaskCalendarCallback::DeleteVariant(year, week, number.parse().map_err(|_| ())?))
+            }
+            _ => Err(()),
+        }
+    }
+}
+
+fn build_calendar_keyboard(weeks: &[PlannedWeekDto], offset: usize) -> InlineKeyboardMarkup {
+    let buttons = weeks
+        .iter()
+        .map(|week| InlineKeyboardButton::callback(
+            T.admin_task_calendar.week_button(week),
+            TaskCalendarCallback::Week(week.id.0, week.id.1),
+        ))
+        .collect::<Vec<_>>();
+
+    let mut rows = buttons.chunks(2)
+        .map(Vec::from)
+        .collect::<Vec<_>>();
+
+    let mut navigation = Vec::new();
+    if offset > 0 {
+        navigation.push(InlineKeyboardButton::callback(
+            T.admin_task_calendar.prev_button,
+            TaskCalendarCallback::Page(offset.saturating_sub(CALENDAR_PAGE_SIZE)),
+        ));
+    }
+    navigation.push(InlineKeyboardButton::callback(
+        T.admin_task_calendar.next_button,
+        TaskCalendarCallback::Page(offset + CALENDAR_PAGE_SIZE),
+    ));
+    rows.push(navigation);
+
+    InlineKeyboardMarkup::new(rows)
+}
+
+fn build_empty_week_keyboard(week_id: (i32, u32)) -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new(vec![
+        vec![
+            InlineKeyboardButton::callback(
+                T.admin_task_calendar.write_button, TaskCalendarCallback::Create(week_id.0, week_id.1),
+            ),
+            InlineKeyboardButton::callback(
+                T.admin_task_calendar.from_pool_button, TaskPoolCallback::Plan(week_id.0, week_id.1),
+            ),
+        ],
+    ])
+}
+
+fn build_planned_task_keyboard(week: &PlannedWeekDto) -> InlineKeyboardMarkup {
+    let (year, week_number) = week.id;
+    let mut rows = vec![
+        vec![
+            InlineKeyboardButton::callback(
+                T.admin_task_calendar.edit_button, TaskCalendarCallback::Edit(year, week_number),
+            ),
+            InlineKeyboardButton::callback(
+                T.admin_task_calendar.delete_button, TaskCalendarCallback::Delete(year, week_number),
+            ),
+        ],
+        vec![
+            InlineKeyboardButton::callback(
+                T.admin_task_calendar.add_variant_button, TaskCalendarCallback::AddVariant(year, week_number),
+            ),
+        ],
+    ];
+    let variants = week.task.iter().flat_map(|task| &task.variants);
+    rows.extend(variants.map(|variant| vec![
+        InlineKeyboardButton::callback(
+            T.admin_task_calendar.delete_variant_button(variant.number),
+            TaskCalendarCallback::DeleteVariant(year, week_number, variant.number),
+        ),
+    ]));
+    InlineKeyboardMarkup::new(rows)
+}
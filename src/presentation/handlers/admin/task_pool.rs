@@ -0,0 +1,227 @@
+use teloxide::prelude::*;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, ParseMode};
+
+use crate::domain::error::DomainError;
+use crate::domain::use_cases::{AddPoolTaskUseCase, GetTaskPoolUseCase, PlanPoolTaskUseCase, ResetTaskPoolUseCase};
+use crate::presentation::handlers::admin::delete_callback_message;
+use crate::presentation::handlers::fsm::CwDialogueState;
+use crate::presentation::handlers::texts::T;
+use crate::presentation::handlers::utils::{CwBotError, CwDialogue, CwHandlerResult};
+
+
+pub async fn handle_admin_menu_task_pool_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: GetTaskPoolUseCase,
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+
+    let pool = use_case.execute().await
+        .map_err(|err| CwBotError::External(err.into()))?;
+
+    bot.send_message(dialogue.chat_id(), T.admin_task_pool.summary(&pool))
+        .parse_mode(ParseMode::Html)
+        .reply_markup(InlineKeyboardMarkup::new(vec![
+            vec![
+                InlineKeyboardButton::callback(T.admin_task_pool.add_button, TaskPoolCallback::Add),
+                InlineKeyboardButton::callback(T.admin_task_pool.reset_button, TaskPoolCallback::ResetSeason),
+            ],
+        ]))
+        .await?;
+    Ok(())
+}
+
+pub async fn handle_task_pool_add_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+
+    bot.send_message(dialogue.chat_id(), T.admin_task_pool.enter_title).await?;
+    dialogue.update(CwDialogueState::AwaitingPoolTaskTitle).await?;
+    Ok(())
+}
+
+pub async fn handle_task_pool_reset_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: ResetTaskPoolUseCase,
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    delete_callback_message(&bot, &q).await?;
+
+    let reset = use_case.execute().await
+        .map_err(|err| CwBotError::External(err.into()))?;
+    log::info!("task pool season has been reset, {} tasks are available again", reset);
+
+    bot.send_message(dialogue.chat_id(), T.admin_task_pool.season_reset(reset)).await?;
+    Ok(())
+}
+
+/// Offers to pick a task among all the unused ones or only the ones with a tag
+pub async fn handle_task_pool_plan_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: GetTaskPoolUseCase,
+    (year, week): (i32, u32),
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    delete_callback_message(&bot, &q).await?;
+
+    let pool = use_case.execute().await
+        .map_err(|err| CwBotError::External(err.into()))?;
+
+    if pool.tasks.iter().all(|task| task.used_on.is_some()) {
+        bot.send_message(dialogue.chat_id(), T.admin_task_pool.exhausted).await?;
+        return Ok(());
+    }
+
+    let mut rows = vec![
+        vec![InlineKeyboardButton::callback(
+            T.admin_task_pool.any_tag_button,
+            TaskPoolCallback::Pick(year, week, String::new()),
+        )],
+    ];
+    let tag_buttons = pool.tags
+        .iter()
+        .map(|tag| InlineKeyboardButton::callback(
+            T.admin_task_pool.tag_button(tag),
+            TaskPoolCallback::Pick(year, week, tag.clone()),
+        ))
+        .collect::<Vec<_>>();
+    rows.extend(tag_buttons.chunks(2).map(Vec::from));
+
+    bot.send_message(dialogue.chat_id(), T.admin_task_pool.choose_tag)
+        .reply_markup(InlineKeyboardMarkup::new(rows))
+        .await?;
+    Ok(())
+}
+
+pub async fn handle_task_pool_pick_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: PlanPoolTaskUseCase,
+    (year, week, tag): (i32, u32, String),
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+    delete_callback_message(&bot, &q).await?;
+
+    let tag = Some(tag.as_str()).filter(|tag| !tag.is_empty());
+    let text = match use_case.execute((year, week), tag).await {
+        Ok(task) => {
+            log::info!("pool task \"{}\" has been planned for week {}-{}", task.title, year, week);
+            T.admin_task_pool.planned(&task)
+        }
+        Err(DomainError::TaskPoolExhausted) => T.admin_task_pool.exhausted.to_string(),
+        Err(DomainError::TaskAlreadyExists(_)) => T.admin_create_task.already_exists.to_string(),
+        Err(DomainError::WeekAlreadyStarted(_)) => T.admin_task_calendar.week_started.to_string(),
+        Err(err) => return Err(CwBotError::External(err.into())),
+    };
+    bot.send_message(dialogue.chat_id(), text)
+        .parse_mode(ParseMode::Html)
+        .await?;
+    Ok(())
+}
+
+pub async fn receive_pool_task_title(
+    bot: Bot,
+    msg: Message,
+    dialogue: CwDialogue,
+) -> CwHandlerResult {
+    let Some(title) = msg.text() else {
+        bot.send_message(dialogue.chat_id(), T.admin_create_task.text_expected).await?;
+        return Ok(());
+    };
+    bot.send_message(dialogue.chat_id(), T.admin_create_task.enter_description).await?;
+    dialogue.update(CwDialogueState::AwaitingPoolTaskDescription { title: title.to_string() }).await?;
+    Ok(())
+}
+
+pub async fn receive_pool_task_description(
+    bot: Bot,
+    msg: Message,
+    dialogue: CwDialogue,
+    title: String,
+) -> CwHandlerResult {
+    let Some(description) = msg.text() else {
+        bot.send_message(dialogue.chat_id(), T.admin_create_task.text_expected).await?;
+        return Ok(());
+    };
+    bot.send_message(dialogue.chat_id(), T.admin_task_pool.enter_tags).await?;
+    dialogue.update(CwDialogueState::AwaitingPoolTaskTags { title, description: description.to_string() }).await?;
+    Ok(())
+}
+
+pub async fn receive_pool_task_tags(
+    bot: Bot,
+    msg: Message,
+    dialogue: CwDialogue,
+    use_case: AddPoolTaskUseCase,
+    (title, description): (String, String),
+) -> CwHandlerResult {
+    let Some(tags) = msg.text() else {
+        bot.send_message(dialogue.chat_id(), T.admin_create_task.text_expected).await?;
+        return Ok(());
+    };
+    match use_case.execute(&title, &description, tags).await {
+        Ok(()) => {}
+        // The title has been checked only now, so the whole task is entered again
+        Err(DomainError::InvalidValue(_)) => {
+            bot.send_message(dialogue.chat_id(), T.admin_task_pool.invalid_task).await?;
+            dialogue.update(CwDialogueState::AwaitingPoolTaskTitle).await?;
+            return Ok(());
+        }
+        Err(err) => return Err(CwBotError::External(err.into())),
+    }
+
+    dialogue.update(CwDialogueState::Idle).await?;
+    bot.send_message(dialogue.chat_id(), T.admin_task_pool.added).await?;
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub enum TaskPoolCallback {
+    Add,
+    ResetSeason,
+    Plan(i32, u32),
+    /// An empty tag stands for any tag
+    Pick(i32, u32, String),
+}
+
+impl Into<String> for TaskPoolCallback {
+    fn into(self) -> String {
+        match self {
+            TaskPoolCallback::Add => "admin_pool_add".to_string(),
+            TaskPoolCallback::ResetSeason => "admin_pool_reset".to_string(),
+            TaskPoolCallback::Plan(year, week) => format!("admin_pool_plan:{year}:{week}"),
+            TaskPoolCallback::Pick(year, week, tag) => format!("admin_pool_pick:{year}:{week}:{tag}"),
+        }
+    }
+}
+
+impl TryFrom<String> for TaskPoolCallback {
+    type Error = ();
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "admin_pool_add" => return Ok(TaskPoolCallback::Add),
+            "admin_pool_reset" => return Ok(TaskPoolCallback::ResetSeason),
+            _ => {}
+        }
+
+        let (action, args) = value.split_once(':').ok_or(())?;
+        let mut args = args.splitn(3, ':');
+        let year: i32 = args.next().ok_or(())?.parse().map_err(|_| ())?;
+        let week: u32 = args.next().ok_or(())?.parse().map_err(|_| ())?;
+        match (action, args.next()) {
+            ("admin_pool_plan", None) => Ok(TaskPoolCallback::Plan(year, week)),
+            ("admin_pool_pick", Some(tag)) => Ok(TaskPoolCallback::Pick(year, week, tag.to_string())),
+            _ => Err(()),
+        }
+    }
+}
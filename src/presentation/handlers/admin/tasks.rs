@@ -0,0 +1,113 @@
+use teloxide::prelude::*;
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup};
+
+use crate::domain::use_cases::{ListTasksUseCase, PlannedWeekDto};
+use crate::presentation::handlers::admin::TaskCalendarCallback;
+use crate::presentation::handlers::texts::T;
+use crate::presentation::handlers::utils::{CwBotError, CwDialogue, CwHandlerResult};
+
+
+const TASKS_PAGE_SIZE: usize = 10;
+
+pub async fn handle_admin_menu_tasks_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: ListTasksUseCase,
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+
+    let tasks = use_case.execute().await
+        .map_err(|err| CwBotError::External(err.into()))?;
+
+    if tasks.is_empty() {
+        bot.send_message(dialogue.chat_id(), T.admin_tasks.no_tasks).await?;
+        return Ok(());
+    }
+
+    bot.send_message(dialogue.chat_id(), T.admin_tasks.text)
+        .reply_markup(build_tasks_keyboard(&tasks, 0))
+        .await?;
+    Ok(())
+}
+
+pub async fn handle_tasks_page_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    use_case: ListTasksUseCase,
+    offset: usize,
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+
+    let tasks = use_case.execute().await
+        .map_err(|err| CwBotError::External(err.into()))?;
+
+    if let Some(message) = q.regular_message() {
+        bot.edit_message_reply_markup(dialogue.chat_id(), message.id)
+            .reply_markup(build_tasks_keyboard(&tasks, offset))
+            .await?;
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub enum TasksCallback {
+    Page(usize),
+}
+
+impl From<TasksCallback> for String {
+    fn from(callback: TasksCallback) -> Self {
+        match callback {
+            TasksCallback::Page(offset) => format!("admin_tasks_page:{offset}"),
+        }
+    }
+}
+
+impl TryFrom<String> for TasksCallback {
+    type Error = ();
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let (action, offset) = value.split_once(':').ok_or(())?;
+        match action {
+            "admin_tasks_page" => Ok(TasksCallback::Page(offset.parse().map_err(|_| ())?)),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Buttons open the same task card as the calendar, with edit and delete actions
+fn build_tasks_keyboard(tasks: &[PlannedWeekDto], offset: usize) -> InlineKeyboardMarkup {
+    let offset = offset.min(tasks.len().saturating_sub(1) / TASKS_PAGE_SIZE * TASKS_PAGE_SIZE);
+
+    let mut rows = tasks
+        .iter()
+        .skip(offset)
+        .take(TASKS_PAGE_SIZE)
+        .map(|week| vec![
+            InlineKeyboardButton::callback(
+                T.admin_tasks.task_button(week),
+                TaskCalendarCallback::Week(week.id.0, week.id.1),
+            ),
+        ])
+        .collect::<Vec<_>>();
+
+    let mut navigation = Vec::new();
+    if offset > 0 {
+        navigation.push(InlineKeyboardButton::callback(
+            T.admin_tasks.newer_button,
+            TasksCallback::Page(offset.saturating_sub(TASKS_PAGE_SIZE)),
+        ));
+    }
+    if offset + TASKS_PAGE_SIZE < tasks.len() {
+        navigation.push(InlineKeyboardButton::callback(
+            T.admin_tasks.older_button,
+            TasksCallback::Page(offset + TASKS_PAGE_SIZE),
+        ));
+    }
+    if !navigation.is_empty() {
+        rows.push(navigation);
+    }
+
+    InlineKeyboardMarkup::new(rows)
+}
@@ -21,19 +21,19 @@ pub async fn handle_admin_menu_user_callback(
     let user = use_case.execute(user_id).await
         .map_err(|err| CwBotError::External(err.into()))?;
 
-    let text = if let Some(user_task) = user.user_task {
+    let text = if let Some(user_task) = &user.user_task {
         T.admin_users.user_info_with_current_meeting(
-            user.username.as_str(),
+            &user.contact(),
             &user.full_name,
             &user.group_name,
             &user.next_task_status,
             &user_task.state,
-            &user_task.partner_username,
+            &user_task.partners,
             &user.completed_quests,
         )
     } else {
         T.admin_users.user_info(
-            user.username.as_str(),
+            &user.contact(),
             &user.full_name,
             &user.group_name,
             &user.next_task_status,
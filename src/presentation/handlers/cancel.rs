@@ -0,0 +1,70 @@
+use teloxide::dispatching::dialogue::GetChatId;
+use teloxide::prelude::*;
+use teloxide::types::KeyboardRemove;
+use teloxide::{ApiError, RequestError};
+
+use crate::domain::use_cases::{CheckAdminUseCase, GetMenuStateUseCase};
+use crate::presentation::handlers::admin::send_admin_menu;
+use crate::presentation::handlers::fsm::CwDialogueState;
+use crate::presentation::handlers::texts::T;
+use crate::presentation::handlers::user::send_menu;
+use crate::presentation::handlers::utils::{CwBotError, CwDialogue, CwHandlerResult};
+
+
+pub async fn handle_cancel_command(
+    bot: Bot,
+    msg: Message,
+    dialogue: CwDialogue,
+    state: CwDialogueState,
+    check_admin_use_case: CheckAdminUseCase,
+    get_menu_state_use_case: GetMenuStateUseCase,
+) -> CwHandlerResult {
+    dialogue.update(CwDialogueState::Idle).await?;
+    log::info!("user {} has cancelled dialogue state {:?}", msg.chat.id.0, state);
+
+    // there is no menu until the profile is completed
+    if matches!(state, CwDialogueState::AwaitingFullName | CwDialogueState::AwaitingGroupName { .. }) {
+        bot.send_message(msg.chat.id, T.cancel.registration_paused)
+            .reply_markup(KeyboardRemove::new())
+            .await?;
+        return Ok(());
+    }
+
+    bot.send_message(msg.chat.id, T.cancel.cancelled)
+        .reply_markup(KeyboardRemove::new())
+        .await?;
+
+    if state.is_admin_flow() {
+        let rights = check_admin_use_case.execute(msg.chat.id.0).await
+            .map_err(|err| CwBotError::External(err.into()))?;
+
+        if let Some(rights) = rights {
+            return send_admin_menu(&bot, msg.chat.id, &rights).await;
+        }
+    }
+
+    send_menu(bot, msg, get_menu_state_use_case).await
+}
+
+/// Handles presses on inline keyboards which are no longer valid for the
+/// current dialogue state, e.g. after `/cancel`
+pub async fn handle_stale_callback(
+    bot: Bot,
+    q: CallbackQuery,
+) -> CwHandlerResult {
+    log::info!("user {} pressed a stale inline button: {:?}", q.from.id, q.data);
+
+    bot.answer_callback_query(&q.id)
+        .text(T.cancel.stale_keyboard)
+        .await?;
+
+    if let (Some(chat_id), Some(message)) = (q.chat_id(), q.regular_message()) {
+        // the keyboard may already be gone, e.g. after a double tap
+        match bot.edit_message_reply_markup(chat_id, message.id).await {
+            Ok(_) | Err(RequestError::Api(ApiError::MessageNotModified)) => {}
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 
-#[derive(Default, Clone)] 
+#[derive(Debug, Default, Clone, PartialEq)]
 #[derive(Serialize, Deserialize)]
 pub enum CwDialogueState {
     #[default]
@@ -11,13 +11,53 @@ pub enum CwDialogueState {
 
     Menu,
     AwaitingAcceptNextMeeting,
+    AwaitingReport { photo_file_ids: Vec<String>, caption: Option<String> },
     
     AwaitingUser,
     AwaitingPartner1,
     AwaitingPartner2 { partner_1_id: i64 },
+    AwaitingPartner3 { partner_1_id: i64, partner_2_id: i64 },
+    AwaitingAutoPairConfirm { groups: Vec<Vec<i64>> },
+    AwaitingTaskVariant { user_ids: Vec<i64> },
     
-    AwaitingTaskTitle,
-    AwaitingTaskDescription { title: String },
+    AwaitingTaskTitle { week_id: (i32, u32), edit: bool },
+    AwaitingTaskDescription { week_id: (i32, u32), edit: bool, title: String },
+    AwaitingTaskMedia { week_id: (i32, u32), edit: bool, title: String, description: String },
+    AwaitingVariantTitle { week_id: (i32, u32) },
+    AwaitingVariantDescription { week_id: (i32, u32), title: String },
+
+    AwaitingPoolTaskTitle,
+    AwaitingPoolTaskDescription { title: String },
+    AwaitingPoolTaskTags { title: String, description: String },
 
     AwaitingUserForComplete,
+    AwaitingReportRejectReason { user_id: i64, week_id: (i32, u32) },
+
+    AwaitingUserForGrant,
+}
+
+impl CwDialogueState {
+    /// Whether the state belongs to one of the admin menu flows
+    pub fn is_admin_flow(&self) -> bool {
+        matches!(
+            self,
+            CwDialogueState::AwaitingUser
+                | CwDialogueState::AwaitingPartner1
+                | CwDialogueState::AwaitingPartner2 { .. }
+                | CwDialogueState::AwaitingPartner3 { .. }
+                | CwDialogueState::AwaitingAutoPairConfirm { .. }
+                | CwDialogueState::AwaitingTaskVariant { .. }
+                | CwDialogueState::AwaitingTaskTitle { .. }
+                | CwDialogueState::AwaitingTaskDescription { .. }
+                | CwDialogueState::AwaitingTaskMedia { .. }
+                | CwDialogueState::AwaitingVariantTitle { .. }
+                | CwDialogueState::AwaitingVariantDescription { .. }
+                | CwDialogueState::AwaitingPoolTaskTitle
+                | CwDialogueState::AwaitingPoolTaskDescription { .. }
+                | CwDialogueState::AwaitingPoolTaskTags { .. }
+                | CwDialogueState::AwaitingUserForComplete
+                | CwDialogueState::AwaitingReportRejectReason { .. }
+                | CwDialogueState::AwaitingUserForGrant
+        )
+    }
 }
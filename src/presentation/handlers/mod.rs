@@ -3,5 +3,6 @@ pub mod utils;
 pub mod admin;
 pub mod user;
 pub mod fsm;
+pub mod cancel;
 
 mod texts;
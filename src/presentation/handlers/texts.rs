@@ -1,9 +1,42 @@
+use teloxide::utils::html::escape;
+
+use crate::domain::use_cases::{AdminDto, ContactDto, PlannedWeekDto, TaskDto, TaskMediaDto, TaskPoolDto, TaskVariantDto};
+
+// Formatted texts are sent with `ParseMode::Html`, so every user-provided
+// value interpolated into them must go through `escape` or `mention`.
+
 type StaticText = &'static str;
 
+/// Users without a username can only be reached through a link to their id
+fn mention(contact: &ContactDto) -> String {
+    match &contact.username {
+        Some(username) => format!("@{}", escape(username)),
+        None => format!(
+            "<a href=\"tg://user?id={}\">{}</a>",
+            contact.id,
+            escape(contact.full_name.as_deref().unwrap_or("без никнейма")),
+        ),
+    }
+}
+
+fn mentions(contacts: &[ContactDto]) -> String {
+    contacts
+        .iter()
+        .map(mention)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn partners_label(contacts: &[ContactDto]) -> StaticText {
+    if contacts.len() > 1 { "Партнёры" } else { "Партнёр" }
+}
+
 pub struct RegistrationTexts {
     pub start: StaticText,
     pub enter_full_name: StaticText,
     pub enter_group_name: StaticText,
+    pub invalid_full_name: StaticText,
+    pub invalid_group_name: StaticText,
     pub registration_complete: StaticText,
 }
 
@@ -19,12 +52,19 @@ pub struct Rules {
     pub text: StaticText,
 }
 
+pub struct Cancel {
+    pub cancelled: StaticText,
+    pub registration_paused: StaticText,
+    pub stale_keyboard: StaticText,
+}
+
 pub struct NextTask {
     pub text: StaticText,
     pub accept_button: StaticText,
     pub reject_button: StaticText,
     pub accept_success: StaticText,
     pub reject_success: StaticText,
+    pub already_answered: StaticText,
 }
 
 pub struct UserTask;
@@ -32,22 +72,55 @@ pub struct UserTask;
 impl UserTask {
     pub fn user_task(
         &self,
-        partner_username: &str,
+        partners: &[ContactDto],
         title: &str,
         description: &str,
     ) -> String {
+        let label = partners_label(partners);
+        let partners = mentions(partners);
         format!(
             "⭐️ Вот твоё задание и партнёр на неделю!\n\
              \n\
-             <b>Партнёр</b>: @{partner_username}\n\
-             <b>Задание</b>: {title}\n\
-             <i>{description}</i>",
+             <b>{label}</b>: {partners}\n\
+             <b>Задание</b>: {}\n\
+             <i>{}</i>",
+            escape(title),
+            escape(description),
+        )
+    }
+
+    pub fn reminder(
+        &self,
+        partners: &[ContactDto],
+        title: &str,
+    ) -> String {
+        let label = partners_label(partners);
+        let partners = mentions(partners);
+        format!(
+            "⏰ Напоминаем: неделя скоро закончится, а задание ещё не выполнено!\n\
+             \n\
+             <b>{label}</b>: {partners}\n\
+             <b>Задание</b>: {}",
+            escape(title),
         )
     }
 }
 
+pub struct TaskReport {
+    pub send_button: StaticText,
+    pub prompt: StaticText,
+    pub submit_button: StaticText,
+    pub cancel_button: StaticText,
+    pub caption_saved: StaticText,
+    pub no_photos: StaticText,
+    pub success: StaticText,
+    pub already_sent: StaticText,
+    pub cancelled: StaticText,
+}
+
 pub struct Profile {
     pub re_register_button: StaticText,
+    pub no_history: StaticText,
 }
 
 impl Profile {
@@ -61,10 +134,37 @@ impl Profile {
         format!(
             "<b>Вот информация о тебе</b>:\n\
              \n\
-             <b>Полное имя</b>: {full_name}\n\
-             <b>Учебная группа</b>: {group_name}\n\
-             <b>Следующая встреча</b>: {next_task}\n\
+             <b>Полное имя</b>: {}\n\
+             <b>Учебная группа</b>: {}\n\
+             <b>Следующая встреча</b>: {}\n\
              <b>Завершено</b>: {completed_tasks}",
+            escape(full_name),
+            escape(group_name),
+            escape(next_task),
+        )
+    }
+
+    pub fn history(
+        &self,
+        entries: &[(i32, u32, Vec<ContactDto>, String, String)],
+    ) -> String {
+        if entries.is_empty() {
+            return self.no_history.to_string();
+        }
+        let entries = entries
+            .iter()
+            .map(|(year, week, partners, title, state)| format!(
+                "• {week} неделя {year}: {}, «{}» ({})",
+                mentions(partners),
+                escape(title),
+                escape(state),
+            ))
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!(
+            "<b>История встреч</b>:\n\
+             \n\
+             {entries}",
         )
     }
 }
@@ -72,9 +172,15 @@ impl Profile {
 pub struct AdminMenu {
     pub text: StaticText,
     pub users_button: StaticText,
-    pub create_next_task_button: StaticText,
+    pub task_calendar_button: StaticText,
+    pub tasks_button: StaticText,
+    pub task_pool_button: StaticText,
     pub assign_partner_button: StaticText,
+    pub auto_pair_button: StaticText,
     pub verification_button: StaticText,
+    pub review_button: StaticText,
+    pub admins_button: StaticText,
+    pub data_changed: StaticText,
 }
 
 pub struct AdminUsers {
@@ -85,39 +191,50 @@ pub struct AdminUsers {
 impl AdminUsers {
     pub fn user_info(
         &self,
-        username: &str,
+        user: &ContactDto,
         full_name: &str,
         group_name: &str,
         next_meeting_state: &str,
         completed_quests: &i32,
     ) -> String {
         format!(
-            "<b>Никнейм</b>: @{username}\n\
-             <b>ФИО</b>: {full_name}\n\
-             <b>Учебная группа</b>: {group_name}\n\
-             <b>Следующая встреча</b>: {next_meeting_state}\n\
+            "<b>Никнейм</b>: {}\n\
+             <b>ФИО</b>: {}\n\
+             <b>Учебная группа</b>: {}\n\
+             <b>Следующая встреча</b>: {}\n\
              <b>Завершено</b>: {completed_quests}",
+            mention(user),
+            escape(full_name),
+            escape(group_name),
+            escape(next_meeting_state),
         )
     }
 
     pub fn user_info_with_current_meeting(
         &self,
-        username: &str,
+        user: &ContactDto,
         full_name: &str,
         group_name: &str,
         next_meeting_state: &str,
         current_meeting_state: &str,
-        partner_username: &str,
+        partners: &[ContactDto],
         completed_quests: &i32,
     ) -> String {
+        let label = partners_label(partners);
+        let partners = mentions(partners);
         format!(
-            "<b>Никнейм</b>: @{username}\n\
-             <b>ФИО</b>: {full_name}\n\
-             <b>Учебная группа</b>: {group_name}\n\
-             <b>Следующая встреча</b>: {next_meeting_state}\n\
-             <b>Текущая встреча</b>: {current_meeting_state}\n\
-             <b>Партнёр</b>: @{partner_username}\n\
+            "<b>Никнейм</b>: {}\n\
+             <b>ФИО</b>: {}\n\
+             <b>Учебная группа</b>: {}\n\
+             <b>Следующая встреча</b>: {}\n\
+             <b>Текущая встреча</b>: {}\n\
+             <b>{label}</b>: {partners}\n\
              <b>Завершено</b>: {completed_quests}",
+            mention(user),
+            escape(full_name),
+            escape(group_name),
+            escape(next_meeting_state),
+            escape(current_meeting_state),
         )
     }
 }
@@ -126,35 +243,403 @@ pub struct AdminAssign {
     pub insufficient_users: StaticText,
     pub assign_first: StaticText,
     pub assign_second: StaticText,
+    pub assign_third: StaticText,
+    pub assign_pair_button: StaticText,
     pub assign_success: StaticText,
+    pub choose_variant: StaticText,
+    pub random_variant_button: StaticText,
+    pub main_variant_button: StaticText,
+    pub variant_not_found: StaticText,
     pub no_next_task: StaticText,
+    pub bot_blocked: StaticText,
+    pub user_deactivated: StaticText,
+    pub chat_not_found: StaticText,
+}
+
+impl AdminAssign {
+    pub fn variant_button(&self, variant: &TaskVariantDto) -> String {
+        format!("{}. {}", variant.number, variant.title)
+    }
+
+    pub fn notify_failed(&self, failures: &[(ContactDto, String)]) -> String {
+        let failures = failures
+            .iter()
+            .map(|(user, reason)| format!("• {}: {}", mention(user), escape(reason)))
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!(
+            "Не удалось уведомить пользователей о назначенной встрече:\n\
+             \n\
+             {failures}",
+        )
+    }
+}
+
+pub struct AdminAutoPair {
+    pub confirm_button: StaticText,
+    pub cancel_button: StaticText,
+    pub no_pairs: StaticText,
+    pub cancelled: StaticText,
+    pub data_changed: StaticText,
+}
+
+impl AdminAutoPair {
+    pub fn not_enough_users(&self, leftover: &[String]) -> String {
+        let mut text = self.no_pairs.to_string();
+        if !leftover.is_empty() {
+            text.push_str(&format!(
+                "\n\n<b>Остались без пары</b>: {}",
+                leftover.iter().map(|name| escape(name)).collect::<Vec<_>>().join(", "),
+            ));
+        }
+        text
+    }
+
+    pub fn proposal(
+        &self,
+        groups: &[Vec<String>],
+        leftover: &[String],
+    ) -> String {
+        let pairs = groups
+            .iter()
+            .enumerate()
+            .map(|(i, group)| format!(
+                "{}. {}",
+                i + 1,
+                group.iter().map(|name| escape(name)).collect::<Vec<_>>().join(" — "),
+            ))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let mut text = format!(
+            "<b>Предлагаемые пары</b>:\n\
+             \n\
+             {pairs}",
+        );
+        if !leftover.is_empty() {
+            text.push_str(&format!(
+                "\n\n<b>Остались без пары</b>: {}",
+                leftover.iter().map(|name| escape(name)).collect::<Vec<_>>().join(", "),
+            ));
+        }
+        text
+    }
+
+    pub fn success(&self, pairs: usize) -> String {
+        format!("Пары успешно назначены: {pairs}")
+    }
+}
+
+pub struct AdminBroadcast;
+
+impl AdminBroadcast {
+    pub fn report(&self, sent: usize, failures: &[(ContactDto, String)]) -> String {
+        let mut text = format!(
+            "<b>Рассылка приглашений на следующую встречу</b>\n\
+             \n\
+             Доставлено: {sent}\n\
+             Не доставлено: {}",
+            failures.len(),
+        );
+        if !failures.is_empty() {
+            let failures = failures
+                .iter()
+                .map(|(user, reason)| format!("• {}: {}", mention(user), escape(reason)))
+                .collect::<Vec<_>>()
+                .join("\n");
+            text.push_str(&format!("\n\n{failures}"));
+        }
+        text
+    }
+}
+
+pub struct AdminReview {
+    pub no_reports: StaticText,
+    pub approve_button: StaticText,
+    pub reject_button: StaticText,
+    pub enter_reason: StaticText,
+    pub approved: StaticText,
+    pub rejected: StaticText,
+    pub already_reviewed: StaticText,
+    pub approved_notification: StaticText,
+}
+
+impl AdminReview {
+    pub fn report(
+        &self,
+        user: &ContactDto,
+        partners: &[ContactDto],
+        title: &str,
+        caption: Option<&str>,
+    ) -> String {
+        let label = partners_label(partners);
+        let partners = mentions(partners);
+        let mut text = format!(
+            "<b>Отчёт от</b> {}\n\
+             <b>{label}</b>: {partners}\n\
+             <b>Задание</b>: {}",
+            mention(user),
+            escape(title),
+        );
+        if let Some(caption) = caption {
+            text.push_str(&format!("\n\n<i>{}</i>", escape(caption)));
+        }
+        text
+    }
+
+    pub fn rejected_notification(&self, reason: &str) -> String {
+        format!(
+            "😔 Ваш отчёт о встрече отклонён.\n\
+             \n\
+             <b>Причина</b>: {}\n\
+             \n\
+             Вы можете отправить новый отчёт в разделе «Актуальная встреча»",
+            escape(reason),
+        )
+    }
 }
 
 pub struct AdminCreateTask {
-    pub enter_title: StaticText,
     pub enter_description: StaticText,
+    pub text_expected: StaticText,
+    pub enter_media: StaticText,
+    pub unsupported_media: StaticText,
     pub success: StaticText,
+    pub updated: StaticText,
     pub already_exists: StaticText,
 }
 
+pub struct AdminTaskCalendar {
+    pub text: StaticText,
+    pub prev_button: StaticText,
+    pub next_button: StaticText,
+    pub edit_button: StaticText,
+    pub delete_button: StaticText,
+    pub write_button: StaticText,
+    pub from_pool_button: StaticText,
+    pub add_variant_button: StaticText,
+    pub enter_variant_title: StaticText,
+    pub invalid_variant: StaticText,
+    pub variant_added: StaticText,
+    pub variant_deleted: StaticText,
+    pub variant_in_use: StaticText,
+    pub variant_not_found: StaticText,
+    pub deleted: StaticText,
+    pub in_use: StaticText,
+    pub edit_in_use: StaticText,
+    pub week_started: StaticText,
+    pub not_found: StaticText,
+}
+
+impl AdminTaskCalendar {
+    pub fn week_button(&self, week: &PlannedWeekDto) -> String {
+        match week.task {
+            Some(_) => format!("✅ {}", week.starts_on),
+            None => format!("➕ {}", week.starts_on),
+        }
+    }
+
+    pub fn planned_task(&self, week: &PlannedWeekDto) -> String {
+        let (title, description) = week.task
+            .as_ref()
+            .map(|task| (task.title.as_str(), task.description.as_str()))
+            .unwrap_or_default();
+        let media = match week.task.as_ref().and_then(|task| task.media.as_ref()) {
+            Some(TaskMediaDto::Photo(_)) => "\n\n📎 Вложение: фото",
+            Some(TaskMediaDto::Document(_)) => "\n\n📎 Вложение: документ",
+            Some(TaskMediaDto::Location { .. }) => "\n\n📎 Вложение: геопозиция",
+            None => "",
+        };
+        let variants = week.task
+            .iter()
+            .flat_map(|task| &task.variants)
+            .map(|variant| format!(
+                "\n\n<b>Вариант {}: {}</b>\n{}",
+                variant.number,
+                escape(&variant.title),
+                escape(&variant.description),
+            ))
+            .collect::<String>();
+        format!(
+            "<b>Неделя с {}</b>\n\
+             \n\
+             <b>{}</b>\n\
+             {}{media}{variants}",
+            escape(&week.starts_on),
+            escape(title),
+            escape(description),
+        )
+    }
+
+    pub fn delete_variant_button(&self, number: u32) -> String {
+        format!("Удалить вариант {number} 🗑")
+    }
+
+    pub fn enter_title(&self, starts_on: &str) -> String {
+        format!("Введите название задания на неделю с {starts_on}")
+    }
+
+    pub fn empty_week(&self, starts_on: &str) -> String {
+        format!("На неделю с {starts_on} задание ещё не запланировано")
+    }
+}
+
+pub struct AdminTasks {
+    pub text: StaticText,
+    pub no_tasks: StaticText,
+    pub newer_button: StaticText,
+    pub older_button: StaticText,
+}
+
+impl AdminTasks {
+    pub fn task_button(&self, week: &PlannedWeekDto) -> String {
+        let title = week.task.as_ref().map(|task| task.title.as_str()).unwrap_or_default();
+        format!("{} · {title}", week.starts_on)
+    }
+}
+
+// Telegram messages are limited to 4096 characters
+const TASK_POOL_MAX_ENTRIES: usize = 40;
+
+pub struct AdminTaskPool {
+    pub add_button: StaticText,
+    pub reset_button: StaticText,
+    pub enter_title: StaticText,
+    pub enter_tags: StaticText,
+    pub invalid_task: StaticText,
+    pub added: StaticText,
+    pub choose_tag: StaticText,
+    pub any_tag_button: StaticText,
+    pub exhausted: StaticText,
+}
+
+impl AdminTaskPool {
+    pub fn summary(&self, pool: &TaskPoolDto) -> String {
+        if pool.tasks.is_empty() {
+            return "<b>Банк заданий</b>\n\nБанк пуст - добавьте первое задание".to_string();
+        }
+
+        let available = pool.tasks.iter().filter(|task| task.used_on.is_none()).count();
+        let mut entries = pool.tasks
+            .iter()
+            .take(TASK_POOL_MAX_ENTRIES)
+            .map(|task| {
+                let tags = task.tags
+                    .iter()
+                    .map(|tag| format!(" #{}", escape(tag)))
+                    .collect::<String>();
+                match &task.used_on {
+                    Some(used_on) => format!("☑️ {}{tags} - неделя с {}", escape(&task.title), escape(used_on)),
+                    None => format!("▫️ {}{tags}", escape(&task.title)),
+                }
+            })
+            .collect::<Vec<_>>();
+        if pool.tasks.len() > TASK_POOL_MAX_ENTRIES {
+            entries.push(format!("...и ещё {}", pool.tasks.len() - TASK_POOL_MAX_ENTRIES));
+        }
+
+        format!(
+            "<b>Банк заданий</b>\n\
+             Свободно в этом сезоне: {available} из {}\n\
+             \n\
+             {}",
+            pool.tasks.len(),
+            entries.join("\n"),
+        )
+    }
+
+    pub fn tag_button(&self, tag: &str) -> String {
+        format!("#{tag}")
+    }
+
+    pub fn planned(&self, task: &TaskDto) -> String {
+        format!(
+            "Из банка запланировано задание:\n\
+             \n\
+             <b>{}</b>\n\
+             {}",
+            escape(&task.title),
+            escape(&task.description),
+        )
+    }
+
+    pub fn season_reset(&self, reset: u64) -> String {
+        format!("Начат новый сезон, снова доступно заданий: {reset}")
+    }
+}
+
 pub struct AdminCompleteTask {
     pub text: StaticText,
     pub no_users: StaticText,
-    pub success: StaticText,
+    pub choose_scope: StaticText,
+    pub pair_button: StaticText,
+    pub single_button: StaticText,
+}
+
+impl AdminCompleteTask {
+    pub fn success(&self, completed: usize) -> String {
+        format!("Задание успешно засчитано участникам встречи: {completed}")
+    }
+}
+
+pub struct AdminRights {
+    pub grant_button: StaticText,
+    pub choose_user: StaticText,
+    pub no_users: StaticText,
+    pub choose_role: StaticText,
+    pub admin_role_button: StaticText,
+    pub reviewer_role_button: StaticText,
+    pub granted: StaticText,
+    pub revoked: StaticText,
+    pub not_registered: StaticText,
+    pub owner_unchangeable: StaticText,
+    pub already_revoked: StaticText,
+    pub permission_denied: StaticText,
+}
+
+impl AdminRights {
+    pub fn list(&self, admins: &[AdminDto]) -> String {
+        let entries = admins
+            .iter()
+            .map(|admin| format!("{} — {}", mention(&admin.contact), escape(&admin.role)))
+            .collect::<Vec<_>>()
+            .join("\n");
+        format!(
+            "<b>Администраторы</b>\n\
+             \n\
+             {entries}",
+        )
+    }
+
+    pub fn revoke_button(&self, admin: &AdminDto) -> String {
+        let name = admin.contact.full_name
+            .clone()
+            .or_else(|| admin.contact.username.as_ref().map(|username| format!("@{username}")))
+            .unwrap_or_else(|| admin.contact.id.to_string());
+        format!("Отозвать: {name}")
+    }
 }
 
 pub struct Texts {
     pub registration: RegistrationTexts,
     pub menu: Menu,
     pub rules: Rules,
+    pub cancel: Cancel,
     pub next_task: NextTask,
     pub user_task: UserTask,
+    pub task_report: TaskReport,
     pub profile: Profile,
     pub admin_menu: AdminMenu,
     pub admin_users: AdminUsers,
     pub admin_assign: AdminAssign,
+    pub admin_auto_pair: AdminAutoPair,
+    pub admin_broadcast: AdminBroadcast,
+    pub admin_review: AdminReview,
     pub admin_create_task: AdminCreateTask,
+    pub admin_task_calendar: AdminTaskCalendar,
+    pub admin_tasks: AdminTasks,
+    pub admin_task_pool: AdminTaskPool,
     pub admin_complete_task: AdminCompleteTask,
+    pub admin_rights: AdminRights,
 }
 
 pub const T: Texts = Texts {
@@ -162,8 +647,17 @@ pub const T: Texts = Texts {
         start: "Привет-привет, рады видеть тебя с нами на одной волне! 🏄‍♂️",
         enter_full_name: "Для регистрации пожалуйста укажи своё ФИО в формате: СМурфик СМурфович СМурфов",
         enter_group_name: "Твоя учебная группа в формате: СМ11-11Б",
+        invalid_full_name: "Не получилось разобрать ФИО 🤔\n\
+                            Укажи фамилию, имя и отчество (если есть) через пробел, например: Иванов Иван Иванович",
+        invalid_group_name: "Такой учебной группы не бывает 🤔\n\
+                             Укажи группу в формате: СМ11-11Б",
         registration_complete: "Поздравляю, теперь ты точно с нами 🥰",
     },
+    cancel: Cancel {
+        cancelled: "Действие отменено",
+        registration_paused: "Регистрация прервана - отправь /start, чтобы продолжить",
+        stale_keyboard: "Это меню устарело, откройте его заново",
+    },
     next_task: NextTask {
         text: "Подтверди участие в следующей встрече",
         accept_button: "Подтверждаю ✅",
@@ -171,8 +665,21 @@ pub const T: Texts = Texts {
         accept_success: "🌊 Вы подтвердили участие в следующей встрече!\n\
                          Скоро здесь появится ваш партнер и задание на неделю",
         reject_success: "Вы отказались от участия в следующей встрече(",
+        already_answered: "Вы уже ответили на это приглашение",
     },
     user_task: UserTask{},
+    task_report: TaskReport {
+        send_button: "Отправить отчёт 📸",
+        prompt: "Пришлите фотографии со встречи и, если хотите, подпись к ним.\n\
+                 Когда всё будет готово, нажмите «Отправить»",
+        submit_button: "Отправить ✅",
+        cancel_button: "Отмена ❌",
+        caption_saved: "Подпись сохранена",
+        no_photos: "Добавьте хотя бы одну фотографию",
+        success: "📨 Отчёт отправлен на проверку! Мы сообщим, когда его проверят",
+        already_sent: "Отчёт по этой встрече уже отправлен",
+        cancelled: "Отправка отчёта отменена",
+    },
     menu: Menu {
         text: "Меню",
         next_task_button: "Следующая встреча ❤️‍🩹",
@@ -189,13 +696,20 @@ pub const T: Texts = Texts {
     },
     profile: Profile {
         re_register_button: "Изменить данные",
+        no_history: "У тебя пока не было встреч",
     },
     admin_menu: AdminMenu {
         text: "Меню администратора",
         users_button: "Пользователи",
-        create_next_task_button: "Создать задание",
+        task_calendar_button: "Календарь заданий",
+        tasks_button: "Все задания",
+        task_pool_button: "Банк заданий",
         assign_partner_button: "Пары",
+        auto_pair_button: "Автоподбор пар",
         verification_button: "Подтверждения",
+        review_button: "Отчёты",
+        admins_button: "Администраторы",
+        data_changed: "Данные изменились, обновите список и попробуйте снова",
     },
     admin_users: AdminUsers {
         text: "Вот список всех пользователей бота",
@@ -205,18 +719,179 @@ pub const T: Texts = Texts {
         insufficient_users: "Недостаточно пользователей, подтвердивших встречу, для назначения пар - должно быть минимум двое",
         assign_first: "Выберите первого пользователя пары",
         assign_second: "Выберите второго пользователя пары",
+        assign_third: "Выберите третьего пользователя, если нужна тройка, или назначьте пару",
+        assign_pair_button: "Назначить пару",
         assign_success: "Пара успешно назначена",
+        choose_variant: "У задания на следующую неделю есть варианты - выберите, какой получит пара",
+        random_variant_button: "Случайный 🎲",
+        main_variant_button: "Основное задание",
+        variant_not_found: "Вариант задания не найден - возможно, его уже удалили. Назначьте пару заново",
         no_next_task: "В базе данных нет информации о следующем задании - без этого невозможно назначить партнёров. Добавьте задание в бота и попробуйте снова",
+        bot_blocked: "пользователь заблокировал бота",
+        user_deactivated: "аккаунт пользователя удалён",
+        chat_not_found: "пользователь не начинал диалог с ботом",
+    },
+    admin_auto_pair: AdminAutoPair {
+        confirm_button: "Назначить ✅",
+        cancel_button: "Отмена ❌",
+        no_pairs: "Недостаточно пользователей, подтвердивших встречу, для подбора пар - должно быть минимум двое",
+        cancelled: "Автоподбор пар отменён",
+        data_changed: "Участники изменились после подбора пар - запустите автоподбор заново",
+    },
+    admin_broadcast: AdminBroadcast{},
+    admin_review: AdminReview {
+        no_reports: "Нет отчётов, ожидающих проверки",
+        approve_button: "Принять ✅",
+        reject_button: "Отклонить ❌",
+        enter_reason: "Напишите причину отклонения отчёта - она будет отправлена пользователю",
+        approved: "Отчёт принят, задание засчитано участникам встречи",
+        rejected: "Отчёт отклонён, пользователь получил причину",
+        already_reviewed: "Этот отчёт уже был проверен",
+        approved_notification: "🎉 Отчёт о встрече принят, задание засчитано!",
     },
     admin_create_task: AdminCreateTask {
-        enter_title: "Введите название задания",
         enter_description: "Введите текст задания",
-        success: "Задание на следующую неделю успешно создано",
-        already_exists: "Задание на следующую неделю уже существует. Дождитесь начала новой недели для создания следующего",
+        text_expected: "Ожидается текстовое сообщение - отправьте ответ текстом",
+        enter_media: "Пришлите фото, документ или геопозицию, которые участники получат вместе с заданием.\n\
+                      Если вложение не нужно, отправьте -",
+        unsupported_media: "Можно приложить только фото, документ или геопозицию. Если вложение не нужно, отправьте -",
+        success: "Задание успешно запланировано",
+        updated: "Задание успешно изменено",
+        already_exists: "На эту неделю уже запланировано задание",
+    },
+    admin_task_calendar: AdminTaskCalendar {
+        text: "Календарь заданий\n\
+               ✅ - задание запланировано, ➕ - неделя свободна",
+        prev_button: "◀️ Раньше",
+        next_button: "Позже ▶️",
+        edit_button: "Изменить ✏️",
+        delete_button: "Удалить 🗑",
+        write_button: "Написать ✏️",
+        from_pool_button: "Из банка 🎲",
+        add_variant_button: "Добавить вариант ➕",
+        enter_variant_title: "Введите название варианта задания - пары этой недели получат основное задание \
+                              или один из его вариантов",
+        invalid_variant: "Название должно быть не длиннее 128 символов\n\
+                          Введите название варианта заново",
+        variant_added: "Вариант задания добавлен",
+        variant_deleted: "Вариант задания удалён",
+        variant_in_use: "Вариант уже выдан участникам - удалить его нельзя",
+        variant_not_found: "Вариант не найден - возможно, его уже удалили",
+        deleted: "Задание удалено",
+        in_use: "Задание уже выдано участникам - удалить его нельзя",
+        edit_in_use: "Задание уже выдано участникам - изменить его нельзя",
+        week_started: "Эта неделя уже началась - планировать можно только будущие недели",
+        not_found: "Задание не найдено - возможно, его уже удалили",
+    },
+    admin_tasks: AdminTasks {
+        text: "Все задания, начиная с последних. Выберите задание, чтобы изменить или удалить его",
+        no_tasks: "Заданий пока нет - запланируйте первое в календаре заданий",
+        newer_button: "◀️ Новее",
+        older_button: "Старше ▶️",
+    },
+    admin_task_pool: AdminTaskPool {
+        add_button: "Добавить ➕",
+        reset_button: "Новый сезон 🔄",
+        enter_title: "Введите название задания для банка",
+        enter_tags: "Введите теги через пробел, например: #прогулка #кафе\n\
+                     Если теги не нужны, отправьте -",
+        invalid_task: "Название должно быть не длиннее 128 символов, а теги - не длиннее 16 символов \
+                       и состоять из букв, цифр, _ и -\n\
+                       Введите название задания заново",
+        added: "Задание добавлено в банк",
+        choose_tag: "Выберите тег - задание будет выбрано случайно среди неиспользованных в этом сезоне",
+        any_tag_button: "Любое задание 🎲",
+        exhausted: "В банке не осталось неиспользованных заданий - добавьте новые или начните новый сезон",
     },
     admin_complete_task: AdminCompleteTask {
         text: "Выберите пользователя из списка для подтверждения выполнения задания",   
         no_users: "Нет ни одного пользователя с активным текущим заданием. Необходимо сначала создать пару для выполнения задания",
-        success: "Задание успешно засчитано",
+        choose_scope: "Кому засчитать выполнение задания?",
+        pair_button: "Всей паре 👥",
+        single_button: "Только этому участнику 👤",
+    },
+    admin_rights: AdminRights {
+        grant_button: "Выдать права ➕",
+        choose_user: "Выберите пользователя, которому нужно выдать права",
+        no_users: "Нет ни одного зарегистрированного пользователя",
+        choose_role: "Выберите роль:\n\
+                      <b>Администратор</b> - задания, пары и подтверждения\n\
+                      <b>Проверяющий</b> - только проверка отчётов",
+        admin_role_button: "Администратор",
+        reviewer_role_button: "Проверяющий",
+        granted: "Права выданы",
+        revoked: "Права отозваны",
+        not_registered: "Пользователь не завершил регистрацию",
+        owner_unchangeable: "Права владельцев задаются в настройках бота и не могут быть изменены",
+        already_revoked: "У пользователя уже нет прав администратора",
+        permission_denied: "Недостаточно прав для этого действия",
     }
 };
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::use_cases::PoolTaskDto;
+    use super::*;
+
+    #[test]
+    fn test_user_info_escapes_user_input() {
+        let user = ContactDto { id: 1, username: Some("user_1".to_string()), full_name: None };
+        let text = T.admin_users.user_info(&user, "<b>Иванов</b> Иван", "СМ1&1", "ожидается", &0);
+
+        assert!(text.contains("&lt;b&gt;Иванов&lt;/b&gt; Иван"));
+        assert!(text.contains("СМ1&amp;1"));
+        assert!(!text.contains("<b>Иванов"));
+    }
+
+    #[test]
+    fn test_mentions_escape_usernames() {
+        let partner = ContactDto { id: 1, username: Some("<script>".to_string()), full_name: None };
+        let text = T.user_task.reminder(&[partner], "Title & more");
+
+        assert!(text.contains("@&lt;script&gt;"));
+        assert!(text.contains("Title &amp; more"));
+    }
+
+    #[test]
+    fn test_task_pool_summary_escapes_titles() {
+        let pool = TaskPoolDto {
+            tasks: vec![
+                PoolTaskDto { title: "<i>Кафе</i>".to_string(), tags: vec!["кафе".to_string()], used_on: None },
+                PoolTaskDto { title: "Парк".to_string(), tags: Vec::new(), used_on: Some("24.03.2025".to_string()) },
+            ],
+            tags: vec!["кафе".to_string()],
+        };
+        let text = T.admin_task_pool.summary(&pool);
+
+        assert!(text.contains("&lt;i&gt;Кафе&lt;/i&gt; #кафе"));
+        assert!(text.contains("Свободно в этом сезоне: 1 из 2"));
+        assert!(text.contains("Парк - неделя с 24.03.2025"));
+    }
+
+    #[test]
+    fn test_planned_task_lists_escaped_variants() {
+        let week = PlannedWeekDto {
+            id: (2025, 13),
+            starts_on: "24.03.2025".to_string(),
+            task: Some(TaskDto {
+                title: "Кофе".to_string(),
+                description: "Выпить кофе".to_string(),
+                media: None,
+                variants: vec![
+                    TaskVariantDto { number: 1, title: "<i>Чай</i>".to_string(), description: "Выпить чай".to_string() },
+                ],
+            }),
+        };
+        let text = T.admin_task_calendar.planned_task(&week);
+
+        assert!(text.contains("<b>Вариант 1: &lt;i&gt;Чай&lt;/i&gt;</b>\nВыпить чай"));
+    }
+
+    #[test]
+    fn test_mention_without_username_links_to_id() {
+        let partner = ContactDto { id: 42, username: None, full_name: Some("Иванов <Иван>".to_string()) };
+        let text = T.user_task.reminder(&[partner], "Title");
+
+        assert!(text.contains("<a href=\"tg://user?id=42\">Иванов &lt;Иван&gt;</a>"));
+    }
+}
@@ -2,12 +2,16 @@ mod user_task;
 mod menu;
 mod next_task;
 mod profile;
+mod report;
 mod registration;
 mod rules;
+mod username;
 
 pub use user_task::*;
 pub use menu::*;
 pub use next_task::*;
 pub use profile::*;
+pub use report::*;
 pub use registration::*;
 pub use rules::*;
+pub use username::*;
@@ -1,10 +1,13 @@
+use teloxide::dispatching::dialogue::GetChatId;
 use teloxide::prelude::*;
-use teloxide::types::{KeyboardButton, KeyboardMarkup};
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, KeyboardButton, KeyboardMarkup};
+use teloxide::RequestError;
 
+use crate::domain::error::DomainError;
 use crate::domain::use_cases::{AcceptNextTaskUseCase, GetMenuStateUseCase, RejectTaskUseCase};
 use crate::presentation::handlers::fsm::CwDialogueState;
 use crate::presentation::handlers::texts::T;
-use crate::presentation::handlers::user::send_menu;
+use crate::presentation::handlers::user::{send_menu, send_menu_callback};
 use crate::presentation::handlers::utils::{CwBotError, CwDialogue, CwHandlerResult};
 
 
@@ -36,7 +39,7 @@ pub async fn handle_next_meeting_accept(
 
     bot.send_message(msg.chat.id, T.next_task.accept_success).await?;
     dialogue.update(CwDialogueState::Idle).await?;
-    log::info!("user @{} accepted the next task", msg.chat.username().unwrap());
+    log::info!("user {} accepted the next task", msg.chat.id);
     
     send_menu(bot, msg, get_menu_state_use_case).await
 }
@@ -53,7 +56,7 @@ pub async fn handle_next_meeting_reject(
 
     bot.send_message(msg.chat.id, T.next_task.reject_success).await?;
     dialogue.update(CwDialogueState::Idle).await?;
-    log::info!("user @{} rejected the next task", msg.chat.username().unwrap());
+    log::info!("user {} rejected the next task", msg.chat.id);
 
     send_menu(bot, msg, get_menu_state_use_case).await
 
@@ -101,3 +104,114 @@ pub fn next_meeting_keyboard() -> KeyboardMarkup {
     ])
         .one_time_keyboard()
 }
+
+pub async fn send_next_task_prompt(bot: &Bot, chat_id: ChatId) -> Result<(), RequestError> {
+    bot.send_message(chat_id, T.next_task.text)
+        .reply_markup(next_task_prompt_keyboard())
+        .await?;
+    Ok(())
+}
+
+pub async fn handle_next_task_prompt_accept_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    accept_task_use_case: AcceptNextTaskUseCase,
+    get_menu_state_use_case: GetMenuStateUseCase,
+) -> CwHandlerResult {
+    let chat_id = q.chat_id().unwrap();
+    let result = accept_task_use_case.execute(chat_id.0).await;
+    answer_next_task_prompt(&bot, &q, result, T.next_task.accept_success).await?;
+
+    dialogue.update(CwDialogueState::Idle).await?;
+    log::info!("user {} accepted the next task from the broadcast", chat_id);
+
+    send_menu_callback(bot, q, get_menu_state_use_case).await
+}
+
+pub async fn handle_next_task_prompt_reject_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+    reject_task_use_case: RejectTaskUseCase,
+    get_menu_state_use_case: GetMenuStateUseCase,
+) -> CwHandlerResult {
+    let chat_id = q.chat_id().unwrap();
+    let result = reject_task_use_case.execute(chat_id.0).await;
+    answer_next_task_prompt(&bot, &q, result, T.next_task.reject_success).await?;
+
+    dialogue.update(CwDialogueState::Idle).await?;
+    log::info!("user {} rejected the next task from the broadcast", chat_id);
+
+    send_menu_callback(bot, q, get_menu_state_use_case).await
+}
+
+async fn answer_next_task_prompt(
+    bot: &Bot,
+    q: &CallbackQuery,
+    result: Result<(), DomainError>,
+    success_text: &str,
+) -> CwHandlerResult {
+    let chat_id = q.chat_id().unwrap();
+    let text = match result {
+        Ok(()) => success_text,
+        Err(DomainError::InvalidStateChange(_)) => T.next_task.already_answered,
+        Err(err) => return Err(CwBotError::Other(err.to_string())),
+    };
+
+    bot.answer_callback_query(q.id.clone()).await?;
+    if let Some(message) = q.regular_message() {
+        bot.edit_message_reply_markup(chat_id, message.id).await?;
+    }
+    bot.send_message(chat_id, text).await?;
+    Ok(())
+}
+
+#[derive(Debug, Clone)]
+pub enum NextTaskPromptCallback {
+    Accept,
+    Reject,
+}
+
+impl Into<String> for NextTaskPromptCallback {
+    fn into(self) -> String {
+        match self {
+            NextTaskPromptCallback::Accept => "next_task_prompt_accept".to_string(),
+            NextTaskPromptCallback::Reject => "next_task_prompt_reject".to_string(),
+        }
+    }
+}
+
+impl Into<InlineKeyboardButton> for NextTaskPromptCallback {
+    fn into(self) -> InlineKeyboardButton {
+        match self {
+            NextTaskPromptCallback::Accept => InlineKeyboardButton::callback(
+                T.next_task.accept_button, NextTaskPromptCallback::Accept,
+            ),
+            NextTaskPromptCallback::Reject => InlineKeyboardButton::callback(
+                T.next_task.reject_button, NextTaskPromptCallback::Reject,
+            ),
+        }
+    }
+}
+
+impl TryFrom<String> for NextTaskPromptCallback {
+    type Error = ();
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "next_task_prompt_accept" => Ok(NextTaskPromptCallback::Accept),
+            "next_task_prompt_reject" => Ok(NextTaskPromptCallback::Reject),
+            _ => Err(()),
+        }
+    }
+}
+
+fn next_task_prompt_keyboard() -> InlineKeyboardMarkup {
+    InlineKeyboardMarkup::new(vec![
+        vec![
+            NextTaskPromptCallback::Accept.into(),
+            NextTaskPromptCallback::Reject.into(),
+        ],
+    ])
+}
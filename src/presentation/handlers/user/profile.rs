@@ -19,11 +19,25 @@ pub async fn handle_profile_callback(
         .await
         .map_err(|err| CwBotError::Other(err.to_string()))?;
     
-    let text = T.profile.profile(
-        &user.full_name,
-        &user.group_name,
-        &user.next_task_status,
-        &user.completed_quests,
+    let history = user.history
+        .iter()
+        .map(|ut| (
+            ut.id.0,
+            ut.id.1,
+            ut.partners.clone(),
+            ut.title.clone(),
+            ut.state.clone(),
+        ))
+        .collect::<Vec<_>>();
+    let text = format!(
+        "{}\n\n{}",
+        T.profile.profile(
+            &user.full_name,
+            &user.group_name,
+            &user.next_task_status,
+            &user.completed_quests,
+        ),
+        T.profile.history(&history),
     );
     
     bot.send_message(dialogue.chat_id(), text)
@@ -2,7 +2,7 @@ use teloxide::prelude::*;
 
 use crate::domain::error::DomainError;
 use crate::domain::use_cases::{
-    CompleteRegistrationUseCase, GetMenuStateUseCase, StartRegistrationUseCase
+    CheckFullNameUseCase, CompleteRegistrationUseCase, GetMenuStateUseCase, StartRegistrationUseCase
 };
 use crate::presentation::handlers::fsm::CwDialogueState;
 use crate::presentation::handlers::texts::T;
@@ -17,10 +17,7 @@ pub async fn handle_start_command(
     start_registration_use_case: StartRegistrationUseCase,
     get_menu_state_use_case: GetMenuStateUseCase,
 ) -> CwHandlerResult {
-    let username = msg.chat.username()
-        .ok_or(CwBotError::Other(format!("no username for user id {}", msg.chat.id.0)))?;
-    
-    if let Err(err) = start_registration_use_case.execute(msg.chat.id.0, username).await {
+    if let Err(err) = start_registration_use_case.execute(msg.chat.id.0, msg.chat.username()).await {
         return match err {
             DomainError::UserAlreadyExists(_) => send_menu(bot, msg, get_menu_state_use_case).await,
             _ => Err(CwBotError::Other(err.to_string())),
@@ -41,12 +38,22 @@ pub async fn receive_full_name(
     bot: Bot,
     msg: Message,
     dialogue: CwDialogue,
+    check_full_name_use_case: CheckFullNameUseCase,
 ) -> CwHandlerResult {
-    let full_name = match msg.text() {
-        Some(text) => text.to_string(),
-        None => return Ok(()),
+    let Some(text) = msg.text() else {
+        bot.send_message(msg.chat.id, T.registration.invalid_full_name).await?;
+        return Ok(());
+    };
+    log::info!("received full name for @{:?}: {}", msg.chat.username(), text);
+
+    let full_name = match check_full_name_use_case.execute(text) {
+        Ok(full_name) => full_name,
+        Err(DomainError::InvalidFullName(_)) => {
+            bot.send_message(msg.chat.id, T.registration.invalid_full_name).await?;
+            return Ok(());
+        }
+        Err(err) => return Err(CwBotError::Other(err.to_string())),
     };
-    log::info!("received full name for @{:?}: {}", msg.chat.username(), full_name);
 
     dialogue.update(CwDialogueState::AwaitingGroupName { full_name }).await?;
     
@@ -63,16 +70,31 @@ pub async fn receive_group_name(
     complete_registration_use_case: CompleteRegistrationUseCase,
     get_menu_state_use_case: GetMenuStateUseCase,
 ) -> CwHandlerResult {
-    let group_name = msg.text().unwrap();
+    let Some(group_name) = msg.text() else {
+        bot.send_message(msg.chat.id, T.registration.invalid_group_name).await?;
+        return Ok(());
+    };
     log::info!("received group name for @{:?}: {}", msg.chat.username(), group_name);
 
-    let user = complete_registration_use_case.execute(
+    let result = complete_registration_use_case.execute(
         msg.chat.id.0,
         full_name.as_str(),
         group_name,
-    )
-        .await
-        .map_err(|err| CwBotError::Other(err.to_string()))?;
+    ).await;
+
+    let user = match result {
+        Ok(user) => user,
+        Err(DomainError::InvalidGroupName(_)) => {
+            bot.send_message(msg.chat.id, T.registration.invalid_group_name).await?;
+            return Ok(());
+        }
+        Err(DomainError::InvalidFullName(_)) => {
+            dialogue.update(CwDialogueState::AwaitingFullName).await?;
+            bot.send_message(msg.chat.id, T.registration.invalid_full_name).await?;
+            return Ok(());
+        }
+        Err(err) => return Err(CwBotError::Other(err.to_string())),
+    };
     
     log::info!("user @{:?} has completed registration: {:?}", msg.chat.username(), user);
 
@@ -0,0 +1,131 @@
+use teloxide::prelude::*;
+use teloxide::types::{KeyboardButton, KeyboardMarkup, KeyboardRemove};
+
+use crate::domain::error::DomainError;
+use crate::domain::use_cases::{GetMenuStateUseCase, SubmitReportUseCase};
+use crate::presentation::handlers::fsm::CwDialogueState;
+use crate::presentation::handlers::texts::T;
+use crate::presentation::handlers::user::send_menu;
+use crate::presentation::handlers::utils::{CwBotError, CwDialogue, CwHandlerResult};
+
+
+pub const SEND_REPORT_CALLBACK: &str = "user_task_send_report";
+
+pub async fn handle_send_report_callback(
+    bot: Bot,
+    q: CallbackQuery,
+    dialogue: CwDialogue,
+) -> CwHandlerResult {
+    bot.answer_callback_query(&q.id).await?;
+
+    bot.send_message(dialogue.chat_id(), T.task_report.prompt)
+        .reply_markup(report_keyboard())
+        .await?;
+
+    dialogue.update(CwDialogueState::AwaitingReport {
+        photo_file_ids: Vec::new(),
+        caption: None,
+    }).await?;
+
+    Ok(())
+}
+
+pub async fn receive_report(
+    bot: Bot,
+    msg: Message,
+    dialogue: CwDialogue,
+    use_case: SubmitReportUseCase,
+    get_menu_state_use_case: GetMenuStateUseCase,
+    (mut photo_file_ids, mut caption): (Vec<String>, Option<String>),
+) -> CwHandlerResult {
+    if let Some(photo) = msg.photo().and_then(|sizes| sizes.last()) {
+        photo_file_ids.push(photo.file.id.clone());
+        if let Some(text) = msg.caption() {
+            caption = Some(text.to_string());
+        }
+        dialogue.update(CwDialogueState::AwaitingReport { photo_file_ids, caption }).await?;
+        return Ok(());
+    }
+
+    let Some(text) = msg.text() else {
+        return Ok(());
+    };
+
+    match ReportCallback::try_from(text.to_string()) {
+        Ok(ReportCallback::Submit) => {
+            if photo_file_ids.is_empty() {
+                bot.send_message(msg.chat.id, T.task_report.no_photos).await?;
+                return Ok(());
+            }
+
+            let reply = match use_case.execute(msg.chat.id.0, photo_file_ids, caption).await {
+                Ok(()) => T.task_report.success,
+                Err(DomainError::InvalidStateChange(_)) => T.task_report.already_sent,
+                Err(err) => return Err(CwBotError::Other(err.to_string())),
+            };
+            log::info!("user {} has submitted a task report", msg.chat.id);
+
+            bot.send_message(msg.chat.id, reply)
+                .reply_markup(KeyboardRemove::new())
+                .await?;
+            dialogue.update(CwDialogueState::Idle).await?;
+            send_menu(bot, msg, get_menu_state_use_case).await
+        }
+        Ok(ReportCallback::Cancel) => {
+            bot.send_message(msg.chat.id, T.task_report.cancelled)
+                .reply_markup(KeyboardRemove::new())
+                .await?;
+            dialogue.update(CwDialogueState::Idle).await?;
+            send_menu(bot, msg, get_menu_state_use_case).await
+        }
+        Err(()) => {
+            caption = Some(text.to_string());
+            dialogue.update(CwDialogueState::AwaitingReport { photo_file_ids, caption }).await?;
+            bot.send_message(msg.chat.id, T.task_report.caption_saved).await?;
+            Ok(())
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum ReportCallback {
+    Submit,
+    Cancel,
+}
+
+impl From<ReportCallback> for String {
+    fn from(callback: ReportCallback) -> Self {
+        match callback {
+            ReportCallback::Submit => String::from(T.task_report.submit_button),
+            ReportCallback::Cancel => String::from(T.task_report.cancel_button),
+        }
+    }
+}
+
+impl From<ReportCallback> for KeyboardButton {
+    fn from(callback: ReportCallback) -> Self {
+        KeyboardButton::new(String::from(callback))
+    }
+}
+
+impl TryFrom<String> for ReportCallback {
+    type Error = ();
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            val if val == T.task_report.submit_button => Ok(ReportCallback::Submit),
+            val if val == T.task_report.cancel_button => Ok(ReportCallback::Cancel),
+            _ => Err(()),
+        }
+    }
+}
+
+fn report_keyboard() -> KeyboardMarkup {
+    KeyboardMarkup::new(vec![
+        vec![
+            ReportCallback::Submit.into(),
+            ReportCallback::Cancel.into(),
+        ],
+    ])
+        .resize_keyboard()
+}
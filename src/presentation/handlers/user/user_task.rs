@@ -1,8 +1,10 @@
 use teloxide::prelude::*;
-use teloxide::types::ParseMode;
-use crate::domain::use_cases::{GetUserTaskUseCase, GetMenuStateUseCase};
+use teloxide::types::{InlineKeyboardButton, InlineKeyboardMarkup, InputFile, ParseMode};
+use teloxide::RequestError;
+use crate::domain::use_cases::{GetUserTaskUseCase, GetMenuStateUseCase, TaskMediaDto, TaskReminderDto, UserTaskDto};
 use crate::presentation::handlers::texts::T;
 use crate::presentation::handlers::user::menu::send_menu_callback;
+use crate::presentation::handlers::user::SEND_REPORT_CALLBACK;
 use crate::presentation::handlers::utils::{CwBotError, CwDialogue, CwHandlerResult};
 
 
@@ -18,13 +20,82 @@ pub async fn handle_current_meeting_callback(
     let user_task = get_user_task_use_case.execute(dialogue.chat_id().0).await
         .map_err(|err| CwBotError::Other(err.to_string()))?;
 
-    bot.send_message(dialogue.chat_id(), T.user_task.user_task(
-        &user_task.partner_username,
+    let keyboard = user_task.can_send_report.then(|| InlineKeyboardMarkup::new(vec![
+        vec![
+            InlineKeyboardButton::callback(
+                T.task_report.send_button, SEND_REPORT_CALLBACK,
+            )
+        ]
+    ]));
+    send_user_task(&bot, dialogue.chat_id(), &user_task, keyboard).await?;
+
+    send_menu_callback(bot, q, get_menu_state_use_case).await
+}
+
+// Telegram limits media captions to 1024 characters
+const CAPTION_MAX_LEN: usize = 1024;
+
+/// Sends the task text along with its media. The text becomes the caption of a
+/// photo or a document if it fits, otherwise it follows the media as a message.
+pub async fn send_user_task(
+    bot: &Bot,
+    chat_id: ChatId,
+    user_task: &UserTaskDto,
+    keyboard: Option<InlineKeyboardMarkup>,
+) -> Result<(), RequestError> {
+    let text = T.user_task.user_task(
+        &user_task.partners,
         &user_task.title,
         &user_task.description,
-    ))
+    );
+    let fits_caption = text.chars().count() <= CAPTION_MAX_LEN;
+
+    match &user_task.media {
+        Some(TaskMediaDto::Photo(file_id)) if fits_caption => {
+            let mut request = bot.send_photo(chat_id, InputFile::file_id(file_id))
+                .caption(text)
+                .parse_mode(ParseMode::Html);
+            if let Some(keyboard) = keyboard {
+                request = request.reply_markup(keyboard);
+            }
+            request.await?;
+            return Ok(());
+        }
+        Some(TaskMediaDto::Document(file_id)) if fits_caption => {
+            let mut request = bot.send_document(chat_id, InputFile::file_id(file_id))
+                .caption(text)
+                .parse_mode(ParseMode::Html);
+            if let Some(keyboard) = keyboard {
+                request = request.reply_markup(keyboard);
+            }
+            request.await?;
+            return Ok(());
+        }
+        Some(TaskMediaDto::Photo(file_id)) => {
+            bot.send_photo(chat_id, InputFile::file_id(file_id)).await?;
+        }
+        Some(TaskMediaDto::Document(file_id)) => {
+            bot.send_document(chat_id, InputFile::file_id(file_id)).await?;
+        }
+        Some(TaskMediaDto::Location { latitude, longitude }) => {
+            bot.send_location(chat_id, *latitude, *longitude).await?;
+        }
+        None => {}
+    }
+
+    let mut request = bot.send_message(chat_id, text)
+        .parse_mode(ParseMode::Html);
+    if let Some(keyboard) = keyboard {
+        request = request.reply_markup(keyboard);
+    }
+    request.await?;
+    Ok(())
+}
+
+pub async fn send_task_reminder(bot: &Bot, reminder: &TaskReminderDto) -> Result<(), RequestError> {
+    let text = T.user_task.reminder(&reminder.partners, &reminder.title);
+    bot.send_message(ChatId(reminder.user_id), text)
         .parse_mode(ParseMode::Html)
         .await?;
-
-    send_menu_callback(bot, q, get_menu_state_use_case).await
+    Ok(())
 }
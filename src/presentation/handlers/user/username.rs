@@ -0,0 +1,19 @@
+use teloxide::prelude::*;
+
+use crate::domain::error::DomainError;
+use crate::domain::use_cases::SyncUsernameUseCase;
+
+
+/// Runs before every handler so that partner mentions never go stale.
+/// Failures are only logged, the update itself is always processed.
+pub async fn sync_username(update: Update, use_case: SyncUsernameUseCase) {
+    let Some(user) = update.from() else {
+        return;
+    };
+
+    match use_case.execute(user.id.0 as i64, user.username.as_deref()).await {
+        Ok(true) => log::info!("username of user {} has been updated to {:?}", user.id, user.username),
+        Ok(false) | Err(DomainError::UserNotFound(_)) => {},
+        Err(err) => log::warn!("failed to sync username of user {}: {}", user.id, err),
+    }
+}
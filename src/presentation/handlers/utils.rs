@@ -1,18 +1,17 @@
 use std::fmt::Debug;
-use teloxide::dispatching::dialogue::{InMemStorage, InMemStorageError};
+use teloxide::dispatching::dialogue::ErasedStorage;
 use teloxide::prelude::Dialogue;
 use teloxide::RequestError;
 
 use super::fsm::CwDialogueState;
 
-pub(crate) type CwDialogue = Dialogue<CwDialogueState, InMemStorage<CwDialogueState>>;
+pub(crate) type CwDialogue = Dialogue<CwDialogueState, ErasedStorage<CwDialogueState>>;
 pub(crate) type CwHandlerResult = Result<(), CwBotError>;
 
 
 #[derive(Debug)]
 pub enum CwBotError {
     Telegram(RequestError),
-    InMemStorage(InMemStorageError),
     External(Box<dyn std::error::Error + Send + Sync>),
     Other(String),
 }
@@ -28,9 +27,3 @@ impl From<Box<dyn std::error::Error + Send + Sync>> for CwBotError {
         CwBotError::External(e)
     }
 }
-
-impl From<InMemStorageError> for CwBotError {
-    fn from(e: InMemStorageError) -> Self {
-        CwBotError::InMemStorage(e)
-    }
-}
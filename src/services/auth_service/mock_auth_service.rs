@@ -1,27 +1,56 @@
+use std::collections::HashMap;
+use std::sync::RwLock;
+
 use crate::domain::error::DomainError;
 use crate::domain::interfaces::AuthService;
-use crate::domain::models::UserId;
+use crate::domain::models::{Admin, AdminRole, UserId};
 
 
 #[derive(Default)]
 pub struct MockAuthService {
-    pub admin_ids: Vec<UserId>,
+    admins: RwLock<HashMap<UserId, AdminRole>>,
 }
 
 #[async_trait::async_trait]
 impl AuthService for MockAuthService {
-    async fn is_admin(&self, user_id: UserId) -> Result<bool, DomainError> {
-        Ok(self.admin_ids.contains(&user_id))
+    async fn role(&self, user_id: UserId) -> Result<Option<AdminRole>, DomainError> {
+        Ok(self.admins.read().unwrap().get(&user_id).copied())
+    }
+
+    async fn admins(&self) -> Result<Vec<Admin>, DomainError> {
+        Ok(self.admins
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(&user_id, &role)| Admin::new(user_id, role))
+            .collect())
+    }
+
+    async fn grant(&self, admin: &Admin) -> Result<(), DomainError> {
+        self.admins.write().unwrap().insert(admin.user_id(), admin.role());
+        Ok(())
+    }
+
+    async fn revoke(&self, user_id: UserId) -> Result<(), DomainError> {
+        self.admins
+            .write()
+            .unwrap()
+            .remove(&user_id)
+            .map(|_| ())
+            .ok_or(DomainError::AdminNotFound(user_id))
     }
 }
 
 impl MockAuthService {
+    /// Every listed user becomes an owner
     pub fn with_admin_ids(admin_ids: impl IntoIterator<Item = impl Into<UserId>>) -> MockAuthService {
         MockAuthService {
-            admin_ids: admin_ids
-                .into_iter()
-                .map(|id| id.into())
-                .collect::<Vec<UserId>>(),
+            admins: RwLock::new(
+                admin_ids
+                    .into_iter()
+                    .map(|id| (id.into(), AdminRole::Owner))
+                    .collect(),
+            ),
         }
     }
 }
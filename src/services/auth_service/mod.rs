@@ -1,3 +1,5 @@
 mod mock_auth_service;
+mod postgres_auth_service;
 
 pub use mock_auth_service::MockAuthService;
+pub use postgres_auth_service::PostgresAuthService;
@@ -0,0 +1,271 @@
+use deadpool_postgres::Pool;
+use postgres_types::{FromSql, ToSql};
+use tokio_postgres::Row;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::AuthService;
+use crate::domain::models::{Admin, AdminRole, UserId};
+
+
+#[derive(Debug, Clone, ToSql, FromSql)]
+#[postgres(name = "admin_role")]
+enum AdminRoleModel {
+    #[postgres(name = "owner")]
+    Owner,
+
+    #[postgres(name = "admin")]
+    Admin,
+
+    #[postgres(name = "reviewer")]
+    Reviewer,
+}
+
+#[derive(Clone, Debug)]
+struct AdminModel {
+    user_id:    i64,    // PK
+    role:       AdminRoleModel,
+}
+
+/// Keeps admins and their roles in the `admins` table. Owners come from
+/// `ADMIN_IDS` through [`PostgresAuthService::seed_owners`], other roles are
+/// granted from the admin menu.
+pub struct PostgresAuthService {
+    pool: Pool,
+}
+
+impl PostgresAuthService {
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+
+    /// Makes every listed user an owner and removes owners which are no
+    /// longer listed, so `ADMIN_IDS` stays the only source of owners
+    pub async fn seed_owners(&self, owner_ids: &[UserId]) -> Result<(), DomainError> {
+        let mut client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let tr = client
+            .transaction()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let owner_ids: Vec<i64> = owner_ids.iter().map(|id| id.0).collect();
+
+        tr.execute(
+            "DELETE FROM admins WHERE role = 'owner' AND user_id <> ALL($1)",
+            &[&owner_ids],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        tr.execute(
+            r#"
+            INSERT INTO admins (user_id, role)
+            SELECT unnest($1::BIGINT[]), 'owner'
+            ON CONFLICT (user_id) DO UPDATE SET
+                role = EXCLUDED.role,
+                granted_at = now()
+            WHERE admins.role <> EXCLUDED.role
+            "#,
+            &[&owner_ids],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        tr.commit()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthService for PostgresAuthService {
+    async fn role(&self, user_id: UserId) -> Result<Option<AdminRole>, DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let row = client.query_opt(
+            "SELECT user_id, role FROM admins WHERE user_id = $1",
+            &[&user_id.0],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        Ok(row.map(|row| {
+            let admin: Admin = AdminModel::from(row).into();
+            admin.role()
+        }))
+    }
+
+    async fn admins(&self) -> Result<Vec<Admin>, DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let rows = client.query(
+            "SELECT user_id, role FROM admins ORDER BY role, granted_at",
+            &[],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| AdminModel::from(row).into())
+            .collect())
+    }
+
+    async fn grant(&self, admin: &Admin) -> Result<(), DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let model: AdminModel = admin.clone().into();
+
+        client.execute(
+            r#"
+            INSERT INTO admins (user_id, role)
+            VALUES ($1, $2)
+            ON CONFLICT (user_id) DO UPDATE SET
+                role = EXCLUDED.role,
+                granted_at = now()
+            "#,
+            &[&model.user_id, &model.role],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        Ok(())
+    }
+
+    async fn revoke(&self, user_id: UserId) -> Result<(), DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let deleted = client.execute(
+            "DELETE FROM admins WHERE user_id = $1",
+            &[&user_id.0],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        if deleted == 0 {
+            return Err(DomainError::AdminNotFound(user_id));
+        }
+
+        Ok(())
+    }
+}
+
+impl From<Admin> for AdminModel {
+    fn from(admin: Admin) -> Self {
+        Self {
+            user_id: admin.user_id().0,
+            role: match admin.role() {
+                AdminRole::Owner => AdminRoleModel::Owner,
+                AdminRole::Admin => AdminRoleModel::Admin,
+                AdminRole::Reviewer => AdminRoleModel::Reviewer,
+            },
+        }
+    }
+}
+
+impl From<AdminModel> for Admin {
+    fn from(model: AdminModel) -> Self {
+        Admin::new(
+            model.user_id,
+            match model.role {
+                AdminRoleModel::Owner => AdminRole::Owner,
+                AdminRoleModel::Admin => AdminRole::Admin,
+                AdminRoleModel::Reviewer => AdminRole::Reviewer,
+            },
+        )
+    }
+}
+
+impl From<Row> for AdminModel {
+    fn from(row: Row) -> Self {
+        Self {
+            user_id: row.get("user_id"),
+            role: row.get("role"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use rand::random;
+    use crate::utils::postgres::testing::test_db_setup;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_grant_and_revoke() {
+        let pool = test_db_setup().await;
+        let service = PostgresAuthService::new(pool);
+        let user_id = UserId(random());
+
+        assert_eq!(service.role(user_id).await.unwrap(), None);
+
+        service.grant(&Admin::new(user_id, AdminRole::Reviewer))
+            .await
+            .expect("failed to grant role");
+        assert_eq!(service.role(user_id).await.unwrap(), Some(AdminRole::Reviewer));
+
+        service.grant(&Admin::new(user_id, AdminRole::Admin))
+            .await
+            .expect("failed to change role");
+        assert_eq!(service.role(user_id).await.unwrap(), Some(AdminRole::Admin));
+
+        service.revoke(user_id)
+            .await
+            .expect("failed to revoke role");
+        assert_eq!(service.role(user_id).await.unwrap(), None);
+
+        let result = service.revoke(user_id).await;
+        assert!(matches!(result, Err(DomainError::AdminNotFound(id)) if id == user_id));
+    }
+
+    #[tokio::test]
+    async fn test_seed_owners() {
+        let pool = test_db_setup().await;
+        let service = PostgresAuthService::new(pool);
+        let owner_id = UserId(random());
+        let admin_id = UserId(random());
+
+        service.grant(&Admin::new(admin_id, AdminRole::Admin))
+            .await
+            .expect("failed to grant role");
+
+        service.seed_owners(&[owner_id, admin_id])
+            .await
+            .expect("failed to seed owners");
+
+        assert_eq!(service.role(owner_id).await.unwrap(), Some(AdminRole::Owner));
+        assert_eq!(service.role(admin_id).await.unwrap(), Some(AdminRole::Owner));
+
+        let admin_ids = service.admins()
+            .await
+            .expect("failed to get admins")
+            .iter()
+            .map(Admin::user_id)
+            .collect::<Vec<_>>();
+        assert!(admin_ids.contains(&owner_id) && admin_ids.contains(&admin_id));
+
+        service.seed_owners(&[owner_id])
+            .await
+            .expect("failed to seed owners");
+
+        assert_eq!(service.role(owner_id).await.unwrap(), Some(AdminRole::Owner));
+        assert_eq!(service.role(admin_id).await.unwrap(), None);
+    }
+}
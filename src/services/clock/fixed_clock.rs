@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Local, NaiveDate, TimeZone, Weekday};
+
+use crate::domain::interfaces::Clock;
+use crate::services::ChronoWeekService;
+
+
+pub struct FixedClock {
+    now: DateTime<Local>,
+}
+
+impl FixedClock {
+    pub fn new(now: DateTime<Local>) -> Self {
+        Self { now }
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Local> {
+        self.now
+    }
+}
+
+/// Week service frozen at noon of `weekday` of the ISO week
+pub fn week_service_at(year: i32, week: u32, weekday: Weekday) -> Arc<ChronoWeekService> {
+    let date = NaiveDate::from_isoywd_opt(year, week, weekday).unwrap();
+    let now = Local.from_local_datetime(&date.and_hms_opt(12, 0, 0).unwrap()).unwrap();
+    Arc::new(ChronoWeekService::new(Arc::new(FixedClock::new(now))))
+}
@@ -0,0 +1,7 @@
+mod system_clock;
+#[cfg(test)]
+mod fixed_clock;
+
+pub use system_clock::SystemClock;
+#[cfg(test)]
+pub use fixed_clock::{week_service_at, FixedClock};
@@ -0,0 +1,13 @@
+use chrono::{DateTime, Local};
+
+use crate::domain::interfaces::Clock;
+
+
+#[derive(Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Local> {
+        Local::now()
+    }
+}
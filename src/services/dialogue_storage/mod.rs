@@ -0,0 +1,3 @@
+mod postgres_dialogue_storage;
+
+pub use postgres_dialogue_storage::PostgresDialogueStorage;
@@ -0,0 +1,229 @@
+use std::sync::Arc;
+
+use deadpool_postgres::{Pool, PoolError};
+use futures::future::BoxFuture;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use teloxide::dispatching::dialogue::serializer::Json;
+use teloxide::dispatching::dialogue::{Serializer, Storage};
+use teloxide::types::ChatId;
+
+
+#[derive(Debug, thiserror::Error)]
+pub enum PostgresDialogueStorageError {
+    #[error("dialogue serialization error: {0}")]
+    Serialization(Box<dyn std::error::Error + Send + Sync>),
+
+    #[error("postgres pool error: {0}")]
+    Pool(#[from] PoolError),
+
+    #[error("postgres error: {0}")]
+    Postgres(#[from] tokio_postgres::Error),
+
+    #[error("dialogue for chat {0} not found")]
+    DialogueNotFound(ChatId),
+}
+
+/// Dialogue storage which keeps states serialized to JSON in the `dialogues`
+/// table (a BYTEA column), so that users don't lose their progress when the bot restarts
+pub struct PostgresDialogueStorage {
+    pool: Pool,
+}
+
+impl PostgresDialogueStorage {
+    pub fn new(pool: Pool) -> Arc<Self> {
+        Arc::new(Self { pool })
+    }
+}
+
+impl<D> Storage<D> for PostgresDialogueStorage
+where
+    D: Send + Serialize + DeserializeOwned + 'static,
+{
+    type Error = PostgresDialogueStorageError;
+
+    fn remove_dialogue(
+        self: Arc<Self>,
+        chat_id: ChatId,
+    ) -> BoxFuture<'static, Result<(), Self::Error>>
+    where
+        D: Send + 'static,
+    {
+        Box::pin(async move {
+            let client = self.pool.get().await?;
+
+            let deleted = client.execute(
+                "DELETE FROM dialogues WHERE chat_id = $1",
+                &[&chat_id.0],
+            ).await?;
+
+            if deleted == 0 {
+                return Err(PostgresDialogueStorageError::DialogueNotFound(chat_id));
+            }
+
+            Ok(())
+        })
+    }
+
+    fn update_dialogue(
+        self: Arc<Self>,
+        chat_id: ChatId,
+        dialogue: D,
+    ) -> BoxFuture<'static, Result<(), Self::Error>>
+    where
+        D: Send + 'static,
+    {
+        Box::pin(async move {
+            let data = Json.serialize(&dialogue)
+                .map_err(|err| PostgresDialogueStorageError::Serialization(err.into()))?;
+
+            let client = self.pool.get().await?;
+
+            client.execute(
+                r#"
+                INSERT INTO dialogues (chat_id, dialogue)
+                VALUES ($1, $2)
+                ON CONFLICT (chat_id) DO UPDATE SET
+                    dialogue = EXCLUDED.dialogue,
+                    updated_at = now()
+                "#,
+                &[&chat_id.0, &data],
+            ).await?;
+
+            Ok(())
+        })
+    }
+
+    fn get_dialogue(
+        self: Arc<Self>,
+        chat_id: ChatId,
+    ) -> BoxFuture<'static, Result<Option<D>, Self::Error>> {
+        Box::pin(async move {
+            let client = self.pool.get().await?;
+
+            let row = client.query_opt(
+                "SELECT dialogue FROM dialogues WHERE chat_id = $1",
+                &[&chat_id.0],
+            ).await?;
+
+            let Some(row) = row else {
+                return Ok(None);
+            };
+
+            let data: Vec<u8> = row.get("dialogue");
+            match Json.deserialize(&data) {
+                Ok(dialogue) => Ok(Some(dialogue)),
+                // States stored before their shape changed can't be read back,
+                // the chat starts over from the default state instead of getting stuck
+                Err(err) => {
+                    log::warn!("dropping dialogue of chat {} which can't be deserialized: {}", chat_id, err);
+                    client.execute(
+                        "DELETE FROM dialogues WHERE chat_id = $1",
+                        &[&chat_id.0],
+                    ).await?;
+                    Ok(None)
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use rand::random;
+    use crate::presentation::handlers::fsm::CwDialogueState;
+    use crate::utils::postgres::testing::test_db_setup;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_update_and_get_dialogue() {
+        let pool = test_db_setup().await;
+        let storage = PostgresDialogueStorage::new(pool);
+        let chat_id = ChatId(random());
+
+        let state = CwDialogueState::AwaitingGroupName { full_name: "Иванов Иван Иванович".to_string() };
+        storage.clone()
+            .update_dialogue(chat_id, state.clone())
+            .await
+            .expect("failed to update dialogue");
+
+        let retrieved: Option<CwDialogueState> = storage.clone()
+            .get_dialogue(chat_id)
+            .await
+            .expect("failed to get dialogue");
+
+        assert_eq!(retrieved, Some(state));
+
+        storage.clone()
+            .update_dialogue(chat_id, CwDialogueState::Idle)
+            .await
+            .expect("failed to update dialogue");
+
+        let retrieved: Option<CwDialogueState> = storage
+            .get_dialogue(chat_id)
+            .await
+            .expect("failed to get dialogue");
+
+        assert_eq!(retrieved, Some(CwDialogueState::Idle));
+    }
+
+    #[tokio::test]
+    async fn test_get_missing_dialogue() {
+        let pool = test_db_setup().await;
+        let storage = PostgresDialogueStorage::new(pool);
+
+        let retrieved: Option<CwDialogueState> = storage
+            .get_dialogue(ChatId(random()))
+            .await
+            .expect("failed to get dialogue");
+
+        assert_eq!(retrieved, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_outdated_dialogue() {
+        let pool = test_db_setup().await;
+        let storage = PostgresDialogueStorage::new(pool.clone());
+        let chat_id = ChatId(random());
+
+        let client = pool.get().await.expect("failed to get client");
+        client.execute(
+            "INSERT INTO dialogues (chat_id, dialogue) VALUES ($1, $2)",
+            &[&chat_id.0, &br#"{"RemovedState":{"field":1}}"#.to_vec()],
+        ).await.expect("failed to insert dialogue");
+
+        let retrieved: Option<CwDialogueState> = storage.clone()
+            .get_dialogue(chat_id)
+            .await
+            .expect("failed to get dialogue");
+        assert_eq!(retrieved, None);
+
+        let result = Storage::<CwDialogueState>::remove_dialogue(storage, chat_id).await;
+        assert!(matches!(result, Err(PostgresDialogueStorageError::DialogueNotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_remove_dialogue() {
+        let pool = test_db_setup().await;
+        let storage = PostgresDialogueStorage::new(pool);
+        let chat_id = ChatId(random());
+
+        storage.clone()
+            .update_dialogue(chat_id, CwDialogueState::Idle)
+            .await
+            .expect("failed to update dialogue");
+
+        Storage::<CwDialogueState>::remove_dialogue(storage.clone(), chat_id)
+            .await
+            .expect("failed to remove dialogue");
+
+        let retrieved: Option<CwDialogueState> = storage.clone()
+            .get_dialogue(chat_id)
+            .await
+            .expect("failed to get dialogue");
+        assert_eq!(retrieved, None);
+
+        let result = Storage::<CwDialogueState>::remove_dialogue(storage, chat_id).await;
+        assert!(matches!(result, Err(PostgresDialogueStorageError::DialogueNotFound(id)) if id == chat_id));
+    }
+}
@@ -1,9 +1,19 @@
 mod user_repository;
 mod auth_service;
 mod task_repository;
+mod report_repository;
 mod week_service;
+mod clock;
+mod unit_of_work;
+mod dialogue_storage;
+mod task_pool_repository;
 
 pub use user_repository::*;
 pub use auth_service::*;
 pub use task_repository::*;
+pub use report_repository::*;
 pub use week_service::*;
+pub use clock::*;
+pub use unit_of_work::*;
+pub use dialogue_storage::*;
+pub use task_pool_repository::*;
@@ -0,0 +1,59 @@
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{ReportRepository, Transaction};
+use crate::domain::models::{Report, TaskId, UserId, UserTaskState};
+use crate::services::{InMemoryTransaction, InMemoryUserRepository};
+
+
+#[derive(Default)]
+pub struct InMemoryReportRepository {
+    m: Arc<RwLock<HashMap<(UserId, TaskId), Report>>>,
+    user_repo: Option<Arc<InMemoryUserRepository>>,
+}
+
+impl InMemoryReportRepository {
+    /// Looks up the state of the reported tasks in `user_repo`, the way
+    /// reports are joined to `user_tasks` in PostgreSQL
+    pub fn with_user_repo(user_repo: Arc<InMemoryUserRepository>) -> Self {
+        Self { m: Arc::default(), user_repo: Some(user_repo) }
+    }
+}
+
+#[async_trait::async_trait]
+impl ReportRepository for InMemoryReportRepository {
+    async fn save_in(&self, tx: &mut dyn Transaction, report: &Report) -> Result<(), DomainError> {
+        let (m, report) = (self.m.clone(), report.clone());
+        InMemoryTransaction::from_dyn(tx)?
+            .stage(move || {
+                let mut guard = m.write().unwrap();
+                guard.insert((report.user_id(), report.task_id()), report);
+            });
+        Ok(())
+    }
+
+    async fn report(&self, user_id: UserId, task_id: TaskId) -> Result<Report, DomainError> {
+        let guard = self.m.read().unwrap();
+        if let Some(report) = guard.get(&(user_id, task_id)) {
+            Ok(report.clone())
+        } else {
+            Err(DomainError::ReportNotFound(user_id, task_id))
+        }
+    }
+
+    async fn awaiting_review(&self) -> Result<Vec<Report>, DomainError> {
+        let Some(user_repo) = self.user_repo.as_ref() else {
+            return Ok(Vec::new());
+        };
+        let guard = self.m.read().unwrap();
+        let mut reports: Vec<Report> = guard
+            .values()
+            .filter(|report| {
+                user_repo.task_state(report.user_id(), report.task_id()) == Some(UserTaskState::AwaitingReview)
+            })
+            .cloned()
+            .collect();
+        reports.sort_by_key(|report| (report.task_id(), report.user_id().0));
+        Ok(reports)
+    }
+}
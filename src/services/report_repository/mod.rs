@@ -0,0 +1,5 @@
+mod in_memory_report_repository;
+mod postgres_report_repository;
+
+pub use in_memory_report_repository::InMemoryReportRepository;
+pub use postgres_report_repository::PostgresReportRepository;
@@ -0,0 +1,280 @@
+use deadpool_postgres::{GenericClient, Pool};
+use tokio_postgres::Row;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{ReportRepository, Transaction};
+use crate::domain::models::{Report, TaskId, UserId};
+use crate::services::PostgresTransaction;
+
+
+#[derive(Clone, Debug)]
+struct ReportModel {
+    user_id:        i64,        // PK   FK (user_tasks)
+    task_year:      i32,        // PK   FK (user_tasks)
+    task_week:      i32,        // PK   FK (user_tasks)
+    photo_file_ids: Vec<String>,
+    caption:        Option<String>,
+}
+
+pub struct PostgresReportRepository {
+    pool: Pool,
+}
+
+impl PostgresReportRepository {
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl ReportRepository for PostgresReportRepository {
+    async fn save_in(&self, tx: &mut dyn Transaction, report: &Report) -> Result<(), DomainError> {
+        let tx = PostgresTransaction::from_dyn(tx)?;
+        Self::write_report(tx.client(), report).await
+    }
+
+    async fn report(&self, user_id: UserId, task_id: TaskId) -> Result<Report, DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let row = client.query_opt(
+            r#"
+            SELECT
+                user_id,
+                task_year,
+                task_week,
+                photo_file_ids,
+                caption
+            FROM task_reports
+            WHERE user_id = $1 AND task_year = $2 AND task_week = $3
+            "#,
+            &[
+                &user_id.0,
+                &task_id.year,
+                &(task_id.week as i32),
+            ],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let Some(row) = row else {
+            return Err(DomainError::ReportNotFound(user_id, task_id));
+        };
+
+        let model: ReportModel = row.into();
+
+        model.try_into()
+    }
+
+    async fn awaiting_review(&self) -> Result<Vec<Report>, DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let rows = client.query(
+            r#"
+            SELECT
+                r.user_id,
+                r.task_year,
+                r.task_week,
+                r.photo_file_ids,
+                r.caption
+            FROM task_reports r
+            JOIN user_tasks ut
+                ON ut.user_id = r.user_id
+                AND ut.task_year = r.task_year
+                AND ut.task_week = r.task_week
+            WHERE ut.state = 'awaiting_review'
+            ORDER BY r.task_year, r.task_week, r.submitted_at
+            "#,
+            &[],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        rows.into_iter()
+            .map(|row| ReportModel::from(row).try_into())
+            .collect()
+    }
+}
+
+impl PostgresReportRepository {
+    async fn write_report<C: GenericClient>(client: &C, report: &Report) -> Result<(), DomainError> {
+        let report_model: ReportModel = report.clone().into();
+
+        client.execute(
+            r#"
+            INSERT INTO task_reports (
+                user_id,
+                task_year,
+                task_week,
+                photo_file_ids,
+                caption
+            ) VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT (user_id, task_year, task_week) DO UPDATE SET
+                photo_file_ids = EXCLUDED.photo_file_ids,
+                caption = EXCLUDED.caption,
+                submitted_at = now()
+            "#,
+            &[
+                &report_model.user_id,
+                &report_model.task_year,
+                &report_model.task_week,
+                &report_model.photo_file_ids,
+                &report_model.caption,
+            ],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        Ok(())
+    }
+}
+
+impl From<Report> for ReportModel {
+    fn from(report: Report) -> Self {
+        Self {
+            user_id: report.user_id().0,
+            task_year: report.task_id().year,
+            task_week: report.task_id().week as i32,
+            photo_file_ids: report.photo_file_ids().to_vec(),
+            caption: report.caption().map(String::from),
+        }
+    }
+}
+
+impl TryInto<Report> for ReportModel {
+    type Error = DomainError;
+
+    fn try_into(self) -> Result<Report, Self::Error> {
+        Report::new(
+            self.user_id,
+            TaskId::new(self.task_year, self.task_week as u32),
+            self.photo_file_ids,
+            self.caption,
+        )
+    }
+}
+
+impl From<Row> for ReportModel {
+    fn from(row: Row) -> Self {
+        Self {
+            user_id: row.get("user_id"),
+            task_year: row.get("task_year"),
+            task_week: row.get("task_week"),
+            photo_file_ids: row.get("photo_file_ids"),
+            caption: row.get("caption"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use rand::{random, random_range};
+    use crate::domain::interfaces::{TaskRepository, UnitOfWork, UserRepository};
+    use crate::domain::models::{Task, User};
+    use crate::services::{PostgresTaskRepository, PostgresUnitOfWork, PostgresUserRepository};
+    use crate::utils::postgres::testing::test_db_setup;
+    use super::*;
+
+    async fn create_user_task(pool: &Pool) -> (UserId, TaskId) {
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let task_repo = PostgresTaskRepository::new(pool.clone());
+
+        let task_id = TaskId::new(random_range(1970..2025), random_range(1..52));
+        let _ = task_repo.save(&Task::new(task_id, "Test task", "Lorem ipsum")).await;
+
+        let partner = User::new(random(), None::<String>);
+        user_repo.save(&partner).await.expect("failed to save partner");
+
+        let id: i64 = random();
+        let mut user = User::new(id, Some(format!("user{}", id)));
+        user.accept().expect("failed to accept next task");
+        user.promote(&[partner.id()], task_id).expect("failed to promote task");
+        user_repo.save(&user).await.expect("failed to save user");
+
+        (user.id(), task_id)
+    }
+
+    async fn save_report(pool: &Pool, repo: &PostgresReportRepository, report: &Report) {
+        let mut tx = PostgresUnitOfWork::new(pool.clone())
+            .begin()
+            .await
+            .expect("failed to begin transaction");
+        repo.save_in(tx.as_mut(), report)
+            .await
+            .expect("failed to save report");
+        tx.commit()
+            .await
+            .expect("failed to commit transaction");
+    }
+
+    #[tokio::test]
+    async fn test_save_and_retrieve_report() {
+        let pool = test_db_setup().await;
+        let repo = PostgresReportRepository::new(pool.clone());
+        let (user_id, task_id) = create_user_task(&pool).await;
+
+        let report = Report::new(
+            user_id,
+            task_id,
+            vec!["photo-1".to_string(), "photo-2".to_string()],
+            Some("We did it".to_string()),
+        )
+            .expect("failed to create report");
+
+        save_report(&pool, &repo, &report).await;
+
+        let retrieved_report = repo.report(user_id, task_id)
+            .await
+            .expect("failed to retrieve report");
+
+        assert_eq!(retrieved_report, report);
+    }
+
+    #[tokio::test]
+    async fn test_resubmitted_report_replaces_previous() {
+        let pool = test_db_setup().await;
+        let repo = PostgresReportRepository::new(pool.clone());
+        let (user_id, task_id) = create_user_task(&pool).await;
+
+        let first = Report::new(user_id, task_id, vec!["photo-1".to_string()], None)
+            .expect("failed to create report");
+        let second = Report::new(user_id, task_id, vec!["photo-2".to_string()], Some("Again".to_string()))
+            .expect("failed to create report");
+
+        save_report(&pool, &repo, &first).await;
+        save_report(&pool, &repo, &second).await;
+
+        let retrieved_report = repo.report(user_id, task_id)
+            .await
+            .expect("failed to retrieve report");
+
+        assert_eq!(retrieved_report, second);
+    }
+
+    #[tokio::test]
+    async fn test_reports_awaiting_review() {
+        let pool = test_db_setup().await;
+        let repo = PostgresReportRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let (user_id, task_id) = create_user_task(&pool).await;
+
+        let report = Report::new(user_id, task_id, vec!["photo-1".to_string()], None)
+            .expect("failed to create report");
+        save_report(&pool, &repo, &report).await;
+
+        let pending = repo.awaiting_review().await.expect("failed to get reports awaiting review");
+        assert!(!pending.contains(&report), "report of an active task should not be awaiting review");
+
+        let mut user = user_repo.user(user_id).await.expect("failed to retrieve user");
+        user.submit_report().expect("failed to submit report");
+        user_repo.update(&mut user).await.expect("failed to update user");
+
+        let pending = repo.awaiting_review().await.expect("failed to get reports awaiting review");
+        assert!(pending.contains(&report));
+    }
+}
@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+use std::sync::{Arc, RwLock};
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{TaskPoolRepository, Transaction};
+use crate::domain::models::{NewPoolTask, PoolTask, PoolTaskId, WeekId};
+use crate::services::InMemoryTransaction;
+
+type PoolTasks = RwLock<BTreeMap<i64, (NewPoolTask, Option<WeekId>)>>;
+
+/// Keeps the tasks as they were added along with the week they are used in
+#[derive(Default)]
+pub struct InMemoryTaskPoolRepository {
+    m: Arc<PoolTasks>,
+}
+
+#[async_trait::async_trait]
+impl TaskPoolRepository for InMemoryTaskPoolRepository {
+    async fn add(&self, task: &NewPoolTask) -> Result<PoolTask, DomainError> {
+        let mut guard = self.m.write().unwrap();
+        let id = guard.keys().next_back().map_or(1, |id| id + 1);
+        guard.insert(id, (task.clone(), None));
+        Ok(PoolTask::restore(id, task.title(), task.description(), task.tags().to_vec(), None))
+    }
+
+    async fn all(&self) -> Result<Vec<PoolTask>, DomainError> {
+        let guard = self.m.read().unwrap();
+        Ok(guard
+            .iter()
+            .map(|(&id, (task, used_in))| {
+                PoolTask::restore(id, task.title(), task.description(), task.tags().to_vec(), *used_in)
+            })
+            .collect())
+    }
+
+    async fn available(&self, tag: Option<&str>) -> Result<Vec<PoolTask>, DomainError> {
+        Ok(self.all()
+            .await?
+            .into_iter()
+            .filter(|task| !task.is_used() && tag.is_none_or(|tag| task.tags().iter().any(|t| t == tag)))
+            .collect())
+    }
+
+    async fn available_in(&self, _tx: &mut dyn Transaction, tag: Option<&str>) -> Result<Vec<PoolTask>, DomainError> {
+        self.available(tag).await
+    }
+
+    async fn mark_used_in(&self, tx: &mut dyn Transaction, id: PoolTaskId, week_id: WeekId) -> Result<(), DomainError> {
+        match self.m.read().unwrap().get(&id.0) {
+            None => return Err(DomainError::PoolTaskNotFound(id)),
+            Some((_, Some(_))) => {
+                return Err(DomainError::InvalidStateChange(format!("pool task {id} is already used")));
+            }
+            Some(_) => {}
+        }
+
+        let m = self.m.clone();
+        InMemoryTransaction::from_dyn(tx)?
+            .stage(move || {
+                if let Some((_, used_in)) = m.write().unwrap().get_mut(&id.0) {
+                    *used_in = Some(week_id);
+                }
+            });
+        Ok(())
+    }
+
+    async fn reset_usage(&self) -> Result<u64, DomainError> {
+        let mut guard = self.m.write().unwrap();
+        let mut reset = 0;
+        for (_, used_in) in guard.values_mut().filter(|(_, used_in)| used_in.is_some()) {
+            *used_in = None;
+            reset += 1;
+        }
+        Ok(reset)
+    }
+}
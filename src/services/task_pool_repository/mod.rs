@@ -0,0 +1,5 @@
+mod in_memory_task_pool_repository;
+mod postgres_task_pool_repository;
+
+pub use in_memory_task_pool_repository::InMemoryTaskPoolRepository;
+pub use postgres_task_pool_repository::PostgresTaskPoolRepository;
@@ -0,0 +1,299 @@
+use deadpool_postgres::Pool;
+use tokio_postgres::Row;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{TaskPoolRepository, Transaction};
+use crate::domain::models::{NewPoolTask, PoolTask, PoolTaskId, WeekId};
+use crate::services::PostgresTransaction;
+
+
+#[derive(Clone, Debug)]
+struct PoolTaskModel {
+    id:          i64,   // PK
+    title:       String,
+    description: String,
+    tags:        Vec<String>,
+    used_year:   Option<i32>,
+    used_week:   Option<i32>,
+}
+
+pub struct PostgresTaskPoolRepository {
+    pool: Pool,
+}
+
+impl PostgresTaskPoolRepository {
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskPoolRepository for PostgresTaskPoolRepository {
+    async fn add(&self, task: &NewPoolTask) -> Result<PoolTask, DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let row = client.query_one(
+            r#"
+            INSERT INTO pool_tasks (
+                title,
+                description,
+                tags
+            ) VALUES ($1, $2, $3)
+            RETURNING id, title, description, tags, used_year, used_week
+            "#,
+            &[
+                &task.title(),
+                &task.description(),
+                &task.tags(),
+            ],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        Ok(PoolTaskModel::from(row).into())
+    }
+
+    async fn all(&self) -> Result<Vec<PoolTask>, DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let rows = client.query(
+            r#"
+            SELECT id, title, description, tags, used_year, used_week
+            FROM pool_tasks
+            ORDER BY id
+            "#,
+            &[],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| PoolTaskModel::from(row).into())
+            .collect())
+    }
+
+    async fn available(&self, tag: Option<&str>) -> Result<Vec<PoolTask>, DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let rows = client.query(
+            r#"
+            SELECT id, title, description, tags, used_year, used_week
+            FROM pool_tasks
+            WHERE used_year IS NULL AND ($1::TEXT IS NULL OR $1 = ANY(tags))
+            ORDER BY id
+            "#,
+            &[&tag],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| PoolTaskModel::from(row).into())
+            .collect())
+    }
+
+    async fn available_in(&self, tx: &mut dyn Transaction, tag: Option<&str>) -> Result<Vec<PoolTask>, DomainError> {
+        let tx = PostgresTransaction::from_dyn(tx)?;
+
+        let rows = tx.client().query(
+            r#"
+            SELECT id, title, description, tags, used_year, used_week
+            FROM pool_tasks
+            WHERE used_year IS NULL AND ($1::TEXT IS NULL OR $1 = ANY(tags))
+            ORDER BY id
+            FOR UPDATE SKIP LOCKED
+            "#,
+            &[&tag],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| PoolTaskModel::from(row).into())
+            .collect())
+    }
+
+    async fn mark_used_in(&self, tx: &mut dyn Transaction, id: PoolTaskId, week_id: WeekId) -> Result<(), DomainError> {
+        let tx = PostgresTransaction::from_dyn(tx)?;
+
+        let row = tx.client().query_opt(
+            "SELECT used_year FROM pool_tasks WHERE id = $1 FOR UPDATE",
+            &[&id.0],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let Some(row) = row else {
+            return Err(DomainError::PoolTaskNotFound(id));
+        };
+        if row.get::<_, Option<i32>>("used_year").is_some() {
+            return Err(DomainError::InvalidStateChange(format!("pool task {id} is already used")));
+        }
+
+        tx.client().execute(
+            "UPDATE pool_tasks SET used_year = $2, used_week = $3 WHERE id = $1",
+            &[
+                &id.0,
+                &week_id.year,
+                &(week_id.week as i32),
+            ],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        Ok(())
+    }
+
+    async fn reset_usage(&self) -> Result<u64, DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        client.execute(
+            "UPDATE pool_tasks SET used_year = NULL, used_week = NULL WHERE used_year IS NOT NULL",
+            &[],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))
+    }
+}
+
+impl From<PoolTaskModel> for PoolTask {
+    fn from(model: PoolTaskModel) -> Self {
+        let used_in = model.used_year
+            .zip(model.used_week)
+            .map(|(year, week)| WeekId::new(year, week as u32));
+        PoolTask::restore(model.id, model.title, model.description, model.tags, used_in)
+    }
+}
+
+impl From<Row> for PoolTaskModel {
+    fn from(row: Row) -> Self {
+        Self {
+            id: row.get("id"),
+            title: row.get("title"),
+            description: row.get("description"),
+            tags: row.get("tags"),
+            used_year: row.get("used_year"),
+            used_week: row.get("used_week"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use rand::random_range;
+
+    use crate::domain::interfaces::{TaskRepository, UnitOfWork};
+    use crate::domain::models::Task;
+    use crate::services::{PostgresTaskRepository, PostgresUnitOfWork};
+    use crate::utils::postgres::testing::test_db_setup;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_add_and_use_pool_task() {
+        let pool = test_db_setup().await;
+        let repo = PostgresTaskPoolRepository::new(pool.clone());
+        let task_repo = PostgresTaskRepository::new(pool.clone());
+        let uow = PostgresUnitOfWork::new(pool.clone());
+        let tag = format!("t{}", random_range(0..1_000_000_000));
+        let year = random_range(2100..1_000_000);
+
+        let new_task = NewPoolTask::new("Прогулка", "Погуляйте по набережной", &format!("#{tag} #прогулка"))
+            .expect("failed to create pool task");
+        let task = repo.add(&new_task)
+            .await
+            .expect("failed to add pool task");
+        assert_eq!(task.tags(), [tag.clone(), "прогулка".to_string()]);
+        assert!(!task.is_used());
+
+        let available = repo.available(Some(&tag)).await.expect("failed to get available tasks");
+        assert_eq!(available, vec![task.clone()]);
+
+        let mut tx = uow.begin().await.expect("failed to begin transaction");
+        task_repo.save_in(tx.as_mut(), &Task::new(WeekId::new(year, 12), "Прогулка", "Погуляйте по набережной"))
+            .await
+            .expect("failed to save task");
+        repo.mark_used_in(tx.as_mut(), task.id(), WeekId::new(year, 12))
+            .await
+            .expect("failed to mark task as used");
+        tx.commit().await.expect("failed to commit transaction");
+
+        assert!(repo.available(Some(&tag)).await.unwrap().is_empty());
+        let stored = repo.all().await.unwrap().into_iter().find(|t| t.id() == task.id()).unwrap();
+        assert_eq!(stored.used_in(), Some(WeekId::new(year, 12)));
+
+        let mut tx = uow.begin().await.expect("failed to begin transaction");
+        let result = repo.mark_used_in(tx.as_mut(), task.id(), WeekId::new(year, 13)).await;
+        assert!(matches!(result, Err(DomainError::InvalidStateChange(_))));
+        tx.rollback().await.expect("failed to rollback transaction");
+    }
+
+    #[tokio::test]
+    async fn test_deleted_task_releases_pool_task() {
+        let pool = test_db_setup().await;
+        let repo = PostgresTaskPoolRepository::new(pool.clone());
+        let task_repo = PostgresTaskRepository::new(pool.clone());
+        let uow = PostgresUnitOfWork::new(pool.clone());
+        let tag = format!("t{}", random_range(0..1_000_000_000));
+        let week_id = WeekId::new(random_range(2100..1_000_000), 7);
+
+        let new_task = NewPoolTask::new("Музей", "Сходите в музей", &format!("#{tag}"))
+            .expect("failed to create pool task");
+        let pool_task = repo.add(&new_task).await.expect("failed to add pool task");
+
+        let mut tx = uow.begin().await.expect("failed to begin transaction");
+        task_repo.save_in(tx.as_mut(), &Task::new(week_id, "Музей", "Сходите в музей"))
+            .await
+            .expect("failed to save task");
+        repo.mark_used_in(tx.as_mut(), pool_task.id(), week_id)
+            .await
+            .expect("failed to mark task as used");
+        tx.commit().await.expect("failed to commit transaction");
+        assert!(repo.available(Some(&tag)).await.unwrap().is_empty());
+
+        task_repo.delete(week_id).await.expect("failed to delete task");
+
+        assert_eq!(repo.available(Some(&tag)).await.unwrap(), vec![pool_task]);
+    }
+
+    #[tokio::test]
+    async fn test_available_in_skips_locked_tasks() {
+        let pool = test_db_setup().await;
+        let repo = PostgresTaskPoolRepository::new(pool.clone());
+        let uow = PostgresUnitOfWork::new(pool.clone());
+        let tag = format!("t{}", random_range(0..1_000_000_000));
+
+        let new_task = NewPoolTask::new("Кино", "Сходите в кино", &format!("#{tag}"))
+            .expect("failed to create pool task");
+        let task = repo.add(&new_task).await.expect("failed to add pool task");
+
+        let mut first = uow.begin().await.expect("failed to begin transaction");
+        let available = repo.available_in(first.as_mut(), Some(&tag))
+            .await
+            .expect("failed to get available tasks");
+        assert_eq!(available, vec![task.clone()]);
+
+        let mut second = uow.begin().await.expect("failed to begin transaction");
+        let available = repo.available_in(second.as_mut(), Some(&tag))
+            .await
+            .expect("failed to get available tasks");
+        assert!(available.is_empty(), "task locked by another transaction should be skipped");
+
+        second.rollback().await.expect("failed to rollback transaction");
+        first.rollback().await.expect("failed to rollback transaction");
+    }
+}
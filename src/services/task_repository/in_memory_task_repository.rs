@@ -1,13 +1,24 @@
 use std::collections::HashMap;
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
 use crate::domain::error::DomainError;
-use crate::domain::interfaces::TaskRepository;
-use crate::domain::models::{Task, TaskId};
+use crate::domain::interfaces::{TaskRepository, Transaction};
+use crate::domain::models::{NewTaskVariant, Task, TaskId, TaskVariant};
+use crate::services::{InMemoryTransaction, InMemoryUserRepository};
 
 
 #[derive(Default)]
 pub struct InMemoryTaskRepository {
-    m: RwLock<HashMap<TaskId, Task>>,
+    m: Arc<RwLock<HashMap<TaskId, Task>>>,
+    variants: RwLock<HashMap<TaskId, Vec<TaskVariant>>>,
+    user_repo: Option<Arc<InMemoryUserRepository>>,
+}
+
+impl InMemoryTaskRepository {
+    /// Refuses to update or delete tasks assigned to users of `user_repo`,
+    /// the way the `user_tasks` foreign key does in PostgreSQL
+    pub fn with_user_repo(user_repo: Arc<InMemoryUserRepository>) -> Self {
+        Self { m: Arc::default(), variants: RwLock::default(), user_repo: Some(user_repo) }
+    }
 }
 
 #[async_trait::async_trait]
@@ -18,6 +29,18 @@ impl TaskRepository for InMemoryTaskRepository {
         Ok(())
     }
 
+    async fn save_in(&self, tx: &mut dyn Transaction, task: &Task) -> Result<(), DomainError> {
+        if self.m.read().unwrap().contains_key(&task.id()) {
+            return Err(DomainError::TaskAlreadyExists(task.id()));
+        }
+        let (m, task) = (self.m.clone(), task.clone());
+        InMemoryTransaction::from_dyn(tx)?
+            .stage(move || {
+                m.write().unwrap().insert(task.id(), task);
+            });
+        Ok(())
+    }
+
     async fn task(&self, id: TaskId) -> Result<Task, DomainError> {
         let guard = self.m.read().unwrap();
         if let Some(task) = guard.get(&id) {
@@ -26,4 +49,156 @@ impl TaskRepository for InMemoryTaskRepository {
             Err(DomainError::TaskNotFound(id))
         }
     }
+
+    async fn list(&self) -> Result<Vec<Task>, DomainError> {
+        let guard = self.m.read().unwrap();
+        let mut tasks = guard.values().cloned().collect::<Vec<_>>();
+        tasks.sort_by_key(|task| std::cmp::Reverse(task.id()));
+        Ok(tasks)
+    }
+
+    async fn tasks_between(&self, from: TaskId, to: TaskId) -> Result<Vec<Task>, DomainError> {
+        let guard = self.m.read().unwrap();
+        let mut tasks = guard
+            .values()
+            .filter(|task| (from..=to).contains(&task.id()))
+            .cloned()
+            .collect::<Vec<_>>();
+        tasks.sort_by_key(|task| task.id());
+        Ok(tasks)
+    }
+
+    async fn update(&self, task: &Task) -> Result<(), DomainError> {
+        if self.user_repo.as_ref().is_some_and(|user_repo| user_repo.is_task_assigned(task.id())) {
+            return Err(DomainError::TaskInUse(task.id()));
+        }
+
+        let mut guard = self.m.write().unwrap();
+        match guard.get_mut(&task.id()) {
+            Some(stored) => {
+                *stored = task.clone();
+                Ok(())
+            }
+            None => Err(DomainError::TaskNotFound(task.id())),
+        }
+    }
+
+    async fn delete(&self, id: TaskId) -> Result<(), DomainError> {
+        if self.user_repo.as_ref().is_some_and(|user_repo| user_repo.is_task_assigned(id)) {
+            return Err(DomainError::TaskInUse(id));
+        }
+
+        let mut guard = self.m.write().unwrap();
+        guard.remove(&id)
+            .map(|_| ())
+            .ok_or(DomainError::TaskNotFound(id))?;
+        self.variants.write().unwrap().remove(&id);
+        Ok(())
+    }
+
+    async fn variants(&self, id: TaskId) -> Result<Vec<TaskVariant>, DomainError> {
+        let guard = self.variants.read().unwrap();
+        Ok(guard.get(&id).cloned().unwrap_or_default())
+    }
+
+    async fn add_variant(&self, id: TaskId, variant: &NewTaskVariant) -> Result<TaskVariant, DomainError> {
+        if !self.m.read().unwrap().contains_key(&id) {
+            return Err(DomainError::TaskNotFound(id));
+        }
+
+        let mut guard = self.variants.write().unwrap();
+        let variants = guard.entry(id).or_default();
+        let number = variants.last().map_or(1, |variant| variant.number() + 1);
+        let variant = TaskVariant::new(number, variant.title(), variant.description());
+        variants.push(variant.clone());
+        Ok(variant)
+    }
+
+    async fn delete_variant(&self, id: TaskId, number: u32) -> Result<(), DomainError> {
+        if self.user_repo.as_ref().is_some_and(|user_repo| user_repo.is_variant_assigned(id, number)) {
+            return Err(DomainError::TaskInUse(id));
+        }
+
+        let mut guard = self.variants.write().unwrap();
+        let variants = guard.entry(id).or_default();
+        let len = variants.len();
+        variants.retain(|variant| variant.number() != number);
+        if variants.len() == len {
+            return Err(DomainError::TaskVariantNotFound(id, number));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::domain::interfaces::UserRepository;
+    use crate::domain::models::{User, UserId};
+    use super::*;
+
+    #[tokio::test]
+    async fn test_list_latest_first() {
+        let repo = InMemoryTaskRepository::default();
+        for id in [TaskId::new(2025, 3), TaskId::new(2026, 1), TaskId::new(2025, 40)] {
+            repo.save(&Task::new(id, "Title", "Lorem ipsum")).await.unwrap();
+        }
+
+        let ids = repo.list().await.unwrap().iter().map(|task| task.id()).collect::<Vec<_>>();
+
+        assert_eq!(ids, vec![TaskId::new(2026, 1), TaskId::new(2025, 40), TaskId::new(2025, 3)]);
+    }
+
+    #[tokio::test]
+    async fn test_update_task() {
+        let repo = InMemoryTaskRepository::default();
+        let task = Task::new(TaskId::new(2025, 3), "Tpyo", "Lorem ipsum");
+
+        let result = repo.update(&task).await;
+        assert!(matches!(result, Err(DomainError::TaskNotFound(_))));
+
+        repo.save(&task).await.unwrap();
+        let fixed = Task::new(task.id(), "Typo", "Lorem ipsum");
+        repo.update(&fixed).await.expect("failed to update task");
+
+        assert_eq!(repo.task(task.id()).await.unwrap(), fixed);
+    }
+
+    #[tokio::test]
+    async fn test_update_assigned_task() {
+        let user_repo = Arc::new(InMemoryUserRepository::default());
+        let repo = InMemoryTaskRepository::with_user_repo(user_repo.clone());
+        let assigned = Task::new(TaskId::new(2025, 4), "Assigned", "Lorem ipsum");
+        repo.save(&assigned).await.unwrap();
+
+        let mut user = User::new(1, Some("alice"));
+        user.accept().unwrap();
+        user.promote(&[UserId(2)], assigned.id()).unwrap();
+        user_repo.save(&user).await.unwrap();
+
+        let result = repo.update(&Task::new(assigned.id(), "Changed", "Lorem ipsum")).await;
+        assert!(matches!(result, Err(DomainError::TaskInUse(_))));
+        assert_eq!(repo.task(assigned.id()).await.unwrap(), assigned);
+    }
+
+    #[tokio::test]
+    async fn test_delete_assigned_task() {
+        let user_repo = Arc::new(InMemoryUserRepository::default());
+        let repo = InMemoryTaskRepository::with_user_repo(user_repo.clone());
+        let free = Task::new(TaskId::new(2025, 3), "Free", "Lorem ipsum");
+        let assigned = Task::new(TaskId::new(2025, 4), "Assigned", "Lorem ipsum");
+        repo.save(&free).await.unwrap();
+        repo.save(&assigned).await.unwrap();
+
+        let mut user = User::new(1, Some("alice"));
+        user.accept().unwrap();
+        user.promote(&[UserId(2)], assigned.id()).unwrap();
+        user_repo.save(&user).await.unwrap();
+
+        repo.delete(free.id()).await.expect("failed to delete task");
+        assert!(matches!(repo.delete(free.id()).await, Err(DomainError::TaskNotFound(_))));
+
+        let result = repo.delete(assigned.id()).await;
+        assert!(matches!(result, Err(DomainError::TaskInUse(id)) if id == assigned.id()));
+        assert!(repo.task(assigned.id()).await.is_ok());
+    }
 }
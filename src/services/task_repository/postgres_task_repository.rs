@@ -1,20 +1,46 @@
 use deadpool_postgres::{GenericClient, Pool};
 use tokio_postgres::Row;
+use postgres_types::{FromSql, ToSql};
 
 use crate::domain::error::DomainError;
-use crate::domain::interfaces::TaskRepository;
-use crate::domain::models::{Task, TaskId};
+use crate::domain::interfaces::{TaskRepository, Transaction};
+use crate::domain::models::{NewTaskVariant, Task, TaskId, TaskMedia, TaskVariant};
+use crate::services::PostgresTransaction;
 use crate::utils::postgres::helpers;
 
 
+#[derive(Debug, Clone, ToSql, FromSql)]
+#[postgres(name = "task_media_kind")]
+enum TaskMediaKindModel {
+    #[postgres(name = "photo")]
+    Photo,
+
+    #[postgres(name = "document")]
+    Document,
+
+    #[postgres(name = "location")]
+    Location,
+}
+
 #[derive(Clone, Debug)]
-struct TaskModel {
-    pub year:        i32,
-    pub week:        i32,
+struct TaskVariantModel {
+    pub number:      i32,
     pub title:       String,
     pub description: String,
 }
 
+#[derive(Clone, Debug)]
+struct TaskModel {
+    pub year:            i32,
+    pub week:            i32,
+    pub title:           String,
+    pub description:     String,
+    pub media_kind:      Option<TaskMediaKindModel>,
+    pub media_file_id:   Option<String>,
+    pub media_latitude:  Option<f64>,
+    pub media_longitude: Option<f64>,
+}
+
 pub struct PostgresTaskRepository {
     pool: Pool,
 }
@@ -33,51 +59,216 @@ impl TaskRepository for PostgresTaskRepository {
             .await
             .map_err(|err| DomainError::Other(err.into()))?;
 
-        let task_model: TaskModel = task.clone().into();
+        Self::insert_task(&client, task).await
+    }
 
-        client.execute(
+    async fn save_in(&self, tx: &mut dyn Transaction, task: &Task) -> Result<(), DomainError> {
+        let tx = PostgresTransaction::from_dyn(tx)?;
+        Self::insert_task(tx.client(), task).await
+    }
+
+    async fn task(&self, id: TaskId) -> Result<Task, DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let row = client.query_opt(
             r#"
-            INSERT INTO tasks (
+            SELECT
                 year,
                 week,
                 title,
-                description
-            ) VALUES ($1, $2, $3, $4)
+                description,
+                media_kind,
+                media_file_id,
+                media_latitude,
+                media_longitude
+            FROM tasks
+            WHERE year = $1 AND week = $2
+            "#,
+            &[
+                &id.year,
+                &(id.week as i32),
+            ],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let Some(row) = row else {
+            return Err(DomainError::TaskNotFound(id));
+        };
+
+        let model: TaskModel = row.into();
+
+        Ok(model.into())
+    }
+
+    async fn list(&self) -> Result<Vec<Task>, DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let rows = client.query(
+            r#"
+            SELECT
+                year,
+                week,
+                title,
+                description,
+                media_kind,
+                media_file_id,
+                media_latitude,
+                media_longitude
+            FROM tasks
+            ORDER BY year DESC, week DESC
+            "#,
+            &[],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| TaskModel::from(row).into())
+            .collect())
+    }
+
+    async fn tasks_between(&self, from: TaskId, to: TaskId) -> Result<Vec<Task>, DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let rows = client.query(
+            r#"
+            SELECT
+                year,
+                week,
+                title,
+                description,
+                media_kind,
+                media_file_id,
+                media_latitude,
+                media_longitude
+            FROM tasks
+            WHERE (year, week) >= ($1, $2) AND (year, week) <= ($3, $4)
+            ORDER BY year, week
+            "#,
+            &[
+                &from.year,
+                &(from.week as i32),
+                &to.year,
+                &(to.week as i32),
+            ],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| TaskModel::from(row).into())
+            .collect())
+    }
+
+    async fn update(&self, task: &Task) -> Result<(), DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let task_model: TaskModel = task.clone().into();
+
+        let updated = client.execute(
+            r#"
+            UPDATE tasks SET
+                title = $3,
+                description = $4,
+                media_kind = $5,
+                media_file_id = $6,
+                media_latitude = $7,
+                media_longitude = $8
+            WHERE year = $1 AND week = $2
+              AND NOT EXISTS (SELECT 1 FROM user_tasks WHERE task_year = $1 AND task_week = $2)
             "#,
             &[
                 &task_model.year,
                 &task_model.week,
                 &task_model.title,
                 &task_model.description,
+                &task_model.media_kind,
+                &task_model.media_file_id,
+                &task_model.media_latitude,
+                &task_model.media_longitude,
+            ],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        if updated == 0 {
+            let exists = client.query_opt(
+                "SELECT 1 FROM tasks WHERE year = $1 AND week = $2",
+                &[&task_model.year, &task_model.week],
+            )
+                .await
+                .map_err(|err| DomainError::Other(err.into()))?
+                .is_some();
+
+            return Err(if exists {
+                DomainError::TaskInUse(task.id())
+            } else {
+                DomainError::TaskNotFound(task.id())
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: TaskId) -> Result<(), DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let deleted = client.execute(
+            "DELETE FROM tasks WHERE year = $1 AND week = $2",
+            &[
+                &id.year,
+                &(id.week as i32),
             ],
         )
             .await
             .map_err(|err| {
-                if helpers::is_unique_violation(&err) {
-                    DomainError::TaskAlreadyExists(task.id())
+                if helpers::is_foreign_key_violation(&err) {
+                    DomainError::TaskInUse(id)
                 } else {
                     DomainError::Other(err.into())
                 }
             })?;
 
+        if deleted == 0 {
+            return Err(DomainError::TaskNotFound(id));
+        }
+
         Ok(())
     }
 
-    async fn task(&self, id: TaskId) -> Result<Task, DomainError> {
+    async fn variants(&self, id: TaskId) -> Result<Vec<TaskVariant>, DomainError> {
         let client = self.pool
             .get()
             .await
             .map_err(|err| DomainError::Other(err.into()))?;
 
-        let row = client.query_opt(
+        let rows = client.query(
             r#"
             SELECT
-                year,
-                week,
+                number,
                 title,
                 description
-            FROM tasks
-            WHERE year = $1 AND week = $2
+            FROM task_variants
+            WHERE task_year = $1 AND task_week = $2
+            ORDER BY number
             "#,
             &[
                 &id.year,
@@ -87,34 +278,177 @@ impl TaskRepository for PostgresTaskRepository {
             .await
             .map_err(|err| DomainError::Other(err.into()))?;
 
-        let Some(row) = row else {
+        Ok(rows
+            .into_iter()
+            .map(|row| TaskVariantModel::from(row).into())
+            .collect())
+    }
+
+    async fn add_variant(&self, id: TaskId, variant: &NewTaskVariant) -> Result<TaskVariant, DomainError> {
+        let mut client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let tr = client
+            .transaction()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        // Concurrent additions to the same task wait here, so that they don't pick the same number
+        let task = tr.query_opt(
+            "SELECT 1 FROM tasks WHERE year = $1 AND week = $2 FOR UPDATE",
+            &[&id.year, &(id.week as i32)],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+        if task.is_none() {
             return Err(DomainError::TaskNotFound(id));
-        };
+        }
 
-        let model: TaskModel = row.into();
+        let row = tr.query_one(
+            r#"
+            INSERT INTO task_variants (
+                task_year,
+                task_week,
+                number,
+                title,
+                description
+            )
+            SELECT $1, $2, COALESCE(MAX(number), 0) + 1, $3, $4
+            FROM task_variants
+            WHERE task_year = $1 AND task_week = $2
+            RETURNING number, title, description
+            "#,
+            &[
+                &id.year,
+                &(id.week as i32),
+                &variant.title(),
+                &variant.description(),
+            ],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
 
-        Ok(model.into())
+        tr.commit()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        Ok(TaskVariantModel::from(row).into())
+    }
+
+    async fn delete_variant(&self, id: TaskId, number: u32) -> Result<(), DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let deleted = client.execute(
+            "DELETE FROM task_variants WHERE task_year = $1 AND task_week = $2 AND number = $3",
+            &[
+                &id.year,
+                &(id.week as i32),
+                &(number as i32),
+            ],
+        )
+            .await
+            .map_err(|err| {
+                if helpers::is_foreign_key_violation(&err) {
+                    DomainError::TaskInUse(id)
+                } else {
+                    DomainError::Other(err.into())
+                }
+            })?;
+
+        if deleted == 0 {
+            return Err(DomainError::TaskVariantNotFound(id, number));
+        }
+
+        Ok(())
+    }
+}
+
+impl PostgresTaskRepository {
+    async fn insert_task<C: GenericClient>(client: &C, task: &Task) -> Result<(), DomainError> {
+        let task_model: TaskModel = task.clone().into();
+
+        client.execute(
+            r#"
+            INSERT INTO tasks (
+                year,
+                week,
+                title,
+                description,
+                media_kind,
+                media_file_id,
+                media_latitude,
+                media_longitude
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            &[
+                &task_model.year,
+                &task_model.week,
+                &task_model.title,
+                &task_model.description,
+                &task_model.media_kind,
+                &task_model.media_file_id,
+                &task_model.media_latitude,
+                &task_model.media_longitude,
+            ],
+        )
+            .await
+            .map_err(|err| {
+                if helpers::is_unique_violation(&err) {
+                    DomainError::TaskAlreadyExists(task.id())
+                } else {
+                    DomainError::Other(err.into())
+                }
+            })?;
+
+        Ok(())
     }
 }
 
 impl From<Task> for TaskModel {
     fn from(task: Task) -> Self {
+        let (media_kind, media_file_id, media_latitude, media_longitude) = match task.media() {
+            None => (None, None, None, None),
+            Some(TaskMedia::Photo { file_id }) => (Some(TaskMediaKindModel::Photo), Some(file_id.clone()), None, None),
+            Some(TaskMedia::Document { file_id }) => (Some(TaskMediaKindModel::Document), Some(file_id.clone()), None, None),
+            Some(TaskMedia::Location { latitude, longitude }) => {
+                (Some(TaskMediaKindModel::Location), None, Some(*latitude), Some(*longitude))
+            }
+        };
         Self {
             year: task.id().year,
             week: task.id().week as i32,
             title: task.title().to_string(),
             description: task.description().to_string(),
+            media_kind,
+            media_file_id,
+            media_latitude,
+            media_longitude,
         }
     }
 }
 
 impl Into<Task> for TaskModel {
     fn into(self) -> Task {
+        // The columns are kept consistent by the `tasks_media_check` constraint
+        let media = match (self.media_kind, self.media_file_id, self.media_latitude, self.media_longitude) {
+            (Some(TaskMediaKindModel::Photo), Some(file_id), _, _) => Some(TaskMedia::Photo { file_id }),
+            (Some(TaskMediaKindModel::Document), Some(file_id), _, _) => Some(TaskMedia::Document { file_id }),
+            (Some(TaskMediaKindModel::Location), _, Some(latitude), Some(longitude)) => {
+                Some(TaskMedia::Location { latitude, longitude })
+            }
+            _ => None,
+        };
         Task::new(
             TaskId::new(self.year, self.week as u32),
             self.title,
             self.description,
         )
+            .with_media(media)
     }
 }
 
@@ -125,6 +459,30 @@ impl From<Row> for TaskModel {
             week: row.get("week"),
             title: row.get("title"),
             description: row.get("description"),
+            media_kind: row.get("media_kind"),
+            media_file_id: row.get("media_file_id"),
+            media_latitude: row.get("media_latitude"),
+            media_longitude: row.get("media_longitude"),
+        }
+    }
+}
+
+impl From<TaskVariantModel> for TaskVariant {
+    fn from(model: TaskVariantModel) -> Self {
+        TaskVariant::new(
+            model.number as u32,
+            model.title,
+            model.description,
+        )
+    }
+}
+
+impl From<Row> for TaskVariantModel {
+    fn from(row: Row) -> Self {
+        Self {
+            number: row.get("number"),
+            title: row.get("title"),
+            description: row.get("description"),
         }
     }
 }
@@ -132,6 +490,9 @@ impl From<Row> for TaskModel {
 #[cfg(test)]
 mod integration_tests {
     use rand::{random, random_range};
+    use crate::domain::interfaces::{UnitOfWork, UserRepository};
+    use crate::domain::models::User;
+    use crate::services::{PostgresUnitOfWork, PostgresUserRepository};
     use crate::utils::postgres::testing::test_db_setup;
     use super::*;
 
@@ -199,5 +560,193 @@ mod integration_tests {
             Err(e) => panic!("expected TaskAlreadyExists error, but got different error: {:?}", e),
         }
     }
-}
 
+    #[tokio::test]
+    async fn test_task_media() {
+        let pool = test_db_setup().await;
+        let repo = PostgresTaskRepository::new(pool.clone());
+        let year = random_range(2100..1_000_000);
+
+        let photo = Task::new(TaskId::new(year, 1), "Photo", "Lorem ipsum")
+            .with_media(Some(TaskMedia::Photo { file_id: "AgACAgIAAxkBAAIB".to_string() }));
+        let location = Task::new(TaskId::new(year, 2), "Location", "Lorem ipsum")
+            .with_media(Some(TaskMedia::Location { latitude: 55.765_9, longitude: 37.685_3 }));
+        repo.save(&photo).await.expect("failed to save task");
+        repo.save(&location).await.expect("failed to save task");
+
+        assert_eq!(repo.task(photo.id()).await.expect("failed to retrieve task"), photo);
+        assert_eq!(repo.task(location.id()).await.expect("failed to retrieve task"), location);
+
+        let document = Task::new(photo.id(), "Document", "Lorem ipsum")
+            .with_media(Some(TaskMedia::Document { file_id: "BQACAgIAAxkBAAIC".to_string() }));
+        repo.update(&document).await.expect("failed to update task");
+        assert_eq!(repo.task(photo.id()).await.expect("failed to retrieve task"), document);
+
+        let without_media = Task::new(location.id(), "Location", "Lorem ipsum");
+        repo.update(&without_media).await.expect("failed to update task");
+        assert_eq!(repo.task(location.id()).await.expect("failed to retrieve task"), without_media);
+    }
+
+    #[tokio::test]
+    async fn test_list_tasks() {
+        let pool = test_db_setup().await;
+        let repo = PostgresTaskRepository::new(pool.clone());
+        let year = random_range(2100..1_000_000);
+
+        let earlier = Task::new(TaskId::new(year, 1), "Earlier", "Lorem ipsum");
+        let later = Task::new(TaskId::new(year, 2), "Later", "Lorem ipsum");
+        repo.save(&earlier).await.expect("failed to save task");
+        repo.save(&later).await.expect("failed to save task");
+
+        let tasks = repo.list()
+            .await
+            .expect("failed to list tasks");
+
+        let earlier_pos = tasks.iter().position(|task| *task == earlier).expect("earlier task is missing");
+        let later_pos = tasks.iter().position(|task| *task == later).expect("later task is missing");
+        assert!(later_pos < earlier_pos, "latest tasks should come first");
+    }
+
+    #[tokio::test]
+    async fn test_tasks_between() {
+        let pool = test_db_setup().await;
+        let repo = PostgresTaskRepository::new(pool.clone());
+        let year = random_range(2100..1_000_000);
+
+        for week in [10, 11, 13, 14] {
+            repo.save(&Task::new(TaskId::new(year, week), format!("Task #{week}"), "Lorem ipsum"))
+                .await
+                .expect("failed to save task");
+        }
+
+        let tasks = repo.tasks_between(TaskId::new(year, 11), TaskId::new(year, 13))
+            .await
+            .expect("failed to list tasks");
+
+        let ids = tasks.iter().map(|task| task.id()).collect::<Vec<_>>();
+        assert_eq!(ids, vec![TaskId::new(year, 11), TaskId::new(year, 13)]);
+    }
+
+    #[tokio::test]
+    async fn test_update_task() {
+        let pool = test_db_setup().await;
+        let repo = PostgresTaskRepository::new(pool.clone());
+        let task = Task::new(TaskId::new(random_range(2100..1_000_000), 1), "Tpyo", "Lorem ipsum");
+
+        let result = repo.update(&task).await;
+        assert!(matches!(result, Err(DomainError::TaskNotFound(id)) if id == task.id()));
+
+        repo.save(&task)
+            .await
+            .expect("failed to save task");
+
+        let fixed = Task::new(task.id(), "Typo", "Dolor sit amet");
+        repo.update(&fixed)
+            .await
+            .expect("failed to update task");
+
+        let retrieved_task = repo.task(task.id())
+            .await
+            .expect("failed to retrieve task");
+        assert_eq!(retrieved_task, fixed);
+    }
+
+    #[tokio::test]
+    async fn test_delete_task() {
+        let pool = test_db_setup().await;
+        let repo = PostgresTaskRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let year = random_range(2100..1_000_000);
+
+        let free = Task::new(TaskId::new(year, 1), "Free", "Lorem ipsum");
+        let assigned = Task::new(TaskId::new(year, 2), "Assigned", "Lorem ipsum");
+        repo.save(&free).await.expect("failed to save task");
+        repo.save(&assigned).await.expect("failed to save task");
+
+        let partner = User::new(random(), None::<String>);
+        user_repo.save(&partner).await.expect("failed to save partner");
+
+        let id: i64 = random();
+        let mut user = User::new(id, Some(format!("user{}", id)));
+        user.accept().expect("failed to accept next task");
+        user.promote(&[partner.id()], assigned.id()).expect("failed to promote task");
+        user_repo.save(&user).await.expect("failed to save user");
+
+        repo.delete(free.id())
+            .await
+            .expect("failed to delete task");
+        assert!(matches!(repo.task(free.id()).await, Err(DomainError::TaskNotFound(_))));
+
+        let result = repo.delete(free.id()).await;
+        assert!(matches!(result, Err(DomainError::TaskNotFound(id)) if id == free.id()));
+
+        let result = repo.delete(assigned.id()).await;
+        assert!(matches!(result, Err(DomainError::TaskInUse(id)) if id == assigned.id()));
+        assert!(repo.task(assigned.id()).await.is_ok());
+
+        let result = repo.update(&Task::new(assigned.id(), "Changed", "Lorem ipsum")).await;
+        assert!(matches!(result, Err(DomainError::TaskInUse(id)) if id == assigned.id()));
+    }
+
+    #[tokio::test]
+    async fn test_task_variants() {
+        let pool = test_db_setup().await;
+        let repo = PostgresTaskRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let task = Task::new(TaskId::new(random_range(2100..1_000_000), 1), "Main", "Lorem ipsum");
+
+        let first = NewTaskVariant::new("First", "Lorem ipsum").unwrap();
+        let second = NewTaskVariant::new("Second", "Dolor sit amet").unwrap();
+
+        let result = repo.add_variant(task.id(), &first).await;
+        assert!(matches!(result, Err(DomainError::TaskNotFound(_))));
+
+        repo.save(&task).await.expect("failed to save task");
+        let first = repo.add_variant(task.id(), &first).await.expect("failed to add variant");
+        let second = repo.add_variant(task.id(), &second).await.expect("failed to add variant");
+        assert_eq!((first.number(), second.number()), (1, 2));
+        assert_eq!(repo.variants(task.id()).await.expect("failed to get variants"), vec![first.clone(), second.clone()]);
+
+        let id: i64 = random();
+        let mut users = vec![User::new(id, Some(format!("user{}", id)))];
+        users[0].accept().expect("failed to accept next task");
+        users.push(User::new(id.wrapping_add(1), None::<String>));
+        users[1].accept().expect("failed to accept next task");
+        User::promote_group(&mut users, task.id(), Some(second.number())).expect("failed to promote group");
+        let mut tx = PostgresUnitOfWork::new(pool.clone()).begin().await.expect("failed to begin transaction");
+        for user in &users {
+            user_repo.save_in(tx.as_mut(), user).await.expect("failed to save user");
+        }
+        tx.commit().await.expect("failed to commit transaction");
+
+        let stored = user_repo.user(users[0].id()).await.expect("failed to retrieve user");
+        assert_eq!(stored.user_task().and_then(|ut| ut.variant()), Some(second.number()));
+
+        let result = repo.delete_variant(task.id(), second.number()).await;
+        assert!(matches!(result, Err(DomainError::TaskInUse(_))));
+
+        repo.delete_variant(task.id(), first.number()).await.expect("failed to delete variant");
+        let result = repo.delete_variant(task.id(), first.number()).await;
+        assert!(matches!(result, Err(DomainError::TaskVariantNotFound(_, 1))));
+        assert_eq!(repo.variants(task.id()).await.expect("failed to get variants"), vec![second]);
+    }
+    #[tokio::test]
+    async fn test_add_variants_concurrently() {
+        let pool = test_db_setup().await;
+        let repo = PostgresTaskRepository::new(pool);
+        let task = Task::new(TaskId::new(random_range(2100..1_000_000), 1), "Main", "Lorem ipsum");
+        repo.save(&task).await.expect("failed to save task");
+
+        let variant = NewTaskVariant::new("Variant", "Lorem ipsum").unwrap();
+        let results = futures::future::join_all(
+            (0..5).map(|_| repo.add_variant(task.id(), &variant))
+        ).await;
+
+        let mut numbers = results
+            .into_iter()
+            .map(|result| result.expect("failed to add variant").number())
+            .collect::<Vec<_>>();
+        numbers.sort();
+        assert_eq!(numbers, vec![1, 2, 3, 4, 5]);
+    }
+}
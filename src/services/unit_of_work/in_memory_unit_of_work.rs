@@ -0,0 +1,53 @@
+use std::any::Any;
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{Transaction, UnitOfWork};
+
+
+type Operation = Box<dyn FnOnce() + Send>;
+
+#[derive(Default)]
+pub struct InMemoryUnitOfWork;
+
+#[async_trait::async_trait]
+impl UnitOfWork for InMemoryUnitOfWork {
+    async fn begin(&self) -> Result<Box<dyn Transaction>, DomainError> {
+        Ok(Box::new(InMemoryTransaction::default()))
+    }
+}
+
+/// Collects writes of the in-memory repositories and applies them on commit
+#[derive(Default)]
+pub struct InMemoryTransaction {
+    operations: Vec<Operation>,
+}
+
+impl InMemoryTransaction {
+    pub fn from_dyn(tx: &mut dyn Transaction) -> Result<&mut Self, DomainError> {
+        tx.as_any_mut()
+            .downcast_mut::<Self>()
+            .ok_or_else(|| DomainError::Other("transaction does not belong to in-memory storage".into()))
+    }
+
+    pub fn stage(&mut self, operation: impl FnOnce() + Send + 'static) {
+        self.operations.push(Box::new(operation));
+    }
+}
+
+#[async_trait::async_trait]
+impl Transaction for InMemoryTransaction {
+    async fn commit(self: Box<Self>) -> Result<(), DomainError> {
+        for operation in self.operations {
+            operation();
+        }
+        Ok(())
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), DomainError> {
+        Ok(())
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
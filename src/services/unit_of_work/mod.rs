@@ -0,0 +1,5 @@
+mod in_memory_unit_of_work;
+mod postgres_unit_of_work;
+
+pub use in_memory_unit_of_work::{InMemoryTransaction, InMemoryUnitOfWork};
+pub use postgres_unit_of_work::{PostgresTransaction, PostgresUnitOfWork};
@@ -0,0 +1,162 @@
+use std::any::Any;
+
+use deadpool_postgres::{Client, Object, Pool};
+
+use crate::domain::error::DomainError;
+use crate::domain::interfaces::{Transaction, UnitOfWork};
+
+
+pub struct PostgresUnitOfWork {
+    pool: Pool,
+}
+
+impl PostgresUnitOfWork {
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait::async_trait]
+impl UnitOfWork for PostgresUnitOfWork {
+    async fn begin(&self) -> Result<Box<dyn Transaction>, DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        client.batch_execute("BEGIN")
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        Ok(Box::new(PostgresTransaction { client: Some(client) }))
+    }
+}
+
+/// Keeps a pooled connection with an open transaction. The transaction is
+/// driven by plain `BEGIN`/`COMMIT`/`ROLLBACK` statements, because
+/// `tokio_postgres::Transaction` borrows the client and cannot be boxed
+/// together with it.
+pub struct PostgresTransaction {
+    client: Option<Client>,
+}
+
+impl PostgresTransaction {
+    pub fn from_dyn(tx: &mut dyn Transaction) -> Result<&mut Self, DomainError> {
+        tx.as_any_mut()
+            .downcast_mut::<Self>()
+            .ok_or_else(|| DomainError::Other("transaction does not belong to PostgreSQL".into()))
+    }
+
+    pub fn client(&self) -> &Client {
+        self.client
+            .as_ref()
+            .expect("transaction is already finished")
+    }
+
+    async fn finish(mut self, statement: &str) -> Result<(), DomainError> {
+        let client = self.client
+            .take()
+            .expect("transaction is already finished");
+
+        client.batch_execute(statement)
+            .await
+            .map_err(|err| DomainError::Other(err.into()))
+    }
+}
+
+#[async_trait::async_trait]
+impl Transaction for PostgresTransaction {
+    async fn commit(self: Box<Self>) -> Result<(), DomainError> {
+        self.finish("COMMIT").await
+    }
+
+    async fn rollback(self: Box<Self>) -> Result<(), DomainError> {
+        self.finish("ROLLBACK").await
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+impl Drop for PostgresTransaction {
+    fn drop(&mut self) {
+        // The connection still has an open transaction, so it must not go back
+        // to the pool. Closing it makes the server roll the transaction back.
+        if let Some(client) = self.client.take() {
+            drop(Object::take(client));
+        }
+    }
+}
+
+#[cfg(test)]
+mod integration_tests {
+    use rand::random;
+    use crate::domain::interfaces::UserRepository;
+    use crate::domain::models::User;
+    use crate::services::PostgresUserRepository;
+    use crate::utils::postgres::testing::test_db_setup;
+    use super::*;
+
+    fn create_user_from_id(id: i64) -> User {
+        User::new(id, Some(format!("user{}", id)))
+    }
+
+    #[tokio::test]
+    async fn test_commit() {
+        let pool = test_db_setup().await;
+        let uow = PostgresUnitOfWork::new(pool.clone());
+        let repo = PostgresUserRepository::new(pool.clone());
+        let mut test_user = create_user_from_id(random());
+        repo.save(&test_user).await.expect("failed to save user");
+
+        let mut tx = uow.begin().await.expect("failed to begin transaction");
+        test_user.accept().expect("failed to accept next task");
+        repo.update_in(tx.as_mut(), &test_user).await.expect("failed to update user");
+        tx.commit().await.expect("failed to commit transaction");
+
+        let updated_user = repo.user(test_user.id()).await.expect("failed to retrieve user");
+        assert_eq!(updated_user.next_task_status(), test_user.next_task_status());
+        assert_eq!(updated_user.version(), test_user.version() + 1);
+    }
+
+    #[tokio::test]
+    async fn test_rollback() {
+        let pool = test_db_setup().await;
+        let uow = PostgresUnitOfWork::new(pool.clone());
+        let repo = PostgresUserRepository::new(pool.clone());
+        let test_user = create_user_from_id(random());
+        repo.save(&test_user).await.expect("failed to save user");
+
+        let mut tx = uow.begin().await.expect("failed to begin transaction");
+        let mut changed_user = repo.user_in(tx.as_mut(), test_user.id()).await.expect("failed to read user");
+        changed_user.accept().expect("failed to accept next task");
+        repo.update_in(tx.as_mut(), &changed_user).await.expect("failed to update user");
+        tx.rollback().await.expect("failed to roll back transaction");
+
+        let retrieved_user = repo.user(test_user.id()).await.expect("failed to retrieve user");
+        assert_eq!(retrieved_user, test_user);
+    }
+
+    #[tokio::test]
+    async fn test_drop_rolls_back() {
+        let pool = test_db_setup().await;
+        let uow = PostgresUnitOfWork::new(pool.clone());
+        let repo = PostgresUserRepository::new(pool.clone());
+        let mut test_user = create_user_from_id(random());
+        repo.save(&test_user).await.expect("failed to save user");
+
+        {
+            let mut tx = uow.begin().await.expect("failed to begin transaction");
+            let mut changed_user = test_user.clone();
+            changed_user.accept().expect("failed to accept next task");
+            repo.update_in(tx.as_mut(), &changed_user).await.expect("failed to update user");
+        }
+
+        let retrieved_user = repo.user(test_user.id()).await.expect("failed to retrieve user");
+        assert_eq!(retrieved_user, test_user);
+
+        test_user.reject().expect("failed to reject next task");
+        repo.update(&mut test_user).await.expect("failed to update user after dropped transaction");
+    }
+}
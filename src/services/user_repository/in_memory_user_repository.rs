@@ -1,13 +1,18 @@
 use std::collections::HashMap;
-use std::sync::RwLock;
+use std::sync::{Arc, RwLock};
 
 use crate::domain::error::DomainError;
-use crate::domain::interfaces::UserRepository;
-use crate::domain::models::{User, UserId};
+use crate::domain::interfaces::{Transaction, UserRepository};
+use crate::domain::models::{NextTaskStatus, TaskId, User, UserId, UserTask, UserTaskHistory, UserTaskState, WeekId};
+use crate::services::InMemoryTransaction;
+
+type Users = RwLock<HashMap<UserId, User>>;
+type History = RwLock<HashMap<UserId, Vec<UserTask>>>;
 
 #[derive(Default)]
 pub struct InMemoryUserRepository {
-    m: RwLock<HashMap<UserId, User>>,
+    m: Arc<Users>,
+    history: Arc<History>,
 }
 
 #[async_trait::async_trait]
@@ -18,16 +23,65 @@ impl UserRepository for InMemoryUserRepository {
             Err(DomainError::UserAlreadyExists(user.id()))
         } else {
             guard.insert(user.id(), user.clone());
+            Self::record_user_task(&self.history, user);
             Ok(())
         }
     }
 
-    async fn update(&self, user: &User) -> Result<(), DomainError> {
-        let mut guard = self.m.write().unwrap();
-        guard.insert(user.id(), user.clone());
+    async fn save_in(&self, tx: &mut dyn Transaction, user: &User) -> Result<(), DomainError> {
+        if self.m.read().unwrap().contains_key(&user.id()) {
+            return Err(DomainError::UserAlreadyExists(user.id()));
+        }
+        let (m, history, user) = (self.m.clone(), self.history.clone(), user.clone());
+        InMemoryTransaction::from_dyn(tx)?
+            .stage(move || {
+                m.write().unwrap().insert(user.id(), user.clone());
+                Self::record_user_task(&history, &user);
+            });
+        Ok(())
+    }
+
+    async fn update(&self, user: &mut User) -> Result<(), DomainError> {
+        Self::check_version(&self.m, user)?;
+        user.increment_version();
+        Self::store(&self.m, &self.history, user);
+        Ok(())
+    }
+
+    async fn user_in(&self, _tx: &mut dyn Transaction, id: UserId) -> Result<User, DomainError> {
+        self.user(id).await
+    }
+
+    async fn user_with_task_in(&self, _tx: &mut dyn Transaction, id: UserId, task_id: TaskId) -> Result<User, DomainError> {
+        let user = self.user(id).await?;
+        let guard = self.history.read().unwrap();
+        let user_task = guard
+            .get(&id)
+            .and_then(|user_tasks| user_tasks.iter().find(|ut| ut.task_id() == task_id))
+            .ok_or(DomainError::NoUserTask)?;
+        Ok(Self::with_task(&user, Some(user_task.clone())))
+    }
+
+    async fn update_in(&self, tx: &mut dyn Transaction, user: &User) -> Result<(), DomainError> {
+        Self::check_version(&self.m, user)?;
+        let (m, history, mut user) = (self.m.clone(), self.history.clone(), user.clone());
+        InMemoryTransaction::from_dyn(tx)?
+            .stage(move || {
+                user.increment_version();
+                Self::store(&m, &history, &user);
+            });
         Ok(())
     }
 
+    async fn user_by_username_in(&self, _tx: &mut dyn Transaction, username: &str) -> Result<Option<User>, DomainError> {
+        let guard = self.m.read().unwrap();
+        Ok(guard
+            .values()
+            .find(|user| user.username() == Some(username))
+            .cloned()
+        )
+    }
+
     async fn user(&self, id: UserId) -> Result<User, DomainError> {
         Ok(self.find_user(id).await?.ok_or(DomainError::UserNotFound(id))?)
     }
@@ -47,7 +101,17 @@ impl UserRepository for InMemoryUserRepository {
         Ok(guard
             .values()
             .filter(|&user| user.is_ready())
-            .map(|user| user.clone())
+            .cloned()
+            .collect()
+        )
+    }
+
+    async fn pending_users(&self) -> Result<Vec<User>, DomainError> {
+        let guard = self.m.read().unwrap();
+        Ok(guard
+            .values()
+            .filter(|&user| user.profile_completed() && user.next_task_status() == NextTaskStatus::Pending)
+            .cloned()
             .collect()
         )
     }
@@ -56,9 +120,130 @@ impl UserRepository for InMemoryUserRepository {
         let guard = self.m.read().unwrap();
         Ok(guard
             .values()
-            .filter(|&user| user.is_ready())
+            .filter(|&user| user.in_progress())
             .map(|user| user.clone())
             .collect()
         )
     }
+
+    async fn users_with_task(&self, state: UserTaskState, week_id: Option<WeekId>) -> Result<Vec<User>, DomainError> {
+        let users = self.m.read().unwrap();
+        let history = self.history.read().unwrap();
+        let mut found: Vec<User> = history
+            .iter()
+            .flat_map(|(id, user_tasks)| user_tasks.iter().map(move |ut| (id, ut)))
+            .filter(|(_, ut)| *ut.state() == state && week_id.is_none_or(|week_id| ut.task_id() == week_id))
+            .filter_map(|(id, ut)| users.get(id).map(|user| Self::with_task(user, Some(ut.clone()))))
+            .collect();
+        found.sort_by_key(|user| (user.user_task().map(UserTask::task_id), user.id().0));
+        Ok(found)
+    }
+
+    async fn history(&self, id: UserId) -> Result<UserTaskHistory, DomainError> {
+        let guard = self.history.read().unwrap();
+        let user_tasks = guard.get(&id).cloned().unwrap_or_default();
+        Ok(UserTaskHistory::new(id, user_tasks))
+    }
+
+    async fn expire_tasks_in(&self, tx: &mut dyn Transaction, before: WeekId) -> Result<u64, DomainError> {
+        let is_stale = move |ut: &UserTask| *ut.state() == UserTaskState::Active && ut.task_id() < before;
+        let expired = self.history
+            .read()
+            .unwrap()
+            .values()
+            .flatten()
+            .filter(|&ut| is_stale(ut))
+            .count();
+
+        let (m, history) = (self.m.clone(), self.history.clone());
+        InMemoryTransaction::from_dyn(tx)?
+            .stage(move || {
+                let mut users = m.write().unwrap();
+                for (id, user_tasks) in history.write().unwrap().iter_mut() {
+                    let mut changed = false;
+                    for ut in user_tasks.iter_mut().filter(|ut| is_stale(ut)) {
+                        changed |= ut.expire().is_ok();
+                    }
+                    if let Some(user) = users.get_mut(id).filter(|_| changed) {
+                        let latest = user_tasks.iter().max_by_key(|ut| ut.task_id()).cloned();
+                        *user = Self::with_task(user, latest);
+                        user.increment_version();
+                    }
+                }
+            });
+        Ok(expired as u64)
+    }
+}
+
+impl InMemoryUserRepository {
+    /// Whether any user has ever been assigned the task, like a
+    /// `user_tasks` row referencing it in PostgreSQL
+    pub fn is_task_assigned(&self, task_id: TaskId) -> bool {
+        let guard = self.history.read().unwrap();
+        guard
+            .values()
+            .flatten()
+            .any(|user_task| user_task.task_id() == task_id)
+    }
+
+    /// Whether any user has ever been assigned the variant of the task
+    pub fn is_variant_assigned(&self, task_id: TaskId, number: u32) -> bool {
+        let guard = self.history.read().unwrap();
+        guard
+            .values()
+            .flatten()
+            .any(|user_task| user_task.task_id() == task_id && user_task.variant() == Some(number))
+    }
+
+    /// State of the user's task of `task_id`, if it has been assigned
+    pub fn task_state(&self, user_id: UserId, task_id: TaskId) -> Option<UserTaskState> {
+        let guard = self.history.read().unwrap();
+        guard
+            .get(&user_id)?
+            .iter()
+            .find(|user_task| user_task.task_id() == task_id)
+            .map(|user_task| user_task.state().clone())
+    }
+
+    fn check_version(m: &Users, user: &User) -> Result<(), DomainError> {
+        let guard = m.read().unwrap();
+        match guard.get(&user.id()) {
+            Some(stored) if stored.version() == user.version() => Ok(()),
+            _ => Err(DomainError::ConcurrentModification(user.id())),
+        }
+    }
+
+    /// The stored user keeps holding the latest task, like reads in PostgreSQL,
+    /// even if `user` has been loaded with an older one
+    fn store(m: &Users, history: &History, user: &User) {
+        Self::record_user_task(history, user);
+        let latest = history
+            .read()
+            .unwrap()
+            .get(&user.id())
+            .and_then(|user_tasks| user_tasks.iter().max_by_key(|ut| ut.task_id()).cloned());
+        let mut guard = m.write().unwrap();
+        guard.insert(user.id(), Self::with_task(user, latest));
+    }
+
+    fn with_task(user: &User, user_task: Option<UserTask>) -> User {
+        User::restore(
+            user.id(),
+            user.username().map(String::from),
+            user.profile().cloned(),
+            user_task,
+            user.next_task_status(),
+            user.completed_tasks(),
+            user.version(),
+        )
+    }
+
+    fn record_user_task(history: &History, user: &User) {
+        if let Some(user_task) = user.user_task() {
+            let mut guard = history.write().unwrap();
+            let user_tasks = guard.entry(user.id()).or_default();
+            user_tasks.retain(|ut| ut.task_id() != user_task.task_id());
+            user_tasks.push(user_task.clone());
+        }
+    }
 }
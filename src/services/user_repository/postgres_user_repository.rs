@@ -1,11 +1,12 @@
-use deadpool_postgres::{Client, GenericClient, Pool};
+use deadpool_postgres::{GenericClient, Pool};
 use tokio_postgres::Row;
 use postgres_types::{FromSql, ToSql};
 
 use crate::domain::error::DomainError;
-use crate::domain::interfaces::UserRepository;
-use crate::domain::models::{NextTaskStatus, Profile, TaskId, User, UserId, UserTask, UserTaskState};
-use crate::utils::postgres::helpers::is_unique_violation;
+use crate::domain::interfaces::{Transaction, UserRepository};
+use crate::domain::models::{NextTaskStatus, Profile, TaskId, User, UserId, UserTask, UserTaskHistory, UserTaskState, WeekId};
+use crate::services::PostgresTransaction;
+use crate::utils::postgres::helpers::is_unique_violation_of;
 
 
 #[derive(Debug, Clone, ToSql, FromSql)]
@@ -14,17 +15,24 @@ enum UserTaskStateModel {
     #[postgres(name = "active")]
     Active,
 
+    #[postgres(name = "awaiting_review")]
+    AwaitingReview,
+
     #[postgres(name = "completed")]
     Completed,
+
+    #[postgres(name = "expired")]
+    Expired,
 }
 
 #[derive(Clone, Debug)]
 struct UserTaskModel {
-    user_id:    i64,    // PK   FK(users)
-    task_year:  i32,    //      FK (tasks)
-    task_week:  i32,    //      FK (tasks)
-    partner_id: i64,    //      FK (users)
-    state:      UserTaskStateModel,
+    user_id:     i64,       // PK   FK(users)
+    task_year:   i32,       // PK   FK (tasks)
+    task_week:   i32,       // PK   FK (tasks)
+    partner_ids: Vec<i64>,  // FK (users) through user_task_partners
+    state:       UserTaskStateModel,
+    variant:     Option<i32>,   // FK (task_variants)
 }
 
 #[derive(Debug, Clone, ToSql, FromSql)]
@@ -43,11 +51,12 @@ enum NextTaskStatusModel {
 #[derive(Clone, Debug)]
 struct UserModel {
     id:                 i64,    // PK
-    username:           String,
+    username:           Option<String>,
     full_name:          Option<String>,
     group_name:         Option<String>,
     next_task_status:   NextTaskStatusModel,
     completed_tasks:    i32,
+    version:            i32,
 }
 
 pub struct PostgresUserRepository {
@@ -73,6 +82,236 @@ impl UserRepository for PostgresUserRepository {
             .await
             .map_err(|err| DomainError::Other(err.into()))?;
 
+        Self::insert_user(&tr, user).await?;
+
+        tr.commit()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        Ok(())
+    }
+
+    async fn save_in(&self, tx: &mut dyn Transaction, user: &User) -> Result<(), DomainError> {
+        let tx = PostgresTransaction::from_dyn(tx)?;
+        Self::insert_user(tx.client(), user).await
+    }
+
+    async fn update(&self, user: &mut User) -> Result<(), DomainError> {
+        let mut client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let tr = client
+            .transaction()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        Self::write_user(&tr, user).await?;
+
+        tr.commit()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        user.increment_version();
+        Ok(())
+    }
+
+    async fn user(&self, id: UserId) -> Result<User, DomainError> {
+        self.find_user(id)
+            .await?
+            .ok_or(DomainError::UserNotFound(id))
+    }
+
+    async fn find_user(&self, id: UserId) -> Result<Option<User>, DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        Self::read_user(&client, id, None, "").await
+    }
+
+    async fn user_in(&self, tx: &mut dyn Transaction, id: UserId) -> Result<User, DomainError> {
+        let tx = PostgresTransaction::from_dyn(tx)?;
+        Self::read_user(tx.client(), id, None, "FOR UPDATE")
+            .await?
+            .ok_or(DomainError::UserNotFound(id))
+    }
+
+    async fn user_with_task_in(&self, tx: &mut dyn Transaction, id: UserId, task_id: TaskId) -> Result<User, DomainError> {
+        let tx = PostgresTransaction::from_dyn(tx)?;
+        let user = Self::read_user(tx.client(), id, Some(task_id), "FOR UPDATE")
+            .await?
+            .ok_or(DomainError::UserNotFound(id))?;
+
+        if user.user_task().is_none() {
+            return Err(DomainError::NoUserTask);
+        }
+        Ok(user)
+    }
+
+    async fn update_in(&self, tx: &mut dyn Transaction, user: &User) -> Result<(), DomainError> {
+        let tx = PostgresTransaction::from_dyn(tx)?;
+        Self::write_user(tx.client(), user).await
+    }
+
+    async fn user_by_username_in(&self, tx: &mut dyn Transaction, username: &str) -> Result<Option<User>, DomainError> {
+        let tx = PostgresTransaction::from_dyn(tx)?;
+        let row = tx.client()
+            .query_opt(
+                "SELECT id FROM users WHERE username = $1 FOR UPDATE",
+                &[&username],
+            )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        Self::read_user(tx.client(), UserId(row.get("id")), None, "FOR UPDATE").await
+    }
+
+    async fn all(&self) -> Result<Vec<User>, DomainError> {
+        self.fetch_users(None).await
+    }
+
+    async fn ready_users(&self) -> Result<Vec<User>, DomainError> {
+        self.fetch_users(Some("next_task_status = 'accepted'")).await
+    }
+
+    async fn pending_users(&self) -> Result<Vec<User>, DomainError> {
+        self.fetch_users(Some("next_task_status = 'pending' AND full_name IS NOT NULL")).await
+    }
+    
+    async fn active_users(&self) -> Result<Vec<User>, DomainError> {
+        self.fetch_users_by_task_state(UserTaskStateModel::Active).await
+    }
+
+    async fn users_with_task(&self, state: UserTaskState, week_id: Option<WeekId>) -> Result<Vec<User>, DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let state: UserTaskStateModel = state.into();
+        let task_year = week_id.map(|week_id| week_id.year);
+        let task_week = week_id.map(|week_id| week_id.week as i32);
+        let rows = client
+            .query(
+                r#"
+                SELECT
+                    id,
+                    username,
+                    full_name,
+                    group_name,
+                    next_task_status,
+                    completed_tasks,
+                    version,
+                    user_id,
+                    task_year,
+                    task_week,
+                    ARRAY(
+                        SELECT p.partner_id
+                        FROM user_task_partners p
+                        WHERE p.user_id = user_tasks.user_id
+                          AND p.task_year = user_tasks.task_year
+                          AND p.task_week = user_tasks.task_week
+                        ORDER BY p.position
+                    ) AS partner_ids,
+                    state,
+                    variant
+                FROM users
+                JOIN user_tasks ON user_tasks.user_id = users.id
+                WHERE state = $1
+                  AND ($2::INTEGER IS NULL OR (task_year = $2 AND task_week = $3))
+                ORDER BY task_year, task_week, id
+                "#,
+                &[&state, &task_year, &task_week],
+            )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let users = rows
+            .into_iter()
+            .map(|row| {
+                let user_model: UserModel = row.clone().into();
+                let user_task_model: UserTaskModel = row.into();
+                (user_model, Some(user_task_model)).into()
+            })
+            .collect();
+
+        Ok(users)
+    }
+
+    async fn history(&self, id: UserId) -> Result<UserTaskHistory, DomainError> {
+        let client = self.pool
+            .get()
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let rows = client
+            .query(
+                r#"
+                SELECT
+                    user_id,
+                    task_year,
+                    task_week,
+                    ARRAY(
+                        SELECT p.partner_id
+                        FROM user_task_partners p
+                        WHERE p.user_id = user_tasks.user_id
+                          AND p.task_year = user_tasks.task_year
+                          AND p.task_week = user_tasks.task_week
+                        ORDER BY p.position
+                    ) AS partner_ids,
+                    state,
+                    variant
+                FROM user_tasks
+                WHERE user_id = $1
+                ORDER BY task_year, task_week
+                "#,
+                &[&id.0],
+            )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        let user_tasks = rows
+            .into_iter()
+            .map(|row| UserTaskModel::from(row).into());
+
+        Ok(UserTaskHistory::new(id, user_tasks))
+    }
+
+    async fn expire_tasks_in(&self, tx: &mut dyn Transaction, before: WeekId) -> Result<u64, DomainError> {
+        let tx = PostgresTransaction::from_dyn(tx)?;
+        let row = tx.client()
+            .query_one(
+                r#"
+                WITH expired AS (
+                    UPDATE user_tasks
+                    SET state = 'expired'
+                    WHERE state IN ('active') AND (task_year, task_week) < ($1, $2)
+                    RETURNING user_id
+                ), bumped AS (
+                    UPDATE users
+                    SET version = version + 1
+                    WHERE id IN (SELECT user_id FROM expired)
+                )
+                SELECT COUNT(*) AS expired FROM expired
+                "#,
+                &[&before.year, &(before.week as i32)],
+            )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
+
+        Ok(row.get::<_, i64>("expired") as u64)
+    }
+}
+
+impl PostgresUserRepository {
+    async fn insert_user<C: GenericClient>(tr: &C, user: &User) -> Result<(), DomainError> {
         let (user_model, user_task_model) = user.into();
 
         tr.execute(
@@ -97,7 +336,7 @@ impl UserRepository for PostgresUserRepository {
         )
             .await
             .map_err(|err| {
-                if is_unique_violation(&err) {
+                if is_unique_violation_of(&err, "users_pkey") {
                     DomainError::UserAlreadyExists(user.id())
                 } else {
                     DomainError::Other(err.into())
@@ -111,43 +350,69 @@ impl UserRepository for PostgresUserRepository {
                     user_id,
                     task_year,
                     task_week,
-                    partner_id,
-                    state
+                    state,
+                    variant
                 ) VALUES ($1, $2, $3, $4, $5)
                 "#,
                 &[
                     &ut.user_id,
                     &ut.task_year,
                     &ut.task_week,
-                    &ut.partner_id,
                     &ut.state,
+                    &ut.variant,
                 ]
             )
                 .await
                 .map_err(|err| DomainError::Other(err.into()))?;
-        }
 
-        tr.commit()
-            .await
-            .map_err(|err| DomainError::Other(err.into()))?;
+            Self::write_partners(tr, &ut).await?;
+        }
 
         Ok(())
     }
 
-    async fn update(&self, user: &User) -> Result<(), DomainError> {
-        let mut client = self.pool
-            .get()
+    /// The user holds the task of `task_id` if given, the latest one otherwise
+    async fn read_user<C: GenericClient>(
+        client: &C,
+        id: UserId,
+        task_id: Option<TaskId>,
+        lock: &str,
+    ) -> Result<Option<User>, DomainError> {
+        let row = client
+            .query_opt(
+                &format!(r#"
+                SELECT
+                    id,
+                    username,
+                    full_name,
+                    group_name,
+                    next_task_status,
+                    completed_tasks,
+                    version
+                FROM users
+                WHERE id = $1
+                {lock}
+                "#),
+                &[&id.0],
+            )
             .await
             .map_err(|err| DomainError::Other(err.into()))?;
 
-        let tr = client
-            .transaction()
-            .await
-            .map_err(|err| DomainError::Other(err.into()))?;
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let user_model: UserModel = row.into();
+        let user_task_model = Self::user_task_model(client, user_model.id, task_id).await?;
+        let user: User  = (user_model, user_task_model).into();
+
+        Ok(Some(user))
+    }
 
+    async fn write_user<C: GenericClient>(tr: &C, user: &User) -> Result<(), DomainError> {
         let (user_model, user_task_model) = user.into();
 
-        tr.execute(
+        let updated = tr.execute(
             r#"
             UPDATE users
             SET
@@ -155,8 +420,9 @@ impl UserRepository for PostgresUserRepository {
                 full_name = $3,
                 group_name = $4,
                 next_task_status = $5,
-                completed_tasks = $6
-            WHERE id = $1
+                completed_tasks = $6,
+                version = version + 1
+            WHERE id = $1 AND version = $7
             "#,
             &[
                 &user_model.id,
@@ -165,11 +431,16 @@ impl UserRepository for PostgresUserRepository {
                 &user_model.group_name,
                 &user_model.next_task_status,
                 &user_model.completed_tasks,
+                &user_model.version,
             ],
         )
             .await
             .map_err(|err| DomainError::Other(err.into()))?;
 
+        if updated == 0 {
+            return Err(DomainError::ConcurrentModification(user.id()));
+        }
+
         if let Some(ut) = user_task_model {
             tr.execute(
                 r#"
@@ -177,86 +448,103 @@ impl UserRepository for PostgresUserRepository {
                     user_id,
                     task_year,
                     task_week,
-                    partner_id,
-                    state
+                    state,
+                    variant
                 ) VALUES ($1, $2, $3, $4, $5)
-                ON CONFLICT (user_id)
+                ON CONFLICT (user_id, task_year, task_week)
                     DO UPDATE
                     SET
-                        task_year  = $2,
-                        task_week  = $3,
-                        partner_id = $4,
-                        state      = $5
+                        state   = $4,
+                        variant = $5
                 "#,
                 &[
                     &ut.user_id,
                     &ut.task_year,
                     &ut.task_week,
-                    &ut.partner_id,
                     &ut.state,
+                    &ut.variant,
                 ]
             )
                 .await
                 .map_err(|err| DomainError::Other(err.into()))?;
-        }
 
-        tr.commit()
-            .await
-            .map_err(|err| DomainError::Other(err.into()))?;
+            Self::write_partners(tr, &ut).await?;
+        }
 
         Ok(())
     }
 
-    async fn user(&self, id: UserId) -> Result<User, DomainError> {
-        self.find_user(id)
-            .await?
-            .ok_or(DomainError::UserNotFound(id))
-    }
+    /// Replaces the partners of the user task, keeping their order
+    async fn write_partners<C: GenericClient>(tr: &C, ut: &UserTaskModel) -> Result<(), DomainError> {
+        tr.execute(
+            r#"
+            DELETE FROM user_task_partners
+            WHERE user_id = $1 AND task_year = $2 AND task_week = $3
+            "#,
+            &[&ut.user_id, &ut.task_year, &ut.task_week],
+        )
+            .await
+            .map_err(|err| DomainError::Other(err.into()))?;
 
-    async fn find_user(&self, id: UserId) -> Result<Option<User>, DomainError> {
-        let client = self.pool
-            .get()
+        tr.execute(
+            r#"
+            INSERT INTO user_task_partners (
+                user_id,
+                task_year,
+                task_week,
+                position,
+                partner_id
+            )
+            SELECT $1, $2, $3, p.position::INTEGER, p.partner_id
+            FROM unnest($4::BIGINT[]) WITH ORDINALITY AS p(partner_id, position)
+            "#,
+            &[&ut.user_id, &ut.task_year, &ut.task_week, &ut.partner_ids],
+        )
             .await
             .map_err(|err| DomainError::Other(err.into()))?;
 
+        Ok(())
+    }
+
+    async fn user_task_model<C: GenericClient>(
+        client: &C,
+        user_id: i64,
+        task_id: Option<TaskId>,
+    ) -> Result<Option<UserTaskModel>, DomainError> {
+        let task_year = task_id.map(|task_id| task_id.year);
+        let task_week = task_id.map(|task_id| task_id.week as i32);
         let row = client
             .query_opt(
                 r#"
                 SELECT
-                    id,
-                    username,
-                    full_name,
-                    group_name,
-                    next_task_status,
-                    completed_tasks
-                FROM users
-                WHERE id = $1
+                    user_id,
+                    task_year,
+                    task_week,
+                    ARRAY(
+                        SELECT p.partner_id
+                        FROM user_task_partners p
+                        WHERE p.user_id = user_tasks.user_id
+                          AND p.task_year = user_tasks.task_year
+                          AND p.task_week = user_tasks.task_week
+                        ORDER BY p.position
+                    ) AS partner_ids,
+                    state,
+                    variant
+                FROM user_tasks
+                WHERE user_id = $1
+                  AND ($2::INTEGER IS NULL OR (task_year = $2 AND task_week = $3))
+                ORDER BY task_year DESC, task_week DESC
+                LIMIT 1
                 "#,
-                &[&id.0],
+                &[&user_id, &task_year, &task_week],
             )
             .await
             .map_err(|err| DomainError::Other(err.into()))?;
 
-        let Some(row) = row else {
-            return Ok(None);
-        };
-
-        let user_model: UserModel = row.into();
-        let user_task_model = Self::user_task_model(&client, user_model.id).await?;
-        let user: User  = (user_model, user_task_model).into();
-
-        Ok(Some(user))
-    }
-
-    async fn all(&self) -> Result<Vec<User>, DomainError> {
-        self.fetch_users(None).await
+        Ok(row.map(|r| r.into()))
     }
 
-    async fn ready_users(&self) -> Result<Vec<User>, DomainError> {
-        self.fetch_users(Some("next_task_status = 'accepted'")).await
-    }
-    
-    async fn active_users(&self) -> Result<Vec<User>, DomainError> {
+    async fn fetch_users_by_task_state(&self, state: UserTaskStateModel) -> Result<Vec<User>, DomainError> {
         let client = self.pool
             .get()
             .await
@@ -272,16 +560,35 @@ impl UserRepository for PostgresUserRepository {
                     group_name,
                     next_task_status,
                     completed_tasks,
+                    version,
                     user_id,
                     task_year,
                     task_week,
-                    partner_id,
-                    state
+                    partner_ids,
+                    state,
+                    variant
                 FROM users
-                RIGHT JOIN user_tasks t ON t.user_id = users.id
-                WHERE state = 'active'
+                JOIN (
+                    SELECT DISTINCT ON (user_id)
+                        user_id,
+                        task_year,
+                        task_week,
+                        ARRAY(
+                            SELECT p.partner_id
+                            FROM user_task_partners p
+                            WHERE p.user_id = user_tasks.user_id
+                              AND p.task_year = user_tasks.task_year
+                              AND p.task_week = user_tasks.task_week
+                            ORDER BY p.position
+                        ) AS partner_ids,
+                        state,
+                        variant
+                    FROM user_tasks
+                    ORDER BY user_id, task_year DESC, task_week DESC
+                ) t ON t.user_id = users.id
+                WHERE t.state = $1
                 "#,
-                &[],
+                &[&state],
             )
                 .await
                 .map_err(|err| DomainError::Other(err.into()))?;
@@ -297,29 +604,6 @@ impl UserRepository for PostgresUserRepository {
         
         Ok(users)
     }
-}
-
-impl PostgresUserRepository {
-    async fn user_task_model(client: &Client, user_id: i64) -> Result<Option<UserTaskModel>, DomainError> {
-        let row = client
-            .query_opt(
-                r#"
-                SELECT
-                    user_id,
-                    task_year,
-                    task_week,
-                    partner_id,
-                    state
-                FROM user_tasks
-                WHERE user_id = $1
-                "#,
-                &[&user_id],
-            )
-            .await
-            .map_err(|err| DomainError::Other(err.into()))?;
-
-        Ok(row.map(|r| r.into()))
-    }
 
     async fn fetch_users(&self, where_clause: Option<&str>) -> Result<Vec<User>, DomainError> {
         let client = self.pool
@@ -335,7 +619,8 @@ impl PostgresUserRepository {
                 full_name,
                 group_name,
                 next_task_status,
-                completed_tasks
+                completed_tasks,
+                version
             FROM users
             {}
             "#,
@@ -350,7 +635,7 @@ impl PostgresUserRepository {
         let mut users: Vec<User> = Vec::new();
         for user_row in user_rows {
             let user_model: UserModel = user_row.into();
-            let user_task_model: Option<UserTaskModel> = Self::user_task_model(&client, user_model.id).await?;
+            let user_task_model: Option<UserTaskModel> = Self::user_task_model(&client, user_model.id, None).await?;
             let user: User = (user_model, user_task_model).into();
             users.push(user);
         }
@@ -363,7 +648,9 @@ impl From<UserTaskState> for UserTaskStateModel {
     fn from(user_task_state: UserTaskState) -> Self {
         match user_task_state {
             UserTaskState::Active => UserTaskStateModel::Active,
+            UserTaskState::AwaitingReview => UserTaskStateModel::AwaitingReview,
             UserTaskState::Completed => UserTaskStateModel::Completed,
+            UserTaskState::Expired => UserTaskStateModel::Expired,
         }
     }
 }
@@ -383,18 +670,20 @@ impl From<&User> for (UserModel, Option<UserTaskModel>) {
         (
             UserModel {
                 id:                 user.id().0,
-                username:           user.username().to_string(),
+                username:           user.username().map(String::from),
                 full_name:          user.profile().map(|p| p.full_name().to_string()),
                 group_name:         user.profile().map(|p| p.group_name().to_string()),
                 next_task_status:   user.next_task_status().into(),
                 completed_tasks:    user.completed_tasks(),
+                version:            user.version(),
             },
             user.user_task().map(|ut| UserTaskModel {
-                user_id:    user.id().into(),
-                task_year:  ut.task_id().year,
-                task_week:  ut.task_id().week as i32,
-                partner_id: ut.partner_id().into(),
-                state:      ut.state().clone().into(),
+                user_id:     user.id().into(),
+                task_year:   ut.task_id().year,
+                task_week:   ut.task_id().week as i32,
+                partner_ids: ut.partner_ids().iter().map(|&id| id.into()).collect(),
+                state:       ut.state().clone().into(),
+                variant:     ut.variant().map(|number| number as i32),
             }),
         )
     }
@@ -404,7 +693,9 @@ impl Into<UserTaskState> for UserTaskStateModel {
     fn into(self) -> UserTaskState {
         match self {
             UserTaskStateModel::Active => UserTaskState::Active,
+            UserTaskStateModel::AwaitingReview => UserTaskState::AwaitingReview,
             UserTaskStateModel::Completed => UserTaskState::Completed,
+            UserTaskStateModel::Expired => UserTaskState::Expired,
         }
     }
 }
@@ -423,9 +714,10 @@ impl Into<UserTask> for UserTaskModel {
     fn into(self) -> UserTask {
         UserTask::restore(
             TaskId::new(self.task_year, self.task_week as u32),
-            self.partner_id,
+            self.partner_ids.into_iter().map(UserId).collect(),
             self.state.into(),
         )
+            .with_variant(self.variant.map(|number| number as u32))
     }
 }
 
@@ -434,7 +726,7 @@ impl Into<User> for (UserModel, Option<UserTaskModel>) {
         let (user_model, user_task_model) = self;
 
         let profile = user_model.full_name.map(|full_name| {
-            Profile::new(
+            Profile::restore(
                 full_name,
                 user_model.group_name.expect("group name must exist if full name exists"),
             )
@@ -449,6 +741,7 @@ impl Into<User> for (UserModel, Option<UserTaskModel>) {
             user_task,
             user_model.next_task_status.into(),
             user_model.completed_tasks,
+            user_model.version,
         )
     }
 }
@@ -462,6 +755,7 @@ impl From<Row> for UserModel {
             group_name:         row.get("group_name"),
             next_task_status:   row.get("next_task_status"),
             completed_tasks:    row.get("completed_tasks"),
+            version:            row.get("version"),
         }
     }
 }
@@ -469,11 +763,12 @@ impl From<Row> for UserModel {
 impl From<Row> for UserTaskModel {
     fn from(row: Row) -> Self {
         Self {
-            user_id:    row.get("user_id"),
-            task_year:  row.get("task_year"),
-            task_week:  row.get("task_week"),
-            partner_id: row.get("partner_id"),
-            state:      row.get("state"),
+            user_id:     row.get("user_id"),
+            task_year:   row.get("task_year"),
+            task_week:   row.get("task_week"),
+            partner_ids: row.get("partner_ids"),
+            state:       row.get("state"),
+            variant:     row.get("variant"),
         }
     }
 }
@@ -482,12 +777,14 @@ impl From<Row> for UserTaskModel {
 mod integration_tests {
     use deadpool_postgres::GenericClient;
     use futures::future::join_all;
-    use rand::random;
+    use rand::{random, random_range};
+    use crate::domain::interfaces::UnitOfWork;
+    use crate::services::PostgresUnitOfWork;
     use crate::utils::postgres::testing::test_db_setup;
     use super::*;
 
     fn create_user_from_id(id: i64) -> User {
-        User::new(id, format!("user{}", id))
+        User::new(id, Some(format!("user{}", id)))
     }
 
     #[tokio::test]
@@ -538,8 +835,11 @@ mod integration_tests {
                 .expect("failed to insert test task");
         }
 
+        let partner = create_user_from_id(random());
+        repo.save(&partner).await.expect("failed to save partner");
+
         test_user.accept().expect("failed to accept next task");
-        test_user.promote(test_user.id(), task_id).expect("failed to promote task");
+        test_user.promote(&[partner.id()], task_id).expect("failed to promote task");
 
         repo.save(&test_user)
             .await
@@ -552,6 +852,268 @@ mod integration_tests {
         assert_eq!(test_user, retrieved_user);
     }
 
+    async fn insert_test_task(pool: &Pool, task_id: TaskId) {
+        let client = pool
+            .get()
+            .await
+            .expect("failed to get client from pool");
+
+        client.execute(
+            r#"
+            INSERT INTO tasks (
+                year,
+                week,
+                title,
+                description
+            ) VALUES ($1, $2, 'Test task', 'Lorem ispum')
+            ON CONFLICT DO NOTHING
+            "#,
+            &[
+                &task_id.year,
+                &(task_id.week as i32),
+            ],
+        )
+            .await
+            .expect("failed to insert test task");
+    }
+
+    #[tokio::test]
+    async fn test_user_tasks_history() {
+        let pool = test_db_setup().await;
+        let repo = PostgresUserRepository::new(pool.clone());
+        let mut test_user = create_user_from_id(random());
+        let partner_1 = create_user_from_id(random());
+        let partner_2 = create_user_from_id(random());
+        let partner_3 = create_user_from_id(random());
+
+        let year = random_range(1970..2025);
+        let first_task_id = TaskId::new(year, 10);
+        let second_task_id = TaskId::new(year, 11);
+        insert_test_task(&pool, first_task_id).await;
+        insert_test_task(&pool, second_task_id).await;
+        for partner in [&partner_1, &partner_2, &partner_3] {
+            repo.save(partner).await.expect("failed to save partner");
+        }
+
+        repo.save(&test_user)
+            .await
+            .expect("failed to save user");
+
+        test_user.accept().expect("failed to accept next task");
+        test_user.promote(&[partner_1.id()], first_task_id).expect("failed to promote task");
+        repo.update(&mut test_user)
+            .await
+            .expect("failed to update user");
+
+        test_user.accept().expect("failed to accept next task");
+        test_user.promote(&[partner_2.id(), partner_3.id()], second_task_id).expect("failed to promote task");
+        repo.update(&mut test_user)
+            .await
+            .expect("failed to update user");
+
+        let retrieved_user = repo.user(test_user.id())
+            .await
+            .expect("failed to retrieve user");
+        assert_eq!(test_user, retrieved_user, "current user task should be the latest one");
+
+        let history = repo.history(test_user.id())
+            .await
+            .expect("failed to retrieve user task history");
+        assert_eq!(history.user_tasks(), &[
+            UserTask::new(first_task_id, vec![partner_1.id()]),
+            UserTask::new(second_task_id, vec![partner_2.id(), partner_3.id()]),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_users_with_task_of_week() {
+        let pool = test_db_setup().await;
+        let repo = PostgresUserRepository::new(pool.clone());
+        let mut test_user = create_user_from_id(random());
+        let partner = create_user_from_id(random());
+
+        let year = random_range(1970..2025);
+        let first_task_id = TaskId::new(year, 10);
+        let second_task_id = TaskId::new(year, 11);
+        insert_test_task(&pool, first_task_id).await;
+        insert_test_task(&pool, second_task_id).await;
+        repo.save(&partner).await.expect("failed to save partner");
+        repo.save(&test_user).await.expect("failed to save user");
+
+        for task_id in [first_task_id, second_task_id] {
+            test_user.accept().expect("failed to accept next task");
+            test_user.promote(&[partner.id()], task_id).expect("failed to promote task");
+            repo.update(&mut test_user).await.expect("failed to update user");
+        }
+
+        let active = repo.users_with_task(UserTaskState::Active, Some(first_task_id))
+            .await
+            .expect("failed to fetch users with task");
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].user_task().map(UserTask::task_id), Some(first_task_id));
+
+        let uow = PostgresUnitOfWork::new(pool.clone());
+        let mut tx = uow.begin().await.expect("failed to begin transaction");
+        let mut user = repo.user_with_task_in(tx.as_mut(), test_user.id(), first_task_id)
+            .await
+            .expect("failed to read user with task");
+        user.complete_task().expect("failed to complete task");
+        repo.update_in(tx.as_mut(), &user).await.expect("failed to update user");
+        tx.commit().await.expect("failed to commit transaction");
+
+        let retrieved_user = repo.user(test_user.id())
+            .await
+            .expect("failed to retrieve user");
+        assert_eq!(retrieved_user.user_task().map(UserTask::task_id), Some(second_task_id));
+        assert_eq!(retrieved_user.completed_tasks(), 1);
+
+        let completed = repo.users_with_task(UserTaskState::Completed, None)
+            .await
+            .expect("failed to fetch users with task");
+        assert!(completed.iter().any(|user| user.id() == test_user.id()));
+
+        let mut tx = uow.begin().await.expect("failed to begin transaction");
+        let result = repo.user_with_task_in(tx.as_mut(), partner.id(), first_task_id).await;
+        assert!(matches!(result, Err(DomainError::NoUserTask)));
+        tx.rollback().await.expect("failed to rollback transaction");
+    }
+
+    #[tokio::test]
+    async fn test_expire_stale_tasks() {
+        let pool = test_db_setup().await;
+        let repo = PostgresUserRepository::new(pool.clone());
+        let mut test_user = create_user_from_id(random());
+        let partner = create_user_from_id(random());
+
+        // years before the ones of other tests, which must not be expired
+        let year = random_range(1000..1970);
+        let stale_task_id = TaskId::new(year, 10);
+        let next_task_id = TaskId::new(year, 11);
+        insert_test_task(&pool, stale_task_id).await;
+        insert_test_task(&pool, next_task_id).await;
+        repo.save(&partner).await.expect("failed to save partner");
+        repo.save(&test_user).await.expect("failed to save user");
+
+        for task_id in [stale_task_id, next_task_id] {
+            test_user.accept().expect("failed to accept next task");
+            test_user.promote(&[partner.id()], task_id).expect("failed to promote task");
+            repo.update(&mut test_user).await.expect("failed to update user");
+        }
+
+        let mut tx = PostgresUnitOfWork::new(pool.clone())
+            .begin()
+            .await
+            .expect("failed to begin transaction");
+        let expired = repo.expire_tasks_in(tx.as_mut(), next_task_id)
+            .await
+            .expect("failed to expire tasks");
+        tx.commit().await.expect("failed to commit transaction");
+        assert!(expired >= 1);
+
+        let history = repo.history(test_user.id())
+            .await
+            .expect("failed to retrieve user task history");
+        let states: Vec<_> = history.user_tasks().iter().map(|ut| ut.state().clone()).collect();
+        assert_eq!(states, vec![UserTaskState::Expired, UserTaskState::Active]);
+
+        let result = repo.update(&mut test_user).await;
+        assert!(matches!(result, Err(DomainError::ConcurrentModification(_))));
+    }
+
+    #[tokio::test]
+    async fn test_save_user_with_unknown_partner() {
+        let pool = test_db_setup().await;
+        let repo = PostgresUserRepository::new(pool.clone());
+        let mut test_user = create_user_from_id(random());
+
+        let task_id = TaskId::new(random_range(1970..2025), 12);
+        insert_test_task(&pool, task_id).await;
+
+        test_user.accept().expect("failed to accept next task");
+        test_user.promote(&[UserId(random())], task_id).expect("failed to promote task");
+
+        let result = repo.save(&test_user).await;
+        assert!(matches!(result, Err(DomainError::Other(_))));
+        assert!(repo.find_user(test_user.id()).await.expect("failed to find user").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_users_without_username() {
+        let pool = test_db_setup().await;
+        let repo = PostgresUserRepository::new(pool.clone());
+        let first = User::new(random(), None::<String>);
+        let second = User::new(random(), None::<String>);
+
+        repo.save(&first).await.expect("failed to save first user");
+        repo.save(&second).await.expect("failed to save second user");
+
+        let retrieved_user = repo.user(first.id())
+            .await
+            .expect("failed to retrieve user");
+        assert_eq!(retrieved_user.username(), None);
+    }
+
+    #[tokio::test]
+    async fn test_user_by_username() {
+        let pool = test_db_setup().await;
+        let repo = PostgresUserRepository::new(pool.clone());
+        let test_user = create_user_from_id(random());
+
+        repo.save(&test_user).await.expect("failed to save user");
+
+        let mut tx = PostgresUnitOfWork::new(pool.clone())
+            .begin()
+            .await
+            .expect("failed to begin transaction");
+
+        let holder = repo.user_by_username_in(tx.as_mut(), test_user.username().unwrap())
+            .await
+            .expect("failed to find user by username");
+        assert_eq!(holder.map(|user| user.id()), Some(test_user.id()));
+
+        let nobody = repo.user_by_username_in(tx.as_mut(), &format!("nobody{}", random::<u32>()))
+            .await
+            .expect("failed to find user by username");
+        assert!(nobody.is_none());
+
+        tx.rollback().await.expect("failed to rollback transaction");
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_update() {
+        let pool = test_db_setup().await;
+        let repo = PostgresUserRepository::new(pool.clone());
+        let test_user = create_user_from_id(random());
+
+        repo.save(&test_user)
+            .await
+            .expect("failed to save user");
+
+        let mut first_copy = repo.user(test_user.id())
+            .await
+            .expect("failed to retrieve user");
+        let mut second_copy = first_copy.clone();
+
+        first_copy.accept().expect("failed to accept next task");
+        repo.update(&mut first_copy)
+            .await
+            .expect("failed to update user");
+
+        second_copy.reject().expect("failed to reject next task");
+        let result = repo.update(&mut second_copy).await;
+
+        match result {
+            Err(DomainError::ConcurrentModification(id)) => assert_eq!(id, test_user.id()),
+            Ok(_) => panic!("expected ConcurrentModification error, but got Ok"),
+            Err(e) => panic!("expected ConcurrentModification error, but got different error: {:?}", e),
+        }
+
+        let retrieved_user = repo.user(test_user.id())
+            .await
+            .expect("failed to retrieve user");
+        assert_eq!(retrieved_user.next_task_status(), NextTaskStatus::Accepted);
+    }
+
     #[tokio::test]
     async fn test_user_exists() {
         let pool = test_db_setup().await;
@@ -584,8 +1146,8 @@ mod integration_tests {
             .await
             .expect("failed to save user");
 
-        test_user.set_profile(Profile::new("Ivanov Ivan", "СМ13-13Б"));
-        repo.update(&test_user)
+        test_user.set_profile(Profile::restore("Ivanov Ivan", "СМ13-13Б"));
+        repo.update(&mut test_user)
             .await
             .expect("failed to update user");
 
@@ -674,4 +1236,36 @@ mod integration_tests {
         assert!(accepted_users.iter().any(|u| u.id() == accepted_user.id()));
         assert!(accepted_users.iter().all(|u| u.id() != pending_user.id()));
     }
+
+    #[tokio::test]
+    async fn test_get_pending_users() {
+        let pool = test_db_setup().await;
+        let repo = PostgresUserRepository::new(pool.clone());
+
+        let unregistered_user = create_user_from_id(random());
+        let pending_user = {
+            let mut user = create_user_from_id(random());
+            user.set_profile(Profile::restore("Ivanov Ivan", "СМ13-13Б"));
+            user
+        };
+        let rejected_user = {
+            let mut user = create_user_from_id(random());
+            user.set_profile(Profile::restore("Petrov Petr", "СМ13-13Б"));
+            user.reject()
+                .expect("failed to reject next task by user");
+            user
+        };
+
+        repo.save(&unregistered_user).await.expect("failed to save user");
+        repo.save(&pending_user).await.expect("failed to save user");
+        repo.save(&rejected_user).await.expect("failed to save user");
+
+        let pending_users = repo.pending_users()
+            .await
+            .expect("failed to get pending users");
+
+        assert!(pending_users.iter().any(|u| u.id() == pending_user.id()));
+        assert!(pending_users.iter().all(|u| u.id() != unregistered_user.id()));
+        assert!(pending_users.iter().all(|u| u.id() != rejected_user.id()));
+    }
 }
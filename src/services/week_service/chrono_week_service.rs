@@ -1,15 +1,31 @@
-use chrono::{Datelike, Local};
+use std::sync::Arc;
 
-use crate::domain::interfaces::WeekService;
+use chrono::{DateTime, Datelike, Local, NaiveDate, TimeZone, Weekday};
+
+use crate::domain::interfaces::{Clock, WeekService};
 use crate::domain::models::WeekId;
+use crate::services::SystemClock;
 
 
-#[derive(Default)]
-pub struct ChronoWeekService;
+pub struct ChronoWeekService {
+    clock: Arc<dyn Clock>,
+}
+
+impl ChronoWeekService {
+    pub fn new(clock: Arc<dyn Clock>) -> Self {
+        Self { clock }
+    }
+}
+
+impl Default for ChronoWeekService {
+    fn default() -> Self {
+        Self::new(Arc::new(SystemClock))
+    }
+}
 
 impl WeekService for ChronoWeekService {
     fn current(&self) -> WeekId {
-        let today = Local::now();
+        let today = self.clock.now();
         let iso_week = today.iso_week();
         WeekId {
             year: iso_week.year(),
@@ -18,10 +34,7 @@ impl WeekService for ChronoWeekService {
     }
 
     fn next(&self, week_id: WeekId) -> WeekId {
-        let date = chrono::NaiveDate::from_isoywd_opt(week_id.year, week_id.week, chrono::Weekday::Mon)
-            .unwrap_or_else(|| {
-                chrono::NaiveDate::from_isoywd_opt(week_id.year + 1, 1, chrono::Weekday::Mon).unwrap()
-            });
+        let date = monday(week_id);
 
         let next_date = date + chrono::Duration::weeks(1);
         let iso_week = next_date.iso_week();
@@ -31,4 +44,56 @@ impl WeekService for ChronoWeekService {
             week: iso_week.week(),
         }
     }
+
+    fn start(&self, week_id: WeekId) -> DateTime<Local> {
+        let midnight = monday(week_id)
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        Local.from_local_datetime(&midnight)
+            .earliest()
+            .unwrap()
+    }
+}
+
+fn monday(week_id: WeekId) -> NaiveDate {
+    NaiveDate::from_isoywd_opt(week_id.year, week_id.week, Weekday::Mon)
+        .unwrap_or_else(|| {
+            NaiveDate::from_isoywd_opt(week_id.year + 1, 1, Weekday::Mon).unwrap()
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::services::FixedClock;
+    use super::*;
+
+    fn week_service_at(year: i32, month: u32, day: u32) -> ChronoWeekService {
+        let now = Local.with_ymd_and_hms(year, month, day, 12, 0, 0).unwrap();
+        ChronoWeekService::new(Arc::new(FixedClock::new(now)))
+    }
+
+    #[test]
+    fn test_current_week() {
+        let week_service = week_service_at(2025, 3, 12);
+
+        assert_eq!(week_service.current(), WeekId::new(2025, 11));
+    }
+
+    #[test]
+    fn test_next_week_crosses_year() {
+        let week_service = week_service_at(2025, 12, 24);
+
+        assert_eq!(week_service.current(), WeekId::new(2025, 52));
+        assert_eq!(week_service.next(week_service.current()), WeekId::new(2026, 1));
+    }
+
+    #[test]
+    fn test_week_start() {
+        let week_service = week_service_at(2025, 3, 12);
+
+        assert_eq!(
+            week_service.start(WeekId::new(2025, 12)),
+            Local.with_ymd_and_hms(2025, 3, 17, 0, 0, 0).unwrap(),
+        );
+    }
 }
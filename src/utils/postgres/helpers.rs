@@ -9,6 +9,13 @@ pub fn is_unique_violation(error: &PgError) -> bool {
         .unwrap_or(false)
 }
 
+pub fn is_unique_violation_of(error: &PgError, constraint: &str) -> bool {
+    error
+        .as_db_error()
+        .map(|e| e.code() == &SqlState::UNIQUE_VIOLATION && e.constraint() == Some(constraint))
+        .unwrap_or(false)
+}
+
 pub fn is_foreign_key_violation(error: &PgError) -> bool {
     error
         .as_db_error()